@@ -135,6 +135,39 @@ impl Components {
         self.inner.list_mut()
     }
 
+    /// Returns the component with the highest temperature.
+    ///
+    /// Components without temperature (or with `f32::NAN`) are ignored.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// if let Some(component) = components.hottest() {
+    ///     println!("{}: {:?}°C", component.label(), component.temperature());
+    /// }
+    /// ```
+    pub fn hottest(&self) -> Option<&Component> {
+        hottest(self.list().iter().map(|c| (c, c.temperature())))
+    }
+
+    /// Returns the average temperature of the components (in celsius degree).
+    ///
+    /// Components without temperature (or with `f32::NAN`) are ignored. Returns `None` if no
+    /// component has a temperature.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// if let Some(temperature) = components.average_temperature() {
+    ///     println!("average: {temperature}°C");
+    /// }
+    /// ```
+    pub fn average_temperature(&self) -> Option<f32> {
+        average_temperature(self.list().iter().map(|c| c.temperature()))
+    }
+
     /// Refreshes the components list.
     ///
     /// ```no_run
@@ -198,10 +231,30 @@ impl Component {
         self.inner.temperature()
     }
 
+    /// Returns the temperature of the component (in fahrenheit degree).
+    ///
+    /// It is converted from [`Component::temperature`]. If the temperature is `f32::NAN`, so is
+    /// the returned value.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if let Some(temperature) = component.temperature_fahrenheit() {
+    ///         println!("{temperature}°F");
+    ///     }
+    /// }
+    /// ```
+    pub fn temperature_fahrenheit(&self) -> Option<f32> {
+        self.temperature().map(celsius_to_fahrenheit)
+    }
+
     /// Returns the maximum temperature of the component (in celsius degree).
     ///
-    /// Note: if `temperature` is higher than the current `max`,
-    /// `max` value will be updated on refresh.
+    /// It is a running maximum since the component was created or since the last call to
+    /// [`Component::reset_max`]: if `temperature` is higher than the current `max`, `max` value
+    /// will be updated on refresh.
     ///
     /// ## Linux
     ///
@@ -222,6 +275,35 @@ impl Component {
         self.inner.max()
     }
 
+    /// Resets the maximum temperature returned by [`Component::max`] to the current
+    /// temperature.
+    ///
+    /// It allows to track the maximum temperature over a given period of time.
+    ///
+    /// ## Linux
+    ///
+    /// If the maximum was provided by the kernel, it is computed by `sysinfo` from
+    /// then on.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// for component in components.iter_mut() {
+    ///     component.reset_max();
+    /// }
+    /// // We wait some time...
+    /// components.refresh(false);
+    /// for component in &components {
+    ///     if let Some(max) = component.max() {
+    ///         println!("{}: {max}°C", component.label());
+    ///     }
+    /// }
+    /// ```
+    pub fn reset_max(&mut self) {
+        self.inner.reset_max()
+    }
+
     /// Returns the highest temperature before the component halts (in celsius degree).
     ///
     /// ## Linux
@@ -242,6 +324,29 @@ impl Component {
         self.inner.critical()
     }
 
+    /// Returns `true` if the temperature of the component reached its critical threshold
+    /// (returned by [`Component::critical`]).
+    ///
+    /// Returns `false` if either the temperature or the critical threshold is unknown (or is
+    /// `f32::NAN`).
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if component.is_critical() {
+    ///         println!("{} is too hot!", component.label());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_critical(&self) -> bool {
+        match (self.temperature(), self.critical()) {
+            (Some(temperature), Some(critical)) => temperature >= critical,
+            _ => false,
+        }
+    }
+
     /// Returns the label of the component.
     ///
     /// ## Linux
@@ -269,6 +374,36 @@ impl Component {
         self.inner.label()
     }
 
+    /// Returns the type of the thermal zone backing this component (like `x86_pkg_temp` or
+    /// `acpitz`), read from `/sys/class/thermal/thermal_zoneN/type`.
+    ///
+    /// Returns `None` if the component doesn't come from a thermal zone.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if let Some(zone_type) = component.thermal_zone_type() {
+    ///         println!("{}: {zone_type}", component.label());
+    ///     }
+    /// }
+    /// ```
+    pub fn thermal_zone_type(&self) -> Option<&str> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.thermal_zone_type()
+            } else {
+                None
+            }
+        }
+    }
+
     /// Refreshes component.
     ///
     /// ```no_run
@@ -284,8 +419,28 @@ impl Component {
     }
 }
 
+fn celsius_to_fahrenheit(celsius: f32) -> f32 {
+    celsius * 9. / 5. + 32.
+}
+
+fn hottest<T>(items: impl Iterator<Item = (T, Option<f32>)>) -> Option<T> {
+    items
+        .filter_map(|(item, temperature)| Some((item, temperature.filter(|t| !t.is_nan())?)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(item, _)| item)
+}
+
+fn average_temperature(temperatures: impl Iterator<Item = Option<f32>>) -> Option<f32> {
+    let (sum, count) = temperatures
+        .flatten()
+        .filter(|t| !t.is_nan())
+        .fold((0., 0u32), |(sum, count), t| (sum + t, count + 1));
+    (count > 0).then(|| sum / count as f32)
+}
+
 #[cfg(test)]
 mod tests {
+    use super::{average_temperature, celsius_to_fahrenheit, hottest};
     use crate::*;
 
     #[test]
@@ -294,4 +449,25 @@ mod tests {
         components.refresh(false);
         components.refresh(false);
     }
+
+    #[test]
+    fn test_celsius_to_fahrenheit() {
+        assert_eq!(celsius_to_fahrenheit(0.), 32.);
+        assert_eq!(celsius_to_fahrenheit(100.), 212.);
+        assert_eq!(celsius_to_fahrenheit(-40.), -40.);
+        assert!(celsius_to_fahrenheit(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_temperature_summary() {
+        let temperatures = [Some(40.), Some(f32::NAN), None, Some(60.), Some(50.)];
+
+        assert_eq!(hottest(temperatures.iter().copied().enumerate()), Some(3));
+        assert_eq!(average_temperature(temperatures.iter().copied()), Some(50.));
+
+        let invalid = [Some(f32::NAN), None];
+        assert_eq!(hottest(invalid.iter().copied().enumerate()), None);
+        assert_eq!(average_temperature(invalid.iter().copied()), None);
+        assert_eq!(average_temperature(std::iter::empty()), None);
+    }
 }
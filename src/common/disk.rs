@@ -1,8 +1,11 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use std::ffi::OsStr;
+use std::collections::HashSet;
+use std::ffi::{OsStr, OsString};
 use std::fmt;
-use std::path::Path;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 use crate::common::impl_get_set::impl_get_set;
 use crate::DiskUsage;
@@ -94,6 +97,9 @@ impl Disk {
 
     /// Returns the available disk size, in bytes.
     ///
+    /// On Unix systems, it doesn't include the blocks reserved for the superuser (see
+    /// [`Disk::free_space`]).
+    ///
     /// ```no_run
     /// use sysinfo::Disks;
     ///
@@ -106,6 +112,65 @@ impl Disk {
         self.inner.available_space()
     }
 
+    /// Returns the change of the [available space](Disk::available_space) (in bytes) since the
+    /// previous refresh of the storage of this disk. A negative value means the disk is filling
+    /// up.
+    ///
+    /// It returns `0` if the storage of the disk was only refreshed once.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// // We wait some time...
+    /// disks.refresh(false);
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}B", disk.name(), disk.available_space_delta());
+    /// }
+    /// ```
+    pub fn available_space_delta(&self) -> i64 {
+        self.inner.available_space_delta()
+    }
+
+    /// Returns the free disk size, in bytes.
+    ///
+    /// Unlike [`Disk::available_space`], it includes the blocks reserved for the superuser on
+    /// Unix systems. On Windows, it is the same as [`Disk::available_space`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}B", disk.name(), disk.free_space());
+    /// }
+    /// ```
+    pub fn free_space(&self) -> u64 {
+        self.inner.free_space()
+    }
+
+    /// Returns the percentage (between `0` and `100`) of the disk which is used.
+    ///
+    /// It is computed from [`Disk::free_space`] so the blocks reserved for the superuser are not
+    /// counted as used. Returns `0` if [`Disk::total_space`] is `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}% used", disk.name(), disk.usage_percent());
+    /// }
+    /// ```
+    pub fn usage_percent(&self) -> f32 {
+        let total_space = self.total_space();
+        if total_space == 0 {
+            return 0.;
+        }
+        let used_space = total_space.saturating_sub(self.free_space());
+        (used_space as f64 / total_space as f64 * 100.) as f32
+    }
+
     /// Returns `true` if the disk is removable.
     ///
     /// ```no_run
@@ -134,6 +199,211 @@ impl Disk {
         self.inner.is_read_only()
     }
 
+    /// Returns `true` if the disk space information couldn't be retrieved in time when calling
+    /// [`Disks::refresh_list_non_blocking`] or, for network mount points (check
+    /// [`Disk::is_network`]), on any refresh. In this case, [`Disk::total_space`],
+    /// [`Disk::available_space`] and [`Disk::free_space`] all return `0`.
+    ///
+    /// ⚠️ This method always returns `false` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new();
+    /// disks.refresh_list_non_blocking(Duration::from_secs(1));
+    /// for disk in disks.list() {
+    ///     if disk.space_unavailable() {
+    ///         println!("[{:?}] is not responding", disk.mount_point());
+    ///     }
+    /// }
+    /// ```
+    pub fn space_unavailable(&self) -> bool {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.space_unavailable()
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the disk is a network mount point (like a NFS or CIFS share).
+    ///
+    /// ⚠️ Network mount points are only listed if the `linux-netdevs` feature is enabled.
+    ///
+    /// ⚠️ This method always returns `false` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if disk.is_network() {
+    ///         println!("[{:?}] is a network share", disk.mount_point());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_network(&self) -> bool {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.is_network()
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Returns the kind of mount point of the disk.
+    ///
+    /// ⚠️ This method always returns [`MountKind::Unknown`] on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Disks, MountKind};
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if disk.mount_kind() == MountKind::Bind {
+    ///         println!("[{:?}] is a bind mount", disk.mount_point());
+    ///     }
+    /// }
+    /// ```
+    pub fn mount_kind(&self) -> MountKind {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.mount_kind()
+            } else {
+                MountKind::Unknown
+            }
+        }
+    }
+
+    /// Returns the ID of the mount, as listed in `/proc/self/mountinfo`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] mount ID: {:?}", disk.mount_point(), disk.mount_id());
+    /// }
+    /// ```
+    pub fn mount_id(&self) -> Option<u32> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                Some(self.inner.mount_id())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the ID of the parent mount, as listed in `/proc/self/mountinfo`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] parent mount ID: {:?}", disk.mount_point(), disk.parent_mount_id());
+    /// }
+    /// ```
+    pub fn parent_mount_id(&self) -> Option<u32> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                Some(self.inner.parent_mount_id())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns how mount and unmount events propagate between this mount and its peers.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Disks, MountPropagation};
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if disk.propagation() == Some(MountPropagation::Shared) {
+    ///         println!("[{:?}] is a shared mount", disk.mount_point());
+    ///     }
+    /// }
+    /// ```
+    pub fn propagation(&self) -> Option<MountPropagation> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                Some(self.inner.propagation())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns when this file system was last mounted, if it can be found.
+    ///
+    /// This is best-effort and returns `None` in most cases: on Linux, it is only available for
+    /// ext2/3/4 file systems, read from their superblock (`s_mtime`), which requires being able
+    /// to read the device (so usually being root).
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if let Some(mounted_since) = disk.mounted_since() {
+    ///         println!("[{:?}] mounted since {mounted_since:?}", disk.mount_point());
+    ///     }
+    /// }
+    /// ```
+    pub fn mounted_since(&self) -> Option<SystemTime> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.mounted_since()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the transparent compression settings of this file system, or `None` if the file
+    /// system doesn't support compression (or if it couldn't be retrieved).
+    ///
+    /// Keep in mind that [`Disk::available_space`] doesn't take compression into account, so
+    /// more data than the available space might actually fit on a compressed file system.
+    ///
+    /// * On Btrfs, it is computed from the `compress` and `compress-force` mount options when
+    ///   the disks list is refreshed. The compression ratio isn't available.
+    /// * On APFS, compression is always reported as enabled. The algorithm and the compression
+    ///   ratio aren't available.
+    ///
+    /// ⚠️ This method always returns `None` on Windows and for ZFS, whose dataset properties
+    /// can only be retrieved through the `zfs` command or `libzfs`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if let Some(compression) = disk.compression() {
+    ///         println!("[{:?}] compression: {compression:?}", disk.mount_point());
+    ///     }
+    /// }
+    /// ```
+    pub fn compression(&self) -> Option<CompressionInfo> {
+        self.inner.compression()
+    }
+
     /// Updates the disk' information with everything loaded.
     ///
     /// Equivalent to <code>[Disk::refresh_specifics]\([DiskRefreshKind::everything]\())</code>.
@@ -195,9 +465,9 @@ impl Disk {
 ///
 /// ⚠️ Note that network devices are excluded by default under Linux.
 /// To display mount points using the CIFS and NFS protocols, the `linux-netdevs`
-/// feature must be enabled. Note, however, that sysinfo may hang under certain
-/// circumstances. For example, if a CIFS or NFS share has been mounted with
-/// the _hard_ option, but the connection has an error, such as the share server has stopped.
+/// feature must be enabled. Their space is retrieved from another thread with a timeout, so
+/// sysinfo doesn't hang if a share has been mounted with the _hard_ option and its server is
+/// unreachable: [`Disk::space_unavailable`] returns `true` instead.
 pub struct Disks {
     inner: crate::DisksInner,
 }
@@ -343,6 +613,205 @@ impl Disks {
         self.inner
             .refresh_specifics(remove_not_listed_disks, refreshes);
     }
+
+    /// Refreshes the disks list and their information without blocking on mount points which
+    /// don't respond, like a NFS or CIFS share mounted with the _hard_ option whose server is
+    /// down.
+    ///
+    /// The disk space of each mount point is retrieved from another thread. If it doesn't
+    /// answer before `timeout`, the disk is still listed but [`Disk::space_unavailable`] returns
+    /// `true` and its space is set to `0`. The thread is left running in the background until
+    /// the mount point answers, and no new thread is started for this mount point in the
+    /// meantime (its space is directly considered unavailable).
+    ///
+    /// Disks which are not listed anymore are removed, like with
+    /// <code>[Disks::refresh]\(true)</code>.
+    ///
+    /// ⚠️ Please note that `timeout` applies to each mount point, not to the whole refresh.
+    ///
+    /// ⚠️ On other platforms than Linux, this is the same as calling
+    /// <code>[Disks::refresh]\(true)</code>.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new();
+    /// disks.refresh_list_non_blocking(Duration::from_millis(500));
+    /// for disk in disks.list() {
+    ///     println!("{disk:?}");
+    /// }
+    /// ```
+    pub fn refresh_list_non_blocking(&mut self, timeout: Duration) {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.refresh_list_non_blocking(timeout);
+            } else {
+                let _timeout = timeout;
+                self.refresh(true);
+            }
+        }
+    }
+
+    /// Returns the number of bytes read and written by all the disks.
+    ///
+    /// Each device is only counted once, even if it is mounted more than once. On Linux, a
+    /// partition isn't counted if the device it belongs to is listed as well.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// // We wait some time...
+    /// disks.refresh(false);
+    /// let usage = disks.total_io();
+    /// println!("read: {} B, written: {} B", usage.read_bytes, usage.written_bytes);
+    /// ```
+    pub fn total_io(&self) -> DiskUsage {
+        self.inner.total_io()
+    }
+
+    /// Returns the list of the mounted file systems, without querying their space.
+    ///
+    /// Unlike [`Disks::refresh`], it never blocks on mount points which don't respond (like a
+    /// NFS share whose server is down) and it lists all the mount points, including the virtual
+    /// file systems (like `proc` or `tmpfs`).
+    ///
+    /// On Linux, it reads `/proc/self/mountinfo`. On FreeBSD and macOS, it uses `getmntinfo`
+    /// and `getfsstat`. On Windows, it lists the mount points of the volumes.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for mount in Disks::list_mounts() {
+    ///     println!("{:?} on {:?} ({:?})", mount.device, mount.mount_point, mount.fs_type);
+    /// }
+    /// ```
+    pub fn list_mounts() -> Vec<MountEntry> {
+        crate::DisksInner::list_mounts()
+    }
+
+    /// Returns the layers of the overlay file system mounted on `mount_point`, read from its
+    /// mount options in `/proc/self/mountinfo`.
+    ///
+    /// Returns `None` if nothing is mounted on `mount_point` or if it's not an overlay file
+    /// system.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::Disks;
+    ///
+    /// if let Some(overlay) = Disks::overlay_layers(Path::new("/")) {
+    ///     println!("lower layers: {:?}", overlay.lower_dirs);
+    ///     println!("upper layer: {:?}", overlay.upper_dir);
+    /// }
+    /// ```
+    pub fn overlay_layers(mount_point: &Path) -> Option<OverlayInfo> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                crate::DisksInner::overlay_layers(mount_point)
+            } else {
+                let _mount_point = mount_point;
+                None
+            }
+        }
+    }
+}
+
+/// A mounted file system, as returned by [`Disks::list_mounts`].
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// for mount in Disks::list_mounts() {
+///     println!("{:?} mounted with {}", mount.mount_point, mount.options);
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct MountEntry {
+    /// Device (or source) of the mount, like `/dev/sda1`.
+    pub device: OsString,
+    /// Path where the file system is mounted.
+    pub mount_point: PathBuf,
+    /// Type of the file system, like `ext4`.
+    pub fs_type: OsString,
+    /// Comma-separated mount options, like `rw,nosuid`.
+    pub options: String,
+}
+
+/// Layers of an overlay file system, as returned by [`Disks::overlay_layers`].
+///
+/// ```no_run
+/// use std::path::Path;
+/// use sysinfo::Disks;
+///
+/// if let Some(overlay) = Disks::overlay_layers(Path::new("/")) {
+///     for dir in &overlay.lower_dirs {
+///         println!("{dir:?}");
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct OverlayInfo {
+    /// Read-only layers, from the top-most one to the bottom-most one.
+    pub lower_dirs: Vec<PathBuf>,
+    /// Writable layer. It is `None` if the overlay is read-only.
+    pub upper_dir: Option<PathBuf>,
+    /// Work directory used by the overlay. It is `None` if the overlay is read-only.
+    pub work_dir: Option<PathBuf>,
+}
+
+/// Transparent compression settings of a file system, as returned by [`Disk::compression`].
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     if let Some(compression) = disk.compression().filter(|c| c.is_enabled) {
+///         println!("[{:?}] compressed with {:?}", disk.mount_point(), compression.algorithm);
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct CompressionInfo {
+    /// Whether new data written on the file system is compressed.
+    pub is_enabled: bool,
+    /// Compression algorithm, like `zstd`. It is `None` if it is unknown or if the default
+    /// algorithm of the file system is used.
+    pub algorithm: Option<String>,
+    /// Ratio between the logical size and the physical size of the stored data, like `1.5`. It
+    /// is `None` if it isn't cheaply available.
+    pub ratio: Option<f64>,
+}
+
+/// Sums the usage of all the given devices, ignoring the duplicated ones.
+#[allow(dead_code)] // Needed for unsupported targets.
+pub(crate) fn sum_disks_usage<K: Hash + Eq>(
+    devices: impl IntoIterator<Item = (K, DiskUsage)>,
+) -> DiskUsage {
+    let mut seen = HashSet::new();
+    let mut total = DiskUsage::default();
+    for (device, usage) in devices {
+        if !seen.insert(device) {
+            continue;
+        }
+        total.total_written_bytes = total
+            .total_written_bytes
+            .saturating_add(usage.total_written_bytes);
+        total.written_bytes = total.written_bytes.saturating_add(usage.written_bytes);
+        total.total_read_bytes = total
+            .total_read_bytes
+            .saturating_add(usage.total_read_bytes);
+        total.read_bytes = total.read_bytes.saturating_add(usage.read_bytes);
+    }
+    total
 }
 
 impl std::ops::Deref for Disks {
@@ -392,10 +861,99 @@ impl fmt::Display for DiskKind {
     }
 }
 
+/// Enum containing the different kinds of mount points.
+///
+/// This type is returned by [`Disk::mount_kind`](`crate::Disk::mount_kind`).
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     println!("{:?}: {:?}", disk.mount_point(), disk.mount_kind());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum MountKind {
+    /// File system stored on a block device.
+    Block,
+    /// Bind mount of a directory (or of a file) of another mount point.
+    Bind,
+    /// Overlay file system, used by most container engines.
+    Overlay,
+    /// In-memory file system (`tmpfs` or `ramfs`).
+    Tmpfs,
+    /// Network file system (like NFS or CIFS).
+    Network,
+    /// Pseudo file system which doesn't store any data (like `autofs` or `debugfs`).
+    Pseudo,
+    /// The kind of mount point couldn't be determined.
+    Unknown,
+}
+
+/// Enum containing the different propagation types of a mount point.
+///
+/// More information in the [kernel documentation][doc].
+///
+/// This type is returned by [`Disk::propagation`](`crate::Disk::propagation`).
+///
+/// ```no_run
+/// use sysinfo::Disks;
+///
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     println!("{:?}: {:?}", disk.mount_point(), disk.propagation());
+/// }
+/// ```
+///
+/// [doc]: https://www.kernel.org/doc/Documentation/filesystems/sharedsubtree.txt
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum MountPropagation {
+    /// Mount events propagate to and from its peers.
+    Shared,
+    /// Mount events propagate from its master, but not to it.
+    Slave,
+    /// Mount events propagate from its master and to and from its peers.
+    SharedAndSlave,
+    /// Mount events don't propagate.
+    Private,
+    /// Like [`MountPropagation::Private`], but it also can't be bind mounted.
+    Unbindable,
+}
+
+impl fmt::Display for MountPropagation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            MountPropagation::Shared => "Shared",
+            MountPropagation::Slave => "Slave",
+            MountPropagation::SharedAndSlave => "SharedAndSlave",
+            MountPropagation::Private => "Private",
+            MountPropagation::Unbindable => "Unbindable",
+        })
+    }
+}
+
+impl fmt::Display for MountKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            MountKind::Block => "Block",
+            MountKind::Bind => "Bind",
+            MountKind::Overlay => "Overlay",
+            MountKind::Tmpfs => "Tmpfs",
+            MountKind::Network => "Network",
+            MountKind::Pseudo => "Pseudo",
+            MountKind::Unknown => "Unknown",
+        })
+    }
+}
+
 /// Used to determine what you want to refresh specifically on the [`Disk`] type.
 ///
 /// * `kind` is about refreshing the [`Disk::kind`] information.
-/// * `storage` is about refreshing the [`Disk::available_space`] and [`Disk::total_space`] information.
+/// * `storage` is about refreshing the [`Disk::available_space`], [`Disk::free_space`] and
+///   [`Disk::total_space`] information.
 /// * `io_usage` is about refreshing the [`Disk::usage`] information.
 ///
 /// ```no_run
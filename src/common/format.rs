@@ -0,0 +1,81 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+const BINARY_UNITS: &[&str] = &["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const SI_UNITS: &[&str] = &["KB", "MB", "GB", "TB", "PB", "EB"];
+
+fn format_with_base(bytes: u64, base: u64, units: &[&str]) -> String {
+    if bytes < base {
+        return format!("{bytes} B");
+    }
+    let base = base as f64;
+    let mut value = bytes as f64 / base;
+    let mut unit = 0;
+    while value >= base && unit + 1 < units.len() {
+        value /= base;
+        unit += 1;
+    }
+    // Rounding can bring the value up to the base (`1023.96` is displayed as `1024.0`), in which
+    // case we use the next unit.
+    if (value * 10.).round() / 10. >= base && unit + 1 < units.len() {
+        value /= base;
+        unit += 1;
+    }
+    format!("{value:.1} {}", units[unit])
+}
+
+/// Formats a number of bytes using binary units (KiB, MiB, GiB, ...), like `1.5 GiB`.
+///
+/// Values under 1 KiB are displayed in bytes, like `512 B`.
+///
+/// ```
+/// use sysinfo::format_bytes;
+///
+/// assert_eq!(format_bytes(512), "512 B");
+/// assert_eq!(format_bytes(1536), "1.5 KiB");
+/// ```
+pub fn format_bytes(bytes: u64) -> String {
+    format_with_base(bytes, 1024, BINARY_UNITS)
+}
+
+/// Formats a number of bytes using SI units (KB, MB, GB, ...), like `1.5 GB`.
+///
+/// Values under 1 KB are displayed in bytes, like `512 B`.
+///
+/// ```
+/// use sysinfo::format_bytes_si;
+///
+/// assert_eq!(format_bytes_si(512), "512 B");
+/// assert_eq!(format_bytes_si(1500), "1.5 KB");
+/// ```
+pub fn format_bytes_si(bytes: u64) -> String {
+    format_with_base(bytes, 1000, SI_UNITS)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format_bytes, format_bytes_si};
+
+    #[test]
+    fn check_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.0 KiB");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(1024 * 1024 - 1), "1.0 MiB");
+        assert_eq!(format_bytes(1024 * 1024), "1.0 MiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024 / 2), "1.5 GiB");
+        assert_eq!(format_bytes(1 << 60), "1.0 EiB");
+        assert_eq!(format_bytes(u64::MAX), "16.0 EiB");
+    }
+
+    #[test]
+    fn check_format_bytes_si() {
+        assert_eq!(format_bytes_si(0), "0 B");
+        assert_eq!(format_bytes_si(999), "999 B");
+        assert_eq!(format_bytes_si(1000), "1.0 KB");
+        assert_eq!(format_bytes_si(999_949), "999.9 KB");
+        assert_eq!(format_bytes_si(999_950), "1.0 MB");
+        assert_eq!(format_bytes_si(1_500_000_000), "1.5 GB");
+        assert_eq!(format_bytes_si(u64::MAX), "18.4 EB");
+    }
+}
@@ -4,6 +4,7 @@
 pub(crate) mod component;
 #[cfg(feature = "disk")]
 pub(crate) mod disk;
+pub(crate) mod format;
 #[cfg(any(feature = "system", feature = "disk"))]
 pub(crate) mod impl_get_set;
 #[cfg(feature = "network")]
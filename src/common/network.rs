@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::net::{AddrParseError, IpAddr};
 use std::num::ParseIntError;
@@ -100,6 +100,140 @@ impl Networks {
     pub fn refresh(&mut self, remove_not_listed_interfaces: bool) {
         self.inner.refresh(remove_not_listed_interfaces)
     }
+
+    /// Refreshes the network interfaces and returns the interfaces which appeared and the ones
+    /// which were removed since the last refresh.
+    ///
+    /// It is equivalent to <code>[Networks::refresh]\(true)</code>.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Wait some time...? Then check what changed.
+    /// let diff = networks.refresh_list_diff();
+    /// for interface_name in &diff.added {
+    ///     println!("{interface_name} is up");
+    /// }
+    /// for interface_name in &diff.removed {
+    ///     println!("{interface_name} is gone");
+    /// }
+    /// ```
+    pub fn refresh_list_diff(&mut self) -> NetworkDiff {
+        let old = self.list().keys().cloned().collect::<HashSet<_>>();
+        self.refresh(true);
+        let mut added = self
+            .list()
+            .keys()
+            .filter(|name| !old.contains(*name))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut removed = old
+            .into_iter()
+            .filter(|name| !self.list().contains_key(name))
+            .collect::<Vec<_>>();
+        added.sort_unstable();
+        removed.sort_unstable();
+        NetworkDiff { added, removed }
+    }
+
+    /// Returns the sum of the network usage of all interfaces, except the loopback ones.
+    ///
+    /// If you want to include the loopback interfaces, use [`Networks::total_with_loopback`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// let total = networks.total();
+    /// println!("in: {} B, out: {} B", total.total_received, total.total_transmitted);
+    /// ```
+    pub fn total(&self) -> NetworkTotals {
+        self.sum(false)
+    }
+
+    /// Returns the sum of the network usage of all interfaces, including the loopback ones.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// let total = networks.total_with_loopback();
+    /// println!("in: {} B, out: {} B", total.total_received, total.total_transmitted);
+    /// ```
+    pub fn total_with_loopback(&self) -> NetworkTotals {
+        self.sum(true)
+    }
+
+    fn sum(&self, include_loopback: bool) -> NetworkTotals {
+        self.list()
+            .iter()
+            .filter(|(name, data)| include_loopback || !is_loopback(name, data))
+            .fold(NetworkTotals::default(), |mut total, (_, data)| {
+                total.received = total.received.saturating_add(data.received());
+                total.transmitted = total.transmitted.saturating_add(data.transmitted());
+                total.total_received = total.total_received.saturating_add(data.total_received());
+                total.total_transmitted = total
+                    .total_transmitted
+                    .saturating_add(data.total_transmitted());
+                total
+            })
+    }
+}
+
+/// Returns `true` if the interface is a loopback one, based on its name (`lo` on Linux, `lo0` on
+/// macOS and FreeBSD, `Loopback Pseudo-Interface 1` on Windows) or on its IP addresses.
+fn is_loopback(name: &str, data: &NetworkData) -> bool {
+    if name == "lo" || name == "lo0" || name.starts_with("Loopback ") {
+        return true;
+    }
+    let ip_networks = data.ip_networks();
+    !ip_networks.is_empty()
+        && ip_networks
+            .iter()
+            .all(|ip_network| ip_network.addr.is_loopback())
+}
+
+/// Sum of the network usage of several interfaces.
+///
+/// It is returned by [`Networks::total`] and [`Networks::total_with_loopback`].
+///
+/// ```no_run
+/// use sysinfo::Networks;
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// let total = networks.total();
+/// println!("received since last refresh: {} B", total.received);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkTotals {
+    /// Number of received bytes since the last refresh, see [`NetworkData::received`].
+    pub received: u64,
+    /// Number of transmitted bytes since the last refresh, see [`NetworkData::transmitted`].
+    pub transmitted: u64,
+    /// Total number of received bytes, see [`NetworkData::total_received`].
+    pub total_received: u64,
+    /// Total number of transmitted bytes, see [`NetworkData::total_transmitted`].
+    pub total_transmitted: u64,
+}
+
+/// Network interfaces which appeared or were removed between two refreshes.
+///
+/// It is returned by [`Networks::refresh_list_diff`].
+///
+/// ```no_run
+/// use sysinfo::Networks;
+///
+/// let mut networks = Networks::new_with_refreshed_list();
+/// let diff = networks.refresh_list_diff();
+/// println!("added: {:?}, removed: {:?}", diff.added, diff.removed);
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct NetworkDiff {
+    /// Names of the interfaces which appeared, sorted alphabetically.
+    pub added: Vec<String>,
+    /// Names of the interfaces which were removed, sorted alphabetically.
+    pub removed: Vec<String>,
 }
 
 impl std::ops::Deref for Networks {
@@ -406,6 +540,166 @@ impl NetworkData {
     pub fn mtu(&self) -> u64 {
         self.inner.mtu()
     }
+
+    /// Returns the name of the interface this one is attached to, like the bridge of a `veth`
+    /// interface or the bond of an ethernet interface. Returns `None` if the interface isn't
+    /// attached to another one.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(master) = network.master_interface() {
+    ///         println!("{interface_name} is attached to {master}");
+    ///     }
+    /// }
+    /// ```
+    pub fn master_interface(&self) -> Option<&str> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.master_interface()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of times the link of the interface went up or down. A high value
+    /// usually means the link is flapping.
+    ///
+    /// Returns `None` if the interface doesn't provide this counter.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(carrier_changes) = network.carrier_changes() {
+    ///         println!("{interface_name}: {carrier_changes} carrier changes");
+    ///     }
+    /// }
+    /// ```
+    pub fn carrier_changes(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.carrier_changes()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the total number of collisions detected on the interface.
+    ///
+    /// Returns `None` if the interface doesn't provide this counter.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(collisions) = network.collisions() {
+    ///         println!("{interface_name}: {collisions} collisions");
+    ///     }
+    /// }
+    /// ```
+    pub fn collisions(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.collisions()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the total number of received packets dropped by the interface (because of a
+    /// lack of buffer space for example). Unlike [`NetworkData::errors_on_received`], these
+    /// packets were valid.
+    ///
+    /// Returns `None` if the interface doesn't provide this counter.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(drops) = network.drops_on_received() {
+    ///         println!("{interface_name}: {drops} received packets dropped");
+    ///     }
+    /// }
+    /// ```
+    pub fn drops_on_received(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.drops_on_received()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the total number of packets dropped by the interface before being transmitted.
+    ///
+    /// Returns `None` if the interface doesn't provide this counter.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(drops) = network.drops_on_transmitted() {
+    ///         println!("{interface_name}: {drops} transmitted packets dropped");
+    ///     }
+    /// }
+    /// ```
+    pub fn drops_on_transmitted(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.drops_on_transmitted()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the total number of FIFO errors (overruns on reception and underruns on
+    /// transmission) of the interface.
+    ///
+    /// Returns `None` if the interface doesn't provide these counters.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(fifo_errors) = network.fifo_errors() {
+    ///         println!("{interface_name}: {fifo_errors} FIFO errors");
+    ///     }
+    /// }
+    /// ```
+    pub fn fifo_errors(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.fifo_errors()
+            } else {
+                None
+            }
+        }
+    }
 }
 
 /// MAC address for network interface.
@@ -3,9 +3,11 @@
 use std::collections::{HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 use crate::common::impl_get_set::impl_get_set;
 use crate::common::DiskUsage;
@@ -24,6 +26,7 @@ use crate::{CpuInner, Gid, ProcessInner, SystemInner, Uid};
 /// ```
 pub struct System {
     pub(crate) inner: SystemInner,
+    last_refresh_timings: RefreshTimings,
 }
 
 impl Default for System {
@@ -84,6 +87,7 @@ impl System {
     pub fn new_with_specifics(refreshes: RefreshKind) -> Self {
         let mut s = Self {
             inner: SystemInner::new(),
+            last_refresh_timings: RefreshTimings::default(),
         };
         s.refresh_specifics(refreshes);
         s
@@ -107,15 +111,35 @@ impl System {
     /// );
     /// ```
     pub fn refresh_specifics(&mut self, refreshes: RefreshKind) {
+        let mut timings = RefreshTimings::default();
         if let Some(kind) = refreshes.memory() {
-            self.refresh_memory_specifics(kind);
+            timings.memory = measure(|| self.refresh_memory_specifics(kind));
         }
         if let Some(kind) = refreshes.cpu() {
-            self.refresh_cpu_specifics(kind);
+            timings.cpu = measure(|| self.refresh_cpu_specifics(kind));
         }
         if let Some(kind) = refreshes.processes() {
-            self.refresh_processes_specifics(ProcessesToUpdate::All, true, kind);
+            timings.processes = measure(|| {
+                self.refresh_processes_specifics(ProcessesToUpdate::All, true, kind);
+            });
         }
+        self.last_refresh_timings = timings;
+    }
+
+    /// Returns how long each part of the last [`System::refresh_specifics`] call took. This
+    /// includes the calls made by [`System::new_with_specifics`] and [`System::refresh_all`].
+    ///
+    /// The parts which weren't refreshed have a duration of `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// let timings = s.last_refresh_timings();
+    /// println!("refreshing processes took {:?}", timings.processes);
+    /// ```
+    pub fn last_refresh_timings(&self) -> RefreshTimings {
+        self.last_refresh_timings
     }
 
     /// Refreshes all system and processes information.
@@ -191,6 +215,35 @@ impl System {
         self.refresh_cpu_specifics(CpuRefreshKind::nothing().with_cpu_usage())
     }
 
+    /// Refreshes the global CPU usage (returned by [`System::global_cpu_usage`]) without
+    /// updating the usage of each CPU when the platform allows it.
+    ///
+    /// It's useful if you only need the global CPU usage on computers with a lot of CPUs. Please
+    /// note that the list of CPUs is still filled the first time this method is called.
+    ///
+    /// ⚠️ Please note that the result will very likely be inaccurate at the first call.
+    /// You need to call this method at least twice (with a bit of time between each call, like
+    /// 200 ms, take a look at [`MINIMUM_CPU_UPDATE_INTERVAL`] for more information)
+    /// to get accurate value as it uses previous results to compute the next value.
+    ///
+    /// ⚠️ On macOS and FreeBSD, the usage of each CPU is refreshed as well.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_global_cpu_usage();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_global_cpu_usage();
+    /// println!("{}%", s.global_cpu_usage());
+    /// ```
+    ///
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+    pub fn refresh_global_cpu_usage(&mut self) {
+        self.inner.refresh_global_cpu_usage()
+    }
+
     /// Refreshes CPUs frequency information.
     ///
     /// Calling this method is the same as calling
@@ -263,6 +316,36 @@ impl System {
         self.inner.refresh_cpu_specifics(refresh_kind)
     }
 
+    /// Refreshes the information of the CPU at `index` (in the list returned by
+    /// [`System::cpus`]). Returns `false` if there is no CPU at this index.
+    ///
+    /// It's useful if you only need to monitor a few CPUs on computers with a lot of CPUs. Please
+    /// note that the list of CPUs is filled (and all CPUs are refreshed) the first time this method
+    /// is called.
+    ///
+    /// ⚠️ Please note that the CPU usage will be inaccurate at the first call.
+    /// You need to call this method at least twice (with a bit of time between each call, like
+    /// 200 ms, take a look at [`MINIMUM_CPU_UPDATE_INTERVAL`] for more information)
+    /// to get accurate value as it uses previous results to compute the next value.
+    ///
+    /// ⚠️ On other platforms than Linux, all CPUs are refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{CpuRefreshKind, System};
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_cpu(0, CpuRefreshKind::nothing().with_cpu_usage());
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu(0, CpuRefreshKind::nothing().with_cpu_usage());
+    /// println!("{}%", s.cpus()[0].cpu_usage());
+    /// ```
+    ///
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+    pub fn refresh_cpu(&mut self, index: usize, refresh_kind: CpuRefreshKind) -> bool {
+        self.inner.refresh_cpu(index, refresh_kind)
+    }
+
     /// Gets all processes and updates their information, along with all the tasks each process has.
     ///
     /// It does the same as:
@@ -389,6 +472,172 @@ impl System {
         nb_updated
     }
 
+    /// Same as [`System::refresh_processes_specifics`] but returns an error listing all the
+    /// processes which couldn't be refreshed (because they are dead or because of missing
+    /// permissions for example), along with the reason.
+    ///
+    /// The other processes are refreshed nonetheless. Processes disappearing during a
+    /// [`ProcessesToUpdate::All`] refresh are not considered as errors, but other failures
+    /// (like missing permissions) are.
+    ///
+    /// On Linux, the error is the one which occurred while reading the process information. On
+    /// other platforms, the reason isn't known so processes listed in [`ProcessesToUpdate::Some`]
+    /// which couldn't be refreshed are reported with [`std::io::ErrorKind::NotFound`] and
+    /// [`ProcessesToUpdate::All`] refreshes never fail.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, RefreshError, System};
+    ///
+    /// let mut s = System::new();
+    /// match s.refresh_processes_specifics_checked(
+    ///     ProcessesToUpdate::Some(&[Pid::from(1)]),
+    ///     true,
+    ///     ProcessRefreshKind::everything(),
+    /// ) {
+    ///     Ok(nb) => println!("refreshed {nb} processes"),
+    ///     Err(RefreshError::Processes { errors, .. }) => {
+    ///         for (pid, error) in errors {
+    ///             println!("failed to refresh {pid}: {error}");
+    ///         }
+    ///     }
+    ///     Err(error) => println!("{error}"),
+    /// }
+    /// ```
+    pub fn refresh_processes_specifics_checked(
+        &mut self,
+        processes_to_update: ProcessesToUpdate<'_>,
+        remove_dead_processes: bool,
+        refresh_kind: ProcessRefreshKind,
+    ) -> Result<usize, RefreshError> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                let (nb_updated, mut errors) = self
+                    .inner
+                    .refresh_processes_specifics_checked(processes_to_update, refresh_kind);
+            } else {
+                let nb_updated = self
+                    .inner
+                    .refresh_processes_specifics(processes_to_update, refresh_kind);
+                let mut errors: Vec<(Pid, io::Error)> = Vec::new();
+            }
+        }
+        let processes = self.inner.processes_mut();
+        match processes_to_update {
+            ProcessesToUpdate::All => {
+                if remove_dead_processes {
+                    processes.retain(|_, v| v.inner.switch_updated());
+                } else {
+                    for proc in processes.values_mut() {
+                        proc.inner.switch_updated();
+                    }
+                }
+            }
+            ProcessesToUpdate::Some(pids) => {
+                for pid in pids {
+                    if processes
+                        .get_mut(pid)
+                        .is_some_and(|proc| proc.inner.switch_updated())
+                    {
+                        continue;
+                    }
+                    if remove_dead_processes {
+                        processes.remove(pid);
+                    }
+                    // If no error was returned, the process wasn't listed at all.
+                    if !errors.iter().any(|(failed_pid, _)| failed_pid == pid) {
+                        errors.push((
+                            *pid,
+                            io::Error::new(io::ErrorKind::NotFound, "process not found"),
+                        ));
+                    }
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(nb_updated)
+        } else {
+            Err(RefreshError::Processes { nb_updated, errors })
+        }
+    }
+
+    /// Refreshes the given processes and returns, for each of them, whether it was found and
+    /// updated.
+    ///
+    /// Processes which could not be refreshed (because they are dead for example) are kept in
+    /// the process list, it's up to you to remove them if needed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessRefreshKind, System};
+    ///
+    /// let mut s = System::new_all();
+    /// for (pid, alive) in s.refresh_pids_detailed(&[Pid::from(1), Pid::from(1337)], ProcessRefreshKind::everything()) {
+    ///     if !alive {
+    ///         println!("{pid} is gone");
+    ///     }
+    /// }
+    /// ```
+    pub fn refresh_pids_detailed(
+        &mut self,
+        pids: &[Pid],
+        refresh_kind: ProcessRefreshKind,
+    ) -> Vec<(Pid, bool)> {
+        self.inner
+            .refresh_processes_specifics(ProcessesToUpdate::Some(pids), refresh_kind);
+        let processes = self.inner.processes_mut();
+        pids.iter()
+            .map(|pid| {
+                let updated = processes
+                    .get_mut(pid)
+                    .is_some_and(|proc| proc.inner.switch_updated());
+                (*pid, updated)
+            })
+            .collect()
+    }
+
+    /// Refreshes all processes (removing the dead ones) and returns the [`Pid`]s of the ones
+    /// which were not in the process list before this call, sorted.
+    ///
+    /// A process whose [`Pid`] was reused since the last refresh (detected by a different
+    /// [`Process::start_time`]) is considered as a new process.
+    ///
+    /// ⚠️ On the first call (when the process list is still empty), all running processes are
+    /// returned. If you are only interested in processes started afterwards, refresh the process
+    /// list before calling this method for the first time.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, ProcessRefreshKind, System};
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// loop {
+    ///     std::thread::sleep(std::time::Duration::from_secs(1));
+    ///     for pid in s.poll_new_processes(ProcessRefreshKind::nothing()) {
+    ///         if let Some(process) = s.process(pid) {
+    ///             println!("[{pid}] started {:?}", process.name());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn poll_new_processes(&mut self, refresh_kind: ProcessRefreshKind) -> Vec<Pid> {
+        let previous = self
+            .processes()
+            .iter()
+            .map(|(pid, process)| (*pid, process.start_time()))
+            .collect::<HashMap<_, _>>();
+        self.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+        let mut new_pids = self
+            .processes()
+            .iter()
+            .filter(|(pid, process)| previous.get(pid) != Some(&process.start_time()))
+            .map(|(pid, _)| *pid)
+            .collect::<Vec<_>>();
+        new_pids.sort_unstable();
+        new_pids
+    }
+
     /// Returns the process list.
     ///
     /// ```no_run
@@ -403,6 +652,29 @@ impl System {
         self.inner.processes()
     }
 
+    /// Retrieves the information of the process corresponding to the given `pid`, without
+    /// listing the other processes. Returns `None` if no such process exists.
+    ///
+    /// It's a shortcut for when you only need to look at one process once: the returned
+    /// [`Process`] is owned and isn't kept in any [`System`]. If you want to follow a process
+    /// over time, use [`System::refresh_processes_specifics`] instead.
+    ///
+    /// ⚠️ The CPU usage needs two refreshes to be computed so [`Process::cpu_usage`] always
+    /// returns `0` for the returned process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessRefreshKind, System};
+    ///
+    /// if let Some(process) = System::process_info(Pid::from(1337), ProcessRefreshKind::everything()) {
+    ///     println!("{:?} {:?}", process.name(), process.cmd());
+    /// }
+    /// ```
+    pub fn process_info(pid: Pid, refresh_kind: ProcessRefreshKind) -> Option<Process> {
+        let mut s = Self::new();
+        s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+        s.inner.processes_mut().remove(&pid)
+    }
+
     /// Returns the process corresponding to the given `pid` or `None` if no such process exists.
     ///
     /// ```no_run
@@ -474,6 +746,129 @@ impl System {
             .filter(move |val: &&Process| val.name() == name)
     }
 
+    /// Writes the processes as [JSON Lines](https://jsonlines.org/): one JSON object per process,
+    /// each on its own line.
+    ///
+    /// Processes are serialized one at a time, so the whole list is never held in memory as
+    /// JSON. Since many small writes are done, you might want to use a [`BufWriter`].
+    ///
+    /// [`BufWriter`]: std::io::BufWriter
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufWriter;
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// let file = File::create("processes.jsonl").unwrap();
+    /// s.processes_to_jsonl(BufWriter::new(file)).unwrap();
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn processes_to_jsonl<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        for process in self.processes().values() {
+            serde_json::to_writer(&mut writer, process)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()
+    }
+
+    /// Returns the sum of the CPU usage of the processes of each user.
+    ///
+    /// Processes are grouped by their real user ID ([`Process::user_id`]), not their effective
+    /// one, so a setuid program is accounted to the user who started it. Processes whose user
+    /// ID is unknown are grouped under `None`.
+    ///
+    /// Userland threads listed in [`System::processes`] (on Linux) are skipped since their
+    /// process already accounts for them.
+    ///
+    /// ⚠️ The user IDs and the CPU usage need to have been refreshed first (with
+    /// [`ProcessRefreshKind::with_user`] and [`ProcessRefreshKind::with_cpu`]), take a look at
+    /// [`Process::cpu_usage`] for more information.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (uid, cpu_usage) in s.cpu_usage_by_user() {
+    ///     println!("{uid:?}: {cpu_usage}%");
+    /// }
+    /// ```
+    pub fn cpu_usage_by_user(&self) -> HashMap<Option<Uid>, f32> {
+        let mut usage: HashMap<Option<Uid>, f32> = HashMap::new();
+        for process in self
+            .processes()
+            .values()
+            .filter(|p| p.thread_kind() != Some(ThreadKind::Userland))
+        {
+            *usage.entry(process.user_id().cloned()).or_default() += process.cpu_usage();
+        }
+        usage
+    }
+
+    /// Returns the sum of the memory usage (in bytes) of the processes of each user.
+    ///
+    /// Like [`System::cpu_usage_by_user`], processes are grouped by their real user ID and
+    /// processes whose user ID is unknown are grouped under `None`. Userland threads are
+    /// skipped as well.
+    ///
+    /// ⚠️ Shared memory is counted once per process using it, so the sum can be greater than
+    /// the memory actually used.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (uid, memory) in s.memory_by_user() {
+    ///     println!("{uid:?}: {memory} bytes");
+    /// }
+    /// ```
+    pub fn memory_by_user(&self) -> HashMap<Option<Uid>, u64> {
+        let mut usage: HashMap<Option<Uid>, u64> = HashMap::new();
+        for process in self
+            .processes()
+            .values()
+            .filter(|p| p.thread_kind() != Some(ThreadKind::Userland))
+        {
+            *usage.entry(process.user_id().cloned()).or_default() += process.memory();
+        }
+        usage
+    }
+
+    /// Returns the highest PID among the known processes along with the maximum PID value, to
+    /// know how close the PID space is to wrapping around.
+    ///
+    /// A highest PID quickly cycling up to the maximum is a sign of many short-lived processes
+    /// being created (like a fork storm).
+    ///
+    /// On Linux, the maximum PID value is read from `/proc/sys/kernel/pid_max`.
+    ///
+    /// ⚠️ It uses the current process list, so you need to have refreshed the processes first
+    /// (with [`System::refresh_processes`] for example), otherwise it returns `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// if let Some(usage) = s.pid_usage() {
+    ///     println!("highest PID: {}/{}", usage.highest_active, usage.pid_max);
+    /// }
+    /// ```
+    pub fn pid_usage(&self) -> Option<PidUsage> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                let highest_active = self.processes().keys().map(|pid| pid.as_u32()).max()?;
+                Some(PidUsage {
+                    highest_active,
+                    pid_max: SystemInner::pid_max()?,
+                })
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns "global" CPUs usage (aka the addition of all the CPUs).
     ///
     /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
@@ -518,6 +913,93 @@ impl System {
         self.inner.cpus()
     }
 
+    /// Returns the number of runnable threads (running or waiting for a CPU) at the time of the
+    /// last CPU usage refresh.
+    ///
+    /// Unlike the [load average](System::load_average), it is not smoothed over time, making it
+    /// a better signal of the current scheduler pressure.
+    ///
+    /// ⚠️ You need to have refreshed the CPU usage (with [`System::refresh_cpu_usage`] for
+    /// example) before calling this method, otherwise it returns `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_cpu_usage();
+    /// if let Some(count) = s.runnable_process_count() {
+    ///     println!("{count} runnable threads");
+    /// }
+    /// ```
+    pub fn runnable_process_count(&self) -> Option<u32> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.runnable_process_count()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of threads blocked waiting for I/O to complete at the time of the last
+    /// CPU usage refresh.
+    ///
+    /// ⚠️ You need to have refreshed the CPU usage (with [`System::refresh_cpu_usage`] for
+    /// example) before calling this method, otherwise it returns `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_cpu_usage();
+    /// if let Some(count) = s.blocked_process_count() {
+    ///     println!("{count} threads blocked on I/O");
+    /// }
+    /// ```
+    pub fn blocked_process_count(&self) -> Option<u32> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.blocked_process_count()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the CPUs are not all of the same kind, like the "performance" and
+    /// "efficiency" cores of Intel hybrid CPUs or the "big" and "LITTLE" cores of ARM CPUs.
+    ///
+    /// On Linux, a CPU is considered hybrid if both the `cpu_core` and `cpu_atom` PMUs (Intel)
+    /// list CPUs in `/sys/devices`, or if the `cpu_capacity` of the CPUs (ARM) are not all the
+    /// same. Other kinds of hybrid CPUs are not detected.
+    ///
+    /// ⚠️ You need to have refreshed the CPUs list (with [`System::refresh_cpu_list`] for
+    /// example) before calling this method, otherwise it returns `false`.
+    ///
+    /// ⚠️ This method always returns `false` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing()),
+    /// );
+    /// println!("hybrid CPU: {}", s.is_hybrid_cpu());
+    /// ```
+    pub fn is_hybrid_cpu(&self) -> bool {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.is_hybrid_cpu()
+            } else {
+                false
+            }
+        }
+    }
+
     /// Returns the RAM size in bytes.
     ///
     /// ```no_run
@@ -617,27 +1099,118 @@ impl System {
         self.inner.used_swap()
     }
 
-    /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
+    /// Returns how much data (in bytes) the swap can hold, taking the compression of the zram
+    /// devices into account.
     ///
-    /// This information is computed every time the method is called.
+    /// The data stored in a zram device is compressed in memory, so what it can hold is limited
+    /// by its size but also by the memory it can still use (its `mem_limit` or its share of the
+    /// [available memory](System::available_memory), which is split between the zram devices)
+    /// multiplied by its current compression ratio. This is a more realistic figure than
+    /// [`System::total_swap`] on systems using zram.
     ///
     /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
     /// calling this method.
     ///
-    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
-    /// systems.
+    /// ⚠️ On other platforms than Linux (or if there is no zram swap device), it returns the
+    /// same value as [`System::total_swap`].
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("limits: {:?}", s.cgroup_limits());
+    /// println!("{} bytes", s.effective_swap_capacity());
     /// ```
-    pub fn cgroup_limits(&self) -> Option<CGroupLimits> {
-        self.inner.cgroup_limits()
-    }
-
-    /// Returns system uptime (in seconds).
+    pub fn effective_swap_capacity(&self) -> u64 {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.effective_swap_capacity()
+            } else {
+                self.total_swap()
+            }
+        }
+    }
+
+    /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
+    ///
+    /// This information is computed every time the method is called.
+    ///
+    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
+    /// calling this method.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// println!("limits: {:?}", s.cgroup_limits());
+    /// ```
+    pub fn cgroup_limits(&self) -> Option<CGroupLimits> {
+        self.inner.cgroup_limits()
+    }
+
+    /// Returns the number of pages swapped in and out since the system booted.
+    ///
+    /// These counters are cumulative, so you need to compare them between two refreshes to
+    /// know the swap activity rate (a high rate is a sign of thrashing).
+    ///
+    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) (or
+    /// [`refresh_memory_specifics`](System::refresh_memory_specifics) with swap enabled) at
+    /// least once before calling this method.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory();
+    /// if let Some(activity) = s.swap_activity() {
+    ///     println!("{} pages in, {} pages out", activity.pages_in, activity.pages_out);
+    /// }
+    /// ```
+    pub fn swap_activity(&self) -> Option<SwapActivity> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.swap_activity()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if pages were swapped in or out between the last two memory refreshes.
+    ///
+    /// It is computed from the [`swap_activity`](System::swap_activity) counters, so it returns
+    /// `None` until the swap was refreshed twice with
+    /// [`refresh_memory`](System::refresh_memory) (or
+    /// [`refresh_memory_specifics`](System::refresh_memory_specifics) with swap enabled).
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_memory();
+    /// if s.is_swapping() == Some(true) {
+    ///     println!("the system is currently swapping");
+    /// }
+    /// ```
+    pub fn is_swapping(&self) -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.is_swapping()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns system uptime (in seconds).
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
@@ -684,6 +1257,286 @@ impl System {
         SystemInner::load_average()
     }
 
+    /// Returns a [`SystemSummary`] gathering the main system values in one struct.
+    ///
+    /// The CPU, memory, swap and processes values come from the last refresh. You need to call
+    /// the matching refresh methods (like [`System::refresh_all`]) before calling this method.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// let summary = s.summary();
+    /// println!("{} processes running", summary.process_count);
+    /// ```
+    pub fn summary(&self) -> SystemSummary {
+        SystemSummary {
+            global_cpu_usage: self.global_cpu_usage(),
+            used_memory: self.used_memory(),
+            total_memory: self.total_memory(),
+            used_swap: self.used_swap(),
+            total_swap: self.total_swap(),
+            process_count: self.processes().len(),
+            uptime: Self::uptime(),
+            load_average: Self::load_average(),
+        }
+    }
+
+    /// Returns the number of file descriptors currently allocated by the whole system and the
+    /// maximum number of file descriptors the system can allocate, as `(allocated, max)`.
+    ///
+    /// It is the system-wide limit which matters for "too many open files" errors, unlike the
+    /// per-process limit used by [`set_open_files_limit`][crate::set_open_files_limit].
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some((allocated, max)) = System::open_file_descriptors() {
+    ///     println!("{allocated} file descriptors open out of {max}");
+    /// }
+    /// ```
+    pub fn open_file_descriptors() -> Option<(u64, u64)> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::open_file_descriptors()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of bits of entropy available in the kernel's entropy pool.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(entropy) = System::available_entropy() {
+    ///     println!("{entropy} bits of entropy available");
+    /// }
+    /// ```
+    pub fn available_entropy() -> Option<u32> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::available_entropy()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the kernel activity counters (interrupts, context switches, etc).
+    ///
+    /// Except for [`procs_running`](KernelStats::procs_running) and
+    /// [`procs_blocked`](KernelStats::procs_blocked), these counters are cumulative, so you need
+    /// to compare them between two calls to compute rates.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(stats) = System::kernel_stats() {
+    ///     println!("{} interrupts serviced", stats.interrupts);
+    /// }
+    /// ```
+    pub fn kernel_stats() -> Option<KernelStats> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::kernel_stats()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the pressure stall information (PSI) of the CPU, memory and I/O, which tells how
+    /// much time tasks spend waiting for these resources. It is a better signal of resource
+    /// contention than the [load average](System::load_average).
+    ///
+    /// On Linux, it is read from `/proc/pressure/{cpu,memory,io}`. Returns `None` if the kernel
+    /// doesn't support PSI.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(pressure) = System::pressure() {
+    ///     println!("I/O pressure: {}%", pressure.io.full_avg10);
+    /// }
+    /// ```
+    pub fn pressure() -> Option<Pressure> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::pressure()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the command line the kernel was booted with.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(cmdline) = System::kernel_cmdline() {
+    ///     println!("kernel command line: {cmdline}");
+    /// }
+    /// ```
+    pub fn kernel_cmdline() -> Option<String> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::kernel_cmdline()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the name of the init process (PID 1) of the current PID namespace.
+    ///
+    /// In containers, it is often the application itself, which makes it useful to know what a
+    /// container is running without having to refresh all processes.
+    ///
+    /// On Linux, it reads `/proc/1/comm` (limited to 15 characters), falling back to the file
+    /// name of the first argument of `/proc/1/cmdline`.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(name) = System::init_process_name() {
+    ///     println!("init process: {name:?}");
+    /// }
+    /// ```
+    pub fn init_process_name() -> Option<OsString> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::init_process_name()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the system was booted with UEFI secure boot enabled.
+    ///
+    /// Returns `None` if the system wasn't booted with UEFI or if the information couldn't be
+    /// retrieved (for example if `efivarfs` isn't mounted or isn't readable on Linux).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(enabled) = System::secure_boot_enabled() {
+    ///     println!("secure boot enabled: {enabled}");
+    /// }
+    /// ```
+    pub fn secure_boot_enabled() -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android", target_os = "windows"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::secure_boot_enabled()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns whether a TPM (Trusted Platform Module) is present and its version.
+    ///
+    /// * On Linux, it looks for `/sys/class/tpm/tpm0` and reads its `tpm_version_major`.
+    /// * On Windows, it uses `Tbsi_GetDeviceInfo`.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function always returns `None` on other platforms than Linux and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(tpm) = System::tpm() {
+    ///     println!("TPM present: {} (version: {:?})", tpm.present, tpm.version);
+    /// }
+    /// ```
+    pub fn tpm() -> Option<TpmInfo> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android", target_os = "windows"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::tpm()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the name of the user logged in the active graphical session.
+    ///
+    /// * On Linux, it looks for an active graphical logind session in `/run/systemd/sessions`,
+    ///   then falls back to the owner of the `:0` X display socket if it isn't a system user
+    ///   (whose UID is lower than `UID_MIN` from `/etc/login.defs`).
+    /// * On macOS, it returns the owner of `/dev/console` (like `SCDynamicStoreCopyConsoleUser`).
+    /// * On Windows, it returns the user of the session attached to the console.
+    ///
+    /// Returns `None` if nobody is logged in a graphical session (on headless systems for
+    /// example).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This function always returns `None` on other platforms than Linux, macOS and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(user) = System::active_desktop_user() {
+    ///     println!("{user} is using the computer");
+    /// }
+    /// ```
+    pub fn active_desktop_user() -> Option<String> {
+        cfg_if! {
+            if #[cfg(all(
+                any(
+                    target_os = "linux",
+                    target_os = "android",
+                    target_os = "macos",
+                    target_os = "windows"
+                ),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::active_desktop_user()
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns the system name.
     ///
     /// | example platform | value of `System::name()` |
@@ -817,7 +1670,14 @@ impl System {
         SystemInner::distribution_id_like()
     }
 
-    /// Returns the system hostname based off DNS.
+    /// Returns the local hostname of the system, without any DNS resolution.
+    ///
+    /// * On Linux, it uses `gethostname`.
+    /// * On FreeBSD and macOS, it uses the `kern.hostname` sysctl.
+    /// * On Windows, it uses `GetComputerNameExW` with `ComputerNamePhysicalDnsHostname`, which
+    ///   doesn't include the domain.
+    ///
+    /// If you want the fully qualified domain name, use [`System::fqdn`].
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
@@ -830,6 +1690,26 @@ impl System {
         SystemInner::host_name()
     }
 
+    /// Returns the fully qualified domain name (FQDN) of the system, like `host.example.com`.
+    ///
+    /// * On Linux, FreeBSD and macOS, it is the canonical name of [`System::host_name`],
+    ///   resolved with `getaddrinfo` (so it depends on `/etc/hosts` and on the DNS
+    ///   configuration and can block while waiting for the DNS server).
+    /// * On Windows, it uses `GetComputerNameExW` with `ComputerNamePhysicalDnsFullyQualified`.
+    ///
+    /// If the system isn't part of a domain, it can be the same as [`System::host_name`].
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("FQDN: {:?}", System::fqdn());
+    /// ```
+    pub fn fqdn() -> Option<String> {
+        SystemInner::fqdn()
+    }
+
     /// Returns the CPU architecture (eg. x86, amd64, aarch64, ...).
     ///
     /// **Important**: this information is computed every time this function is called.
@@ -843,9 +1723,51 @@ impl System {
         SystemInner::cpu_arch().unwrap_or_else(|| std::env::consts::ARCH.to_owned())
     }
 
-    /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
+    /// Returns the architecture details of the host.
     ///
-    /// In case there are multiple CPUs, it will combine the physical core count of all the CPUs.
+    /// Contrary to [`System::cpu_arch`], on Windows it returns the host architecture even if the
+    /// current process is running under emulation (a 32-bit process on a 64-bit system for
+    /// example). The `bits` are deduced from the architecture name and fall back to the ones of
+    /// the compiled target if the architecture is unknown.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let details = System::arch_details();
+    /// println!("{}: {} bits, {:?} endian", details.arch, details.bits, details.endianness);
+    /// ```
+    pub fn arch_details() -> ArchDetails {
+        cfg_if! {
+            if #[cfg(all(target_os = "windows", not(feature = "unknown-ci")))] {
+                let arch = SystemInner::native_cpu_arch();
+            } else {
+                let arch = SystemInner::cpu_arch();
+            }
+        }
+        let arch = arch.unwrap_or_else(|| std::env::consts::ARCH.to_owned());
+        let bits = if arch.contains("64") || arch == "s390x" {
+            64
+        } else if arch.is_empty() || arch == "unknown" {
+            usize::BITS as u8
+        } else {
+            32
+        };
+        ArchDetails {
+            bits,
+            endianness: if cfg!(target_endian = "big") {
+                Endianness::Big
+            } else {
+                Endianness::Little
+            },
+            arch,
+        }
+    }
+
+    /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
+    ///
+    /// In case there are multiple CPUs, it will combine the physical core count of all the CPUs.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
@@ -858,6 +1780,219 @@ impl System {
     pub fn physical_core_count() -> Option<usize> {
         SystemInner::physical_core_count()
     }
+
+    /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
+    ///
+    /// Unlike [`System::physical_core_count`], the value is only computed the first time this
+    /// method is called and is then kept in this `System` instance.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// println!("{:?}", s.physical_core_count_cached());
+    /// ```
+    pub fn physical_core_count_cached(&mut self) -> Option<usize> {
+        self.inner.physical_core_count_cached()
+    }
+
+    /// Returns the number of CPUs the current process can actually use. This is the number you
+    /// want to use to size a thread pool.
+    ///
+    /// On Linux, it takes into account the CPU affinity of the current process and the CPU quota
+    /// of its cgroup and of its parent cgroups (`cpu.max` for cgroups v2, `cpu.cfs_quota_us` for
+    /// cgroups v1), which can be much lower than the number of CPUs when running inside a
+    /// container. On other platforms, it relies on [`std::thread::available_parallelism`].
+    ///
+    /// It always returns at least `1`.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{} CPUs available", System::available_parallelism());
+    /// ```
+    pub fn available_parallelism() -> usize {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::available_parallelism()
+            } else {
+                std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+            }
+        }
+    }
+
+    /// Returns the logical CPUs the current process is confined to by the cpuset of its cgroup,
+    /// or `None` if there is no such restriction.
+    ///
+    /// On Linux, it reads `cpuset.cpus.effective` (or `cpuset.effective_cpus` for cgroups v1) of
+    /// the cgroup of the current process.
+    ///
+    /// ⚠️ This function always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(cpus) = System::cpuset_cpus() {
+    ///     println!("confined to CPUs {cpus:?}");
+    /// }
+    /// ```
+    pub fn cpuset_cpus() -> Option<Vec<usize>> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::cpuset_cpus()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of CPUs which are currently online.
+    ///
+    /// On Linux, CPUs can be taken offline (hotplug), in which case this number is lower than
+    /// [`System::possible_cpu_count`]. On other platforms, it returns the number of logical
+    /// CPUs (from [`std::thread::available_parallelism`]).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{} CPUs online", System::online_cpu_count());
+    /// ```
+    pub fn online_cpu_count() -> usize {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::online_cpu_count()
+            } else {
+                std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+            }
+        }
+    }
+
+    /// Returns the maximum number of CPUs the system can have, including the ones which are
+    /// offline or not plugged yet. Use it to size per-CPU data structures.
+    ///
+    /// On other platforms than Linux, it returns the number of logical CPUs (from
+    /// [`std::thread::available_parallelism`]).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{} CPUs possible", System::possible_cpu_count());
+    /// ```
+    pub fn possible_cpu_count() -> usize {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                SystemInner::possible_cpu_count()
+            } else {
+                std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+            }
+        }
+    }
+
+    /// Returns `true` if simultaneous multithreading (SMT, or hyper-threading) is enabled, or
+    /// `None` if it couldn't be determined.
+    ///
+    /// On Linux, it reads `/sys/devices/system/cpu/smt/active`. On other platforms (or if this
+    /// file doesn't exist), it compares the number of logical CPUs with
+    /// [`System::physical_core_count`].
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("SMT enabled: {:?}", System::smt_enabled());
+    /// ```
+    pub fn smt_enabled() -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                if let Some(active) = SystemInner::smt_enabled() {
+                    return Some(active);
+                }
+            }
+        }
+        let physical_core_count = Self::physical_core_count()?;
+        if physical_core_count == 0 {
+            return None;
+        }
+        Some(Self::online_cpu_count() > physical_core_count)
+    }
+
+    /// Returns the transparent huge pages settings or `None` if they couldn't be retrieved.
+    ///
+    /// On Linux, it reads `/sys/kernel/mm/transparent_hugepage/enabled` and
+    /// `/sys/kernel/mm/transparent_hugepage/defrag`.
+    ///
+    /// ⚠️ This function always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(thp) = System::transparent_huge_pages() {
+    ///     println!("enabled: {:?}, defrag: {:?}", thp.enabled, thp.defrag);
+    /// }
+    /// ```
+    pub fn transparent_huge_pages() -> Option<ThpStatus> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::transparent_huge_pages()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the system needs to be rebooted to finish an update, or `None` if it
+    /// couldn't be determined.
+    ///
+    /// On Linux, it checks if `/run/reboot-required` exists (created on Debian and Ubuntu when an
+    /// installed package requires a reboot). Otherwise, it checks if the running kernel (see
+    /// [`System::kernel_version`]) is older than the newest `/boot/vmlinuz-*` one.
+    ///
+    /// ⚠️ This function always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if System::reboot_required() == Some(true) {
+    ///     println!("a reboot is needed to finish the update");
+    /// }
+    /// ```
+    pub fn reboot_required() -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::reboot_required()
+            } else {
+                None
+            }
+        }
+    }
 }
 
 /// A struct representing system load average value.
@@ -886,6 +2021,197 @@ pub struct LoadAvg {
     pub fifteen: f64,
 }
 
+/// Durations of the different parts of a [`System::refresh_specifics`] call.
+///
+/// It is returned by [`System::last_refresh_timings`][crate::System::last_refresh_timings].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let timings = s.last_refresh_timings();
+/// println!(
+///     "cpu: {:?}, memory: {:?}, processes: {:?}",
+///     timings.cpu,
+///     timings.memory,
+///     timings.processes,
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefreshTimings {
+    /// Time spent refreshing the CPUs.
+    pub cpu: Duration,
+    /// Time spent refreshing the memory.
+    pub memory: Duration,
+    /// Time spent refreshing the processes.
+    pub processes: Duration,
+}
+
+/// Returns how long `f` took to run.
+fn measure<F: FnOnce()>(f: F) -> Duration {
+    // `Instant::now` panics on some targets we don't support (like `wasm32-unknown-unknown`).
+    if !crate::IS_SUPPORTED_SYSTEM {
+        f();
+        return Duration::ZERO;
+    }
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+/// Main system values gathered in one struct.
+///
+/// It is returned by [`System::summary`][crate::System::summary].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let summary = s.summary();
+/// println!(
+///     "memory: {}/{} bytes, uptime: {} seconds",
+///     summary.used_memory,
+///     summary.total_memory,
+///     summary.uptime,
+/// );
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct SystemSummary {
+    /// Global CPU usage (in %), see [`System::global_cpu_usage`][crate::System::global_cpu_usage].
+    pub global_cpu_usage: f32,
+    /// Used RAM (in bytes).
+    pub used_memory: u64,
+    /// Total RAM (in bytes).
+    pub total_memory: u64,
+    /// Used swap (in bytes).
+    pub used_swap: u64,
+    /// Total swap (in bytes).
+    pub total_swap: u64,
+    /// Number of processes.
+    pub process_count: usize,
+    /// System uptime (in seconds).
+    pub uptime: u64,
+    /// System load average.
+    pub load_average: LoadAvg,
+}
+
+/// Version of a TPM (Trusted Platform Module).
+///
+/// It is used in [`TpmInfo`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TpmVersion {
+    /// TPM 1.2.
+    V1,
+    /// TPM 2.0.
+    V2,
+    /// The version couldn't be retrieved (or there is no TPM).
+    Unknown,
+}
+
+/// Information about the TPM (Trusted Platform Module) of the system.
+///
+/// It is returned by [`System::tpm`][crate::System::tpm].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TpmInfo {
+    /// Version of the TPM.
+    pub version: TpmVersion,
+    /// Whether a TPM was found.
+    pub present: bool,
+}
+
+/// How much of the PID space is used.
+///
+/// It is returned by [`System::pid_usage`][crate::System::pid_usage].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PidUsage {
+    /// Highest PID among the known processes.
+    pub highest_active: u32,
+    /// Value above which PIDs wrap around.
+    pub pid_max: u32,
+}
+
+/// Mode of a transparent huge pages setting.
+///
+/// It is used in [`ThpStatus`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThpMode {
+    /// Applies to all memory regions.
+    Always,
+    /// Only applies to memory regions marked with `madvise(MADV_HUGEPAGE)`.
+    MadviseOnly,
+    /// Disabled.
+    Never,
+    /// Only for the `defrag` setting: memory is reclaimed and compacted in the background.
+    Defer,
+    /// Only for the `defrag` setting: like [`ThpMode::MadviseOnly`] for memory regions marked
+    /// with `madvise(MADV_HUGEPAGE)` and like [`ThpMode::Defer`] for the other ones.
+    DeferMadvise,
+}
+
+/// Transparent huge pages settings.
+///
+/// It is returned by [`System::transparent_huge_pages`][crate::System::transparent_huge_pages].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// if let Some(thp) = System::transparent_huge_pages() {
+///     println!("{:?}", thp.enabled);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThpStatus {
+    /// When transparent huge pages are used.
+    pub enabled: ThpMode,
+    /// When the kernel compacts memory to make huge pages available.
+    pub defrag: ThpMode,
+}
+
+/// Quotes `arg` for POSIX shells if it contains special characters.
+#[cfg(any(not(windows), feature = "unknown-ci"))]
+fn push_shell_quoted(out: &mut String, arg: &str) {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "@%+=:,./-_".contains(c);
+    if !arg.is_empty() && arg.chars().all(is_safe) {
+        out.push_str(arg);
+        return;
+    }
+    // Nothing is special inside single quotes, so only single quotes need to be escaped.
+    out.push('\'');
+    out.push_str(&arg.replace('\'', r"'\''"));
+    out.push('\'');
+}
+
+/// Byte order of the system.
+///
+/// It is used in [`ArchDetails`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// Architecture details of the host.
+///
+/// It is returned by [`System::arch_details`][crate::System::arch_details].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let details = System::arch_details();
+/// println!("{} bits", details.bits);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchDetails {
+    /// Word size of the host architecture (usually 32 or 64).
+    pub bits: u8,
+    /// Byte order of the host.
+    pub endianness: Endianness,
+    /// Name of the host architecture (eg. x86, x86_64, aarch64, ...).
+    pub arch: String,
+}
+
 /// An enum representing signals on UNIX-like systems.
 ///
 /// On non-unix systems, this enum is mostly useless and is only there to keep coherency between
@@ -1017,43 +2343,495 @@ pub struct CGroupLimits {
     pub rss: u64,
 }
 
-/// Enum describing the different status of a process.
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
-pub enum ProcessStatus {
-    /// ## Linux
-    ///
-    /// Idle kernel thread.
-    ///
-    /// ## macOs/FreeBSD
-    ///
-    /// Process being created by fork.
-    ///
-    /// ## Other OS
-    ///
-    /// Not available.
-    Idle,
-    /// Running.
-    Run,
-    /// ## Linux
-    ///
-    /// Sleeping in an interruptible waiting.
-    ///
-    /// ## macOS/FreeBSD
-    ///
-    /// Sleeping on an address.
-    ///
-    /// ## Other OS
-    ///
-    /// Not available.
-    Sleep,
-    /// ## Linux
-    ///
-    /// Stopped (on a signal) or (before Linux 2.6.33) trace stopped.
-    ///
-    /// ## macOS/FreeBSD
-    ///
-    /// Process debugging or suspension.
+/// Resources usage of the cgroup of a process, as accounted by the cgroup itself.
+///
+/// It is returned by [`Process::cgroup_usage`][crate::Process::cgroup_usage].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     if let Some(usage) = process.cgroup_usage() {
+///         println!("cgroup memory: {} bytes", usage.memory_current);
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CGroupUsage {
+    /// Memory (in bytes) currently used by the cgroup (`memory.current`).
+    pub memory_current: u64,
+    /// Highest memory usage (in bytes) of the cgroup (`memory.peak`). It is `None` on kernels
+    /// older than Linux 5.19.
+    pub memory_peak: Option<u64>,
+    /// Total CPU time (in microseconds) used by the cgroup (`usage_usec` in `cpu.stat`).
+    pub cpu_usage_usec: u64,
+}
+
+/// A cgroup a process belongs to.
+///
+/// It is returned by [`Process::cgroups`][crate::Process::cgroups].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     for cgroup in process.cgroups().unwrap_or_default() {
+///         println!("{:?}: {}", cgroup.controllers, cgroup.path);
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct CGroupMembership {
+    /// Controllers bound to this hierarchy (like `cpu` and `cpuacct`). It is empty for the
+    /// unified cgroup v2 hierarchy.
+    pub controllers: Vec<String>,
+    /// Path of the cgroup, relative to the root of its hierarchy.
+    pub path: String,
+}
+
+/// Realtime scheduling parameters of a process.
+///
+/// It is returned by [`Process::realtime_params`][crate::Process::realtime_params].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     if let Some(params) = process.realtime_params() {
+///         println!("{params:?}");
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RtParams {
+    /// Realtime priority (between 1 and 99) for `SCHED_FIFO` and `SCHED_RR` processes.
+    pub priority: i32,
+    /// Runtime (in nanoseconds) of a `SCHED_DEADLINE` process.
+    pub runtime_ns: u64,
+    /// Period (in nanoseconds) of a `SCHED_DEADLINE` process.
+    pub period_ns: u64,
+    /// Relative deadline (in nanoseconds) of a `SCHED_DEADLINE` process.
+    pub deadline_ns: u64,
+}
+
+/// Snapshot of the CPU time of a process, returned by
+/// [`Process::cpu_baseline`][crate::Process::cpu_baseline].
+///
+/// It is used to compute the CPU usage of a process over a custom interval with
+/// [`Process::cpu_usage_since`][crate::Process::cpu_usage_since].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     let baseline = process.cpu_baseline();
+///     println!("{baseline:?}");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CpuBaseline {
+    pid: Pid,
+    cpu_time: u64,
+    global_cpu_time: u64,
+}
+
+/// Number of I/O syscalls made by a process since it started.
+///
+/// It is returned by [`Process::io_syscalls`][crate::Process::io_syscalls].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     if let Some(syscalls) = process.io_syscalls() {
+///         println!("{syscalls:?}");
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IoSyscalls {
+    /// Number of read syscalls.
+    pub read_calls: u64,
+    /// Number of write syscalls.
+    pub write_calls: u64,
+}
+
+/// Permissions of the executable of a process, as returned by [`Process::exe_permissions`].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     if let Some(permissions) = process.exe_permissions() {
+///         println!("{permissions:?}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExePermissions {
+    /// Whether the set-user-ID bit is set.
+    pub setuid: bool,
+    /// Whether the set-group-ID bit is set.
+    pub setgid: bool,
+    /// Whether the executable has file capabilities (the `security.capability` extended
+    /// attribute).
+    pub has_file_caps: bool,
+    /// User ID of the owner of the executable.
+    pub owner_uid: Uid,
+}
+
+/// Scheduling autogroup of a process.
+///
+/// It is returned by [`Process::autogroup`][crate::Process::autogroup].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     if let Some(autogroup) = process.autogroup() {
+///         println!("{autogroup:?}");
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutogroupInfo {
+    /// ID of the autogroup.
+    pub id: u64,
+    /// Nice value of the autogroup (between -20 and 19), applied to the group as a whole.
+    pub nice: i32,
+}
+
+/// Scheduling statistics of a process.
+///
+/// It is returned by [`Process::sched_stats`][crate::Process::sched_stats].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     if let Some(stats) = process.sched_stats() {
+///         println!("waited {} ns to run", stats.wait_time_ns);
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchedStats {
+    /// Time (in nanoseconds) spent running on a CPU.
+    pub run_time_ns: u64,
+    /// Time (in nanoseconds) spent waiting on a run queue.
+    pub wait_time_ns: u64,
+    /// Number of timeslices run on a CPU.
+    pub timeslices: u64,
+}
+
+/// Scheduling policy of a process.
+///
+/// It is returned in [`SchedulingSummary::policy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum SchedPolicy {
+    /// Default time-sharing policy (`SCHED_OTHER`).
+    Other,
+    /// First-in first-out realtime policy (`SCHED_FIFO`).
+    Fifo,
+    /// Round-robin realtime policy (`SCHED_RR`).
+    RoundRobin,
+    /// Time-sharing policy for batch processes (`SCHED_BATCH`).
+    Batch,
+    /// Policy for very low priority background processes (`SCHED_IDLE`).
+    Idle,
+    /// Deadline policy (`SCHED_DEADLINE`).
+    Deadline,
+    /// Unknown policy.
+    Unknown(u32),
+}
+
+/// Scheduling information of a process, gathered in a single call.
+///
+/// It is returned by [`Process::scheduling_summary`][crate::Process::scheduling_summary].
+/// Each field is `None` if it couldn't be retrieved.
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     if let Some(summary) = process.scheduling_summary() {
+///         println!("{summary:?}");
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchedulingSummary {
+    /// Scheduling policy.
+    pub policy: Option<SchedPolicy>,
+    /// Nice value (between -20 and 19).
+    pub nice: Option<i32>,
+    /// CPU the process last ran on.
+    pub last_cpu: Option<usize>,
+    /// Number of times the process gave up the CPU by itself (waiting for I/O for example).
+    pub voluntary_context_switches: Option<u64>,
+    /// Number of times the process was preempted.
+    pub involuntary_context_switches: Option<u64>,
+    /// Time (in nanoseconds) spent waiting on a run queue.
+    pub wait_time_ns: Option<u64>,
+}
+
+/// Split of the resident memory of a process between its different kinds of pages.
+///
+/// It is returned by [`Process::rss_breakdown`][crate::Process::rss_breakdown].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     if let Some(rss) = process.rss_breakdown() {
+///         println!("heap and stack: {} bytes", rss.anonymous);
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RssBreakdown {
+    /// Resident anonymous memory (in bytes), like the heap and the stack.
+    pub anonymous: u64,
+    /// Resident file mappings (in bytes), like the executable and the shared libraries.
+    pub file_backed: u64,
+    /// Resident shared memory (in bytes), including `tmpfs` mappings and `SysV` shared memory.
+    pub shmem: u64,
+}
+
+/// Enum describing the different kinds of file descriptors.
+///
+/// It is used by [`OpenFile`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum FdKind {
+    /// Regular file or directory.
+    File,
+    /// Socket.
+    Socket,
+    /// Pipe (or FIFO).
+    Pipe,
+    /// Anonymous file descriptor without a path (like an `eventfd` or an `epoll` instance).
+    Anon,
+    /// Character device (like a terminal or `/dev/null`).
+    Char,
+}
+
+/// File opened by a process.
+///
+/// It is returned by [`Process::open_files`][crate::Process::open_files].
+///
+/// ```no_run
+/// use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+///
+/// let mut s = System::new();
+/// s.refresh_processes_specifics(
+///     ProcessesToUpdate::All,
+///     true,
+///     ProcessRefreshKind::nothing().with_open_files(),
+/// );
+/// if let Some(files) = s.process(Pid::from(1337)).and_then(|process| process.open_files()) {
+///     for file in files {
+///         println!("{}: {:?}", file.fd, file.path);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenFile {
+    /// File descriptor number.
+    pub fd: i32,
+    /// Kind of the file descriptor.
+    pub kind: FdKind,
+    /// Path of the file, if it has one.
+    pub path: Option<PathBuf>,
+}
+
+/// Number of TCP sockets of a process by state.
+///
+/// It is returned by [`Process::socket_summary`][crate::Process::socket_summary].
+///
+/// ```no_run
+/// use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+///
+/// let mut s = System::new();
+/// s.refresh_processes_specifics(
+///     ProcessesToUpdate::All,
+///     true,
+///     ProcessRefreshKind::nothing().with_open_files(),
+/// );
+/// if let Some(summary) = s.process(Pid::from(1337)).and_then(|process| process.socket_summary()) {
+///     println!("{} established connections", summary.established);
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SocketSummary {
+    /// Number of listening sockets.
+    pub listening: usize,
+    /// Number of established connections.
+    pub established: usize,
+    /// Number of connections in the `TIME_WAIT` state.
+    pub time_wait: usize,
+}
+
+/// Contains the number of pages swapped in and out since the system booted.
+///
+/// It is returned by [`System::swap_activity`][crate::System::swap_activity].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let mut s = System::new();
+/// s.refresh_memory();
+/// if let Some(activity) = s.swap_activity() {
+///     println!("{} pages in, {} pages out", activity.pages_in, activity.pages_out);
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapActivity {
+    /// Number of pages swapped in from the swap.
+    pub pages_in: u64,
+    /// Number of pages swapped out to the swap.
+    pub pages_out: u64,
+}
+
+/// Kernel activity counters since the system booted.
+///
+/// It is returned by [`System::kernel_stats`][crate::System::kernel_stats].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// if let Some(stats) = System::kernel_stats() {
+///     println!("{} context switches", stats.context_switches);
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelStats {
+    /// Number of interrupts serviced.
+    pub interrupts: u64,
+    /// Number of context switches.
+    pub context_switches: u64,
+    /// Number of processes (and threads) created.
+    pub processes_forked: u64,
+    /// Number of processes currently running.
+    pub procs_running: u64,
+    /// Number of processes currently blocked waiting for I/O to complete.
+    pub procs_blocked: u64,
+}
+
+/// Pressure stall information of a resource: the share of time (in %) during which tasks were
+/// stalled waiting for it, averaged over the last 10 and 60 seconds.
+///
+/// It is used in [`Pressure`].
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct PressureStats {
+    /// Share of time at least one task was stalled, over the last 10 seconds.
+    pub some_avg10: f32,
+    /// Share of time at least one task was stalled, over the last 60 seconds.
+    pub some_avg60: f32,
+    /// Share of time all non-idle tasks were stalled at the same time, over the last 10 seconds.
+    pub full_avg10: f32,
+    /// Share of time all non-idle tasks were stalled at the same time, over the last 60 seconds.
+    pub full_avg60: f32,
+}
+
+/// Pressure stall information of the CPU, memory and I/O.
+///
+/// It is returned by [`System::pressure`][crate::System::pressure].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// if let Some(pressure) = System::pressure() {
+///     println!("memory pressure: {}%", pressure.memory.some_avg10);
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Pressure {
+    /// CPU pressure. The `full` values are always `0` before Linux 5.13.
+    pub cpu: PressureStats,
+    /// Memory pressure.
+    pub memory: PressureStats,
+    /// I/O pressure.
+    pub io: PressureStats,
+}
+
+/// Memory and swap information, returned by [`memory_info`][crate::memory_info].
+///
+/// All values are in bytes.
+///
+/// ```no_run
+/// let info = sysinfo::memory_info();
+/// println!("{} / {} bytes used", info.used, info.total);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryInfo {
+    /// Total amount of RAM.
+    pub total: u64,
+    /// Amount of free RAM.
+    ///
+    /// Check [`System::free_memory`][crate::System::free_memory] for more information.
+    pub free: u64,
+    /// Amount of available RAM.
+    ///
+    /// Check [`System::available_memory`][crate::System::available_memory] for more
+    /// information.
+    pub available: u64,
+    /// Amount of used RAM.
+    pub used: u64,
+    /// Total amount of swap.
+    pub total_swap: u64,
+    /// Amount of free swap.
+    pub free_swap: u64,
+}
+
+/// Enum describing the different status of a process.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ProcessStatus {
+    /// ## Linux
+    ///
+    /// Idle kernel thread.
+    ///
+    /// ## macOs/FreeBSD
+    ///
+    /// Process being created by fork.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Idle,
+    /// Running.
+    Run,
+    /// ## Linux
+    ///
+    /// Sleeping in an interruptible waiting.
+    ///
+    /// ## macOS/FreeBSD
+    ///
+    /// Sleeping on an address.
+    ///
+    /// ## Other OS
+    ///
+    /// Not available.
+    Sleep,
+    /// ## Linux
+    ///
+    /// Stopped (on a signal) or (before Linux 2.6.33) trace stopped.
+    ///
+    /// ## macOS/FreeBSD
+    ///
+    /// Process debugging or suspension.
     ///
     /// ## Other OS
     ///
@@ -1145,6 +2923,55 @@ pub enum ThreadKind {
     Userland,
 }
 
+/// Enum describing the different kinds of processes.
+///
+/// It is returned by [`Process::kind`][crate::Process::kind].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ProcessKind {
+    /// Kernel thread.
+    Kernel,
+    /// Process run by a system account (`root`, a service...).
+    System,
+    /// Process run by a user.
+    User,
+    /// The user of the process couldn't be retrieved.
+    Unknown,
+}
+
+/// Enum describing whether a binary is 32-bit or 64-bit.
+///
+/// It is returned by [`Process::bitness`][crate::Process::bitness].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum Bitness {
+    /// 32-bit binary.
+    Bit32,
+    /// 64-bit binary.
+    Bit64,
+}
+
+/// Returns `true` if `uid` is a system account.
+fn is_system_user(uid: &Uid) -> bool {
+    cfg_if! {
+        if #[cfg(all(any(target_os = "macos", target_os = "ios"), not(feature = "unknown-ci")))] {
+            // Regular users start at 501 on macOS.
+            **uid < 500
+        } else if #[cfg(all(
+            any(target_os = "linux", target_os = "android", target_os = "freebsd"),
+            not(feature = "unknown-ci")
+        ))] {
+            crate::unix::utils::is_system_user(**uid)
+        } else if #[cfg(windows)] {
+            // `SYSTEM`, `LOCAL SERVICE` and `NETWORK SERVICE` accounts.
+            matches!(uid.to_string().as_str(), "S-1-5-18" | "S-1-5-19" | "S-1-5-20")
+        } else {
+            let _uid = uid;
+            false
+        }
+    }
+}
+
 /// Struct containing information of a process.
 ///
 /// ## iOS
@@ -1267,24 +3094,90 @@ impl Process {
         self.inner.name()
     }
 
-    /// Returns the command line.
+    /// Returns a human readable name of the process, meant to be displayed.
     ///
-    ///  **⚠️ Important ⚠️**
+    /// It returns the file name of [`Process::exe`] if it is available, otherwise it falls back
+    /// to [`Process::name`]. On **Windows**, the `.exe` extension is removed so the same program
+    /// has the same display name on all platforms.
     ///
-    /// On **Windows**, you might need to use `administrator` privileges when running your program
-    /// to have access to this information.
+    /// Unlike [`Process::name`], it is not limited to 15 characters on **Linux** (unless the
+    /// executable path cannot be retrieved).
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.cmd());
+    ///     println!("{:?}", process.display_name());
     /// }
     /// ```
-    pub fn cmd(&self) -> &[OsString] {
-        self.inner.cmd()
-    }
+    pub fn display_name(&self) -> OsString {
+        let name = self
+            .exe()
+            .and_then(|exe| exe.file_name())
+            .unwrap_or_else(|| self.name());
+        let path = Path::new(name);
+        if cfg!(windows)
+            && path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("exe"))
+        {
+            if let Some(stem) = path.file_stem() {
+                return stem.to_os_string();
+            }
+        }
+        name.to_os_string()
+    }
+
+    /// Returns the command line.
+    ///
+    ///  **⚠️ Important ⚠️**
+    ///
+    /// On **Windows**, you might need to use `administrator` privileges when running your program
+    /// to have access to this information.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cmd());
+    /// }
+    /// ```
+    pub fn cmd(&self) -> &[OsString] {
+        self.inner.cmd()
+    }
+
+    /// Returns the command line as a string which can be copy-pasted in a shell: arguments
+    /// containing spaces or other special characters are quoted.
+    ///
+    /// On **Windows**, it returns the command line as it was given to the process (arguments are
+    /// only quoted if it couldn't be retrieved).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.cmd_display());
+    /// }
+    /// ```
+    pub fn cmd_display(&self) -> String {
+        cfg_if! {
+            if #[cfg(all(windows, not(feature = "unknown-ci")))] {
+                self.inner.cmd_display()
+            } else {
+                let mut out = String::new();
+                for (pos, arg) in self.cmd().iter().enumerate() {
+                    if pos > 0 {
+                        out.push(' ');
+                    }
+                    push_shell_quoted(&mut out, &arg.to_string_lossy());
+                }
+                out
+            }
+        }
+    }
 
     /// Returns the path to the process.
     ///
@@ -1368,354 +3261,1434 @@ impl Process {
         self.inner.root()
     }
 
-    /// Returns the memory usage (in bytes).
+    /// Returns the number of CPUs the process can actually use: the number of CPUs allowed by its
+    /// CPU affinity, capped by the CPU quota of its cgroup (and of the parents of its cgroup).
+    /// As the quota can be a fraction of a CPU, so can the returned value.
     ///
-    /// This method returns the [size of the resident set], that is, the amount of memory that the
-    /// process allocated and which is currently mapped in physical RAM. It does not include memory
-    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
+    /// Returns `None` if the CPU affinity of the process couldn't be retrieved.
     ///
-    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
-    /// present time, but it might not be a good indicator of the total memory that the process will
-    /// be using over its lifetime. For that purpose, you can try and use
-    /// [`virtual_memory`](Process::virtual_memory).
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.memory());
+    ///     if let Some(count) = process.effective_cpu_count() {
+    ///         println!("{:?} can use {count} CPUs", process.name());
+    ///     }
     /// }
     /// ```
-    ///
-    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
-    pub fn memory(&self) -> u64 {
-        self.inner.memory()
+    pub fn effective_cpu_count(&self) -> Option<f32> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.effective_cpu_count()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the virtual memory usage (in bytes).
+    /// Returns the cgroups the process belongs to, one for each hierarchy listed in
+    /// `/proc/[pid]/cgroup`.
     ///
-    /// This method returns the [size of virtual memory], that is, the amount of memory that the
-    /// process can access, whether it is currently mapped in physical RAM or not. It includes
-    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
-    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    /// With cgroups v1, a process can be in a different cgroup for each controller (like
+    /// `memory` and `cpu`). With cgroups v2, there is only one entry with no controllers.
     ///
-    /// This value has limitations though. Depending on the operating system and type of process,
-    /// this value might be a good indicator of the total memory that the process will be using over
-    /// its lifetime. However, for example, in the version 14 of macOS this value is in the order of
-    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
-    /// process maps into memory a very large file, this value will increase accordingly, even if
-    /// the process is not actively using the memory.
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.virtual_memory());
+    ///     if let Some(cgroups) = process.cgroups() {
+    ///         for cgroup in cgroups {
+    ///             println!("{:?}: {}", cgroup.controllers, cgroup.path);
+    ///         }
+    ///     }
     /// }
     /// ```
-    ///
-    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
-    pub fn virtual_memory(&self) -> u64 {
-        self.inner.virtual_memory()
+    pub fn cgroups(&self) -> Option<Vec<CGroupMembership>> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.cgroups()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the parent PID.
+    /// Returns the resources usage of the cgroup of the process, as accounted by the cgroup
+    /// itself. Unlike the sum of [`Process::memory`] of the processes of a container, it
+    /// includes the kernel memory and page cache charged to the cgroup.
+    ///
+    /// Returns `None` if the process isn't in a cgroup v2 or if its cgroup doesn't provide
+    /// this information (like the root cgroup).
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.parent());
+    ///     if let Some(usage) = process.cgroup_usage() {
+    ///         println!("{usage:?}");
+    ///     }
     /// }
     /// ```
-    pub fn parent(&self) -> Option<Pid> {
-        self.inner.parent()
+    pub fn cgroup_usage(&self) -> Option<CGroupUsage> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.cgroup_usage()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the status of the process.
+    /// Returns the device and inode numbers (in this order) of the executable of the process.
+    ///
+    /// It is retrieved every time the process is refreshed with [`ProcessRefreshKind::exe`] set
+    /// to anything other than [`UpdateKind::Never`], even if [`Process::exe`] is already set.
+    /// Comparing it between refreshes allows to detect that the process executed another binary.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.status());
+    ///     if let Some((dev, inode)) = process.exe_identity() {
+    ///         println!("executable: device {dev}, inode {inode}");
+    ///     }
     /// }
     /// ```
-    pub fn status(&self) -> ProcessStatus {
-        self.inner.status()
+    pub fn exe_identity(&self) -> Option<(u64, u64)> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.exe_identity()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the time where the process was started (in seconds) from epoch.
+    /// Returns the permissions of the executable of the process which are relevant for privilege
+    /// escalation: setuid/setgid bits, file capabilities and owner.
+    ///
+    /// Returns `None` if the executable couldn't be read (usually because of missing
+    /// permissions or because it's a kernel thread).
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Started at {} seconds", process.start_time());
+    ///     if let Some(permissions) = process.exe_permissions() {
+    ///         if permissions.setuid || permissions.has_file_caps {
+    ///             println!("{:?} runs a privileged executable", process.name());
+    ///         }
+    ///     }
     /// }
     /// ```
-    pub fn start_time(&self) -> u64 {
-        self.inner.start_time()
+    pub fn exe_permissions(&self) -> Option<ExePermissions> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.exe_permissions()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns for how much time the process has been running (in seconds).
+    /// Returns `true` if the executable of the process was deleted (or replaced, by a package
+    /// upgrade for example) since the process was started.
+    ///
+    /// Returns `None` if the executable couldn't be retrieved (usually because of missing
+    /// permissions or because it's a kernel thread).
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Running since {} seconds", process.run_time());
+    ///     if process.exe_deleted() == Some(true) {
+    ///         println!("{:?} runs a deleted executable", process.name());
+    ///     }
     /// }
     /// ```
-    pub fn run_time(&self) -> u64 {
-        self.inner.run_time()
+    pub fn exe_deleted(&self) -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.exe_deleted()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the total CPU usage (in %). Notice that it might be bigger than
-    /// 100 if run on a multi-core machine.
+    /// Returns `true` if the root directory of the process isn't the same as the one of the
+    /// current process, which happens when it was `chroot`ed or runs in a container.
     ///
-    /// If you want a value between 0% and 100%, divide the returned value by
-    /// the number of CPUs.
+    /// Returns `None` if the information couldn't be retrieved (usually because of missing
+    /// permissions).
     ///
-    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
-    /// **twice** because CPU usage computation is based on time diff (process
-    /// time on a given time period divided by total system time on the same
-    /// time period).
+    /// ⚠️ This information is computed every time this method is called.
     ///
-    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
-    /// between two calls of this method (take a look at
-    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
-    /// more information).
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
+    /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
-    /// // Wait a bit because CPU usage is based on diff.
-    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    /// // Refresh CPU usage to get actual value.
-    /// s.refresh_processes_specifics(
-    ///     ProcessesToUpdate::All,
-    ///     true,
-    ///     ProcessRefreshKind::nothing().with_cpu()
-    /// );
+    /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}%", process.cpu_usage());
+    ///     if process.is_chrooted() == Some(true) {
+    ///         println!("{:?} has a different root directory", process.name());
+    ///     }
     /// }
     /// ```
-    pub fn cpu_usage(&self) -> f32 {
-        self.inner.cpu_usage()
+    pub fn is_chrooted(&self) -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.is_chrooted()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the total accumulated CPU usage (in CPU-milliseconds). Note
-    /// that it might be bigger than the total clock run time of a process if
-    /// run on a multi-core machine.
+    /// Returns whether the process is a 32-bit or a 64-bit binary, which allows to detect 32-bit
+    /// programs running on 64-bit systems.
+    ///
+    /// * On Linux and FreeBSD, it is the class of the ELF executable.
+    /// * On macOS, it is retrieved from the kernel, falling back to the Mach-O header of the
+    ///   executable.
+    /// * On Windows, it uses `IsWow64Process2`.
+    ///
+    /// Returns `None` if the executable couldn't be read (usually because of missing
+    /// permissions).
+    ///
+    /// ⚠️ This information is computed every time this method is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{Bitness, Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.accumulated_cpu_time());
+    ///     if process.bitness() == Some(Bitness::Bit32) {
+    ///         println!("{:?} is a 32-bit program", process.name());
+    ///     }
     /// }
     /// ```
-    pub fn accumulated_cpu_time(&self) -> u64 {
-        self.inner.accumulated_cpu_time()
+    pub fn bitness(&self) -> Option<Bitness> {
+        self.inner.bitness()
     }
 
-    /// Returns number of bytes read and written to disk.
+    /// Returns the identifier of the mount namespace of the process. Processes with the same
+    /// identifier see the same mount points.
     ///
-    /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
-    /// written bytes.
+    /// ⚠️ This information is computed every time this method is called.
     ///
-    /// ⚠️ Files might be cached in memory by your OS, meaning that reading/writing them might not
-    /// increase the `read_bytes`/`written_bytes` values. You can find more information about it
-    /// in the `proc_pid_io` manual (`man proc_pid_io` on unix platforms).
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     let disk_usage = process.disk_usage();
-    ///     println!("read bytes   : new/total => {}/{}",
-    ///         disk_usage.read_bytes,
-    ///         disk_usage.total_read_bytes,
-    ///     );
-    ///     println!("written bytes: new/total => {}/{}",
-    ///         disk_usage.written_bytes,
-    ///         disk_usage.total_written_bytes,
-    ///     );
+    ///     println!("mount namespace: {:?}", process.mount_namespace_id());
     /// }
     /// ```
-    pub fn disk_usage(&self) -> DiskUsage {
-        self.inner.disk_usage()
+    pub fn mount_namespace_id(&self) -> Option<u64> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.mount_namespace_id()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the ID of the owner user of this process or `None` if this
-    /// information couldn't be retrieved. If you want to get the [`User`] from
-    /// it, take a look at [`Users::get_user_by_id`].
+    /// Returns the memory usage (in bytes).
     ///
-    /// [`User`]: crate::User
-    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
+    /// This method returns the [size of the resident set], that is, the amount of memory that the
+    /// process allocated and which is currently mapped in physical RAM. It does not include memory
+    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
     ///
-    /// ```no_run
+    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
+    /// present time, but it might not be a good indicator of the total memory that the process will
+    /// be using over its lifetime. For that purpose, you can try and use
+    /// [`virtual_memory`](Process::virtual_memory).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.memory());
+    /// }
+    /// ```
+    ///
+    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
+    pub fn memory(&self) -> u64 {
+        self.inner.memory()
+    }
+
+    /// Returns the change of the [memory usage](Process::memory) (in bytes) since the previous
+    /// refresh of the memory of this process. A negative value means the memory usage shrank.
+    ///
+    /// It returns `0` if the memory of the process was only refreshed once.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.memory_delta());
+    /// }
+    /// ```
+    pub fn memory_delta(&self) -> i64 {
+        self.inner.memory_delta()
+    }
+
+    /// Returns the virtual memory usage (in bytes).
+    ///
+    /// This method returns the [size of virtual memory], that is, the amount of memory that the
+    /// process can access, whether it is currently mapped in physical RAM or not. It includes
+    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
+    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    ///
+    /// This value has limitations though. Depending on the operating system and type of process,
+    /// this value might be a good indicator of the total memory that the process will be using over
+    /// its lifetime. However, for example, in the version 14 of macOS this value is in the order of
+    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
+    /// process maps into memory a very large file, this value will increase accordingly, even if
+    /// the process is not actively using the memory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.virtual_memory());
+    /// }
+    /// ```
+    ///
+    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
+    pub fn virtual_memory(&self) -> u64 {
+        self.inner.virtual_memory()
+    }
+
+    /// Returns the parent PID.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.parent());
+    /// }
+    /// ```
+    pub fn parent(&self) -> Option<Pid> {
+        self.inner.parent()
+    }
+
+    /// Returns the number of direct children of this process in the given [`System`].
+    ///
+    /// The [tasks][Process::tasks] of the process aren't counted.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} children", process.child_count(&s));
+    /// }
+    /// ```
+    pub fn child_count(&self, system: &System) -> usize {
+        let pid = self.pid();
+        let tasks = self.tasks();
+        system
+            .processes()
+            .iter()
+            .filter(|(child_pid, child)| {
+                child.parent() == Some(pid) && !tasks.is_some_and(|tasks| tasks.contains(child_pid))
+            })
+            .count()
+    }
+
+    /// Returns the status of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.status());
+    /// }
+    /// ```
+    pub fn status(&self) -> ProcessStatus {
+        self.inner.status()
+    }
+
+    /// Returns the time where the process was started (in seconds) from epoch.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Started at {} seconds", process.start_time());
+    /// }
+    /// ```
+    pub fn start_time(&self) -> u64 {
+        self.inner.start_time()
+    }
+
+    /// Returns for how much time the process has been running (in seconds).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Running since {} seconds", process.run_time());
+    /// }
+    /// ```
+    pub fn run_time(&self) -> u64 {
+        self.inner.run_time()
+    }
+
+    /// Returns the total CPU usage (in %). Notice that it might be bigger than
+    /// 100 if run on a multi-core machine.
+    ///
+    /// If you want a value between 0% and 100%, take a look at
+    /// [`Process::cpu_usage_normalized`].
+    ///
+    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
+    /// **twice** because CPU usage computation is based on time diff (process
+    /// time on a given time period divided by total system time on the same
+    /// time period).
+    ///
+    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
+    /// between two calls of this method (take a look at
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
+    /// more information).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
+    ///
+    /// let mut s = System::new_all();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPU usage to get actual value.
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_cpu()
+    /// );
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}%", process.cpu_usage());
+    /// }
+    /// ```
+    pub fn cpu_usage(&self) -> f32 {
+        self.inner.cpu_usage()
+    }
+
+    /// Returns the total CPU usage (in %) divided by the number of logical CPUs, so it stays
+    /// between 0% and 100% whatever the number of CPUs.
+    ///
+    /// The number of logical CPUs is the one used when the CPU usage of the process was last
+    /// computed.
+    ///
+    /// The same limitations as [`Process::cpu_usage`] apply.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}%", process.cpu_usage_normalized());
+    /// }
+    /// ```
+    pub fn cpu_usage_normalized(&self) -> f32 {
+        self.cpu_usage() / self.inner.cpu_count().max(1) as f32
+    }
+
+    /// Returns the total accumulated CPU usage (in CPU-milliseconds). Note
+    /// that it might be bigger than the total clock run time of a process if
+    /// run on a multi-core machine.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.accumulated_cpu_time());
+    /// }
+    /// ```
+    pub fn accumulated_cpu_time(&self) -> u64 {
+        self.inner.accumulated_cpu_time()
+    }
+
+    /// Returns a snapshot of the [accumulated CPU time](Process::accumulated_cpu_time) of the
+    /// process, to be used later with [`Process::cpu_usage_since`].
+    ///
+    /// It allows to compute the CPU usage over a custom interval instead of the one between the
+    /// last two refreshes. It should be called right after refreshing the process with
+    /// [`ProcessRefreshKind::cpu`].
+    ///
+    /// On Linux, the interval is measured with the global CPU time of the refresh, like
+    /// [`Process::cpu_usage`]. On other platforms, the wall-clock time is used.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+    ///
+    /// let pid = Pid::from(1337);
+    /// let refresh_kind = ProcessRefreshKind::nothing().with_cpu();
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+    /// let baseline = s.process(pid).map(|process| process.cpu_baseline());
+    ///
+    /// // Do something...
+    ///
+    /// s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+    /// if let (Some(process), Some(baseline)) = (s.process(pid), baseline) {
+    ///     println!("{}%", process.cpu_usage_since(&baseline));
+    /// }
+    /// ```
+    pub fn cpu_baseline(&self) -> CpuBaseline {
+        let (cpu_time, global_cpu_time) = self.cpu_times();
+        CpuBaseline {
+            pid: self.pid(),
+            cpu_time,
+            global_cpu_time,
+        }
+    }
+
+    /// Returns the CPU time of the process and the global CPU time, in the same unit.
+    fn cpu_times(&self) -> (u64, u64) {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.cpu_times()
+            } else {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_millis() as u64)
+                    .unwrap_or(0);
+                (self.accumulated_cpu_time(), now)
+            }
+        }
+    }
+
+    /// Returns the CPU usage (in %) of the process since `baseline` was taken with
+    /// [`Process::cpu_baseline`].
+    ///
+    /// Like [`Process::cpu_usage`], it can be greater than 100% on multi-core machines. It
+    /// should be called right after refreshing the process with [`ProcessRefreshKind::cpu`].
+    /// It returns `0` if no time passed since `baseline` was taken or if `baseline` was taken
+    /// for another process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+    ///
+    /// let pid = Pid::from(1337);
+    /// let refresh_kind = ProcessRefreshKind::nothing().with_cpu();
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+    /// let baseline = s.process(pid).map(|process| process.cpu_baseline());
+    ///
+    /// // Do something...
+    ///
+    /// s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+    /// if let (Some(process), Some(baseline)) = (s.process(pid), baseline) {
+    ///     println!("{}%", process.cpu_usage_since(&baseline));
+    /// }
+    /// ```
+    pub fn cpu_usage_since(&self, baseline: &CpuBaseline) -> f32 {
+        if baseline.pid != self.pid() {
+            return 0.;
+        }
+        let (cpu_time, global_cpu_time) = self.cpu_times();
+        let elapsed = global_cpu_time.saturating_sub(baseline.global_cpu_time);
+        if elapsed == 0 {
+            return 0.;
+        }
+        let cpu_time = cpu_time.saturating_sub(baseline.cpu_time);
+        (cpu_time as f64 / elapsed as f64 * 100.) as f32
+    }
+
+    /// Returns number of bytes read and written to disk.
+    ///
+    /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
+    /// written bytes.
+    ///
+    /// ⚠️ Files might be cached in memory by your OS, meaning that reading/writing them might not
+    /// increase the `read_bytes`/`written_bytes` values. You can find more information about it
+    /// in the `proc_pid_io` manual (`man proc_pid_io` on unix platforms). To get all the bytes
+    /// read and written by the process, use [`Process::char_io`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     let disk_usage = process.disk_usage();
+    ///     println!("read bytes   : new/total => {}/{}",
+    ///         disk_usage.read_bytes,
+    ///         disk_usage.total_read_bytes,
+    ///     );
+    ///     println!("written bytes: new/total => {}/{}",
+    ///         disk_usage.written_bytes,
+    ///         disk_usage.total_written_bytes,
+    ///     );
+    /// }
+    /// ```
+    pub fn disk_usage(&self) -> DiskUsage {
+        self.inner.disk_usage()
+    }
+
+    /// Returns number of bytes read and written by the process through I/O syscalls (like
+    /// `read` and `write`), whether they reached the disk or not.
+    ///
+    /// Unlike [`Process::disk_usage`], it includes the reads served from the page cache and the
+    /// I/O on pipes, sockets and terminals.
+    ///
+    /// It is only updated when the disk usage of the process is refreshed (check
+    /// [`ProcessRefreshKind::with_disk_usage`]). Returns `None` if it couldn't be retrieved.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(char_io) = process.char_io() {
+    ///         println!("read chars   : new/total => {}/{}",
+    ///             char_io.read_bytes,
+    ///             char_io.total_read_bytes,
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub fn char_io(&self) -> Option<DiskUsage> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.char_io()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of read and write syscalls (like `read` and `pread`, or `write` and
+    /// `pwrite`) made by the process since it started.
+    ///
+    /// Along with [`Process::char_io`], it allows to know if a process does a lot of small I/O
+    /// or a few big ones.
+    ///
+    /// It is only updated when the disk usage of the process is refreshed (check
+    /// [`ProcessRefreshKind::with_disk_usage`]). Returns `None` if it couldn't be retrieved.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(syscalls) = process.io_syscalls() {
+    ///         println!("{} reads, {} writes", syscalls.read_calls, syscalls.write_calls);
+    ///     }
+    /// }
+    /// ```
+    pub fn io_syscalls(&self) -> Option<IoSyscalls> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.io_syscalls()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the ID of the owner user of this process or `None` if this
+    /// information couldn't be retrieved. If you want to get the [`User`] from
+    /// it, take a look at [`Users::get_user_by_id`].
+    ///
+    /// [`User`]: crate::User
+    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("User id for process 1337: {:?}", process.user_id());
+    /// }
+    /// ```
+    pub fn user_id(&self) -> Option<&Uid> {
+        self.inner.user_id()
+    }
+
+    /// Returns the user ID of the effective owner of this process or `None` if
+    /// this information couldn't be retrieved. If you want to get the [`User`]
+    /// from it, take a look at [`Users::get_user_by_id`].
+    ///
+    /// If you run something with `sudo`, the real user ID of the launched
+    /// process will be the ID of the user you are logged in as but effective
+    /// user ID will be `0` (i-e root).
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// [`User`]: crate::User
+    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("User id for process 1337: {:?}", process.effective_user_id());
+    /// }
+    /// ```
+    pub fn effective_user_id(&self) -> Option<&Uid> {
+        self.inner.effective_user_id()
+    }
+
+    /// Returns the process group ID of the process.
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Group ID for process 1337: {:?}", process.group_id());
+    /// }
+    /// ```
+    pub fn group_id(&self) -> Option<Gid> {
+        self.inner.group_id()
+    }
+
+    /// Returns the effective group ID of the process.
+    ///
+    /// If you run something with `sudo`, the real group ID of the launched
+    /// process will be the primary group ID you are logged in as but effective
+    /// group ID will be `0` (i-e root).
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("User id for process 1337: {:?}", process.effective_group_id());
+    /// }
+    /// ```
+    pub fn effective_group_id(&self) -> Option<Gid> {
+        self.inner.effective_group_id()
+    }
+
+    /// Returns the session ID for the current process or `None` if it couldn't
+    /// be retrieved.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Session ID for process 1337: {:?}", process.session_id());
+    /// }
+    /// ```
+    pub fn session_id(&self) -> Option<Pid> {
+        self.inner.session_id()
+    }
+
+    /// Returns the process group ID for the current process or `None` if it couldn't
+    /// be retrieved.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Process group ID for process 1337: {:?}", process.process_group_id());
+    /// }
+    /// ```
+    pub fn process_group_id(&self) -> Option<Pid> {
+        self.inner.process_group_id()
+    }
+
+    /// Returns the index of the CPU the process last ran on.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("process 1337 last ran on CPU {:?}", process.last_cpu());
+    /// }
+    /// ```
+    pub fn last_cpu(&self) -> Option<usize> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.last_cpu()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the ID of the foreground process group of the controlling terminal of this
+    /// process, or `None` if it has no controlling terminal.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if process.foreground_process_group_id() == process.process_group_id() {
+    ///         println!("process 1337 is in the foreground");
+    ///     }
+    /// }
+    /// ```
+    pub fn foreground_process_group_id(&self) -> Option<Pid> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.foreground_process_group_id()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Tasks run by this process. If there are none, returns `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let mut s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.user_id());
+    ///     if let Some(tasks) = process.tasks() {
+    ///         println!("Listing tasks for process {:?}", process.pid());
+    ///         for task_pid in tasks {
+    ///             if let Some(task) = s.process(*task_pid) {
+    ///                 println!("Task {:?}: {:?}", task.pid(), task.name());
+    ///             }
+    ///         }
+    ///     }
     /// }
     /// ```
-    pub fn user_id(&self) -> Option<&Uid> {
-        self.inner.user_id()
+    pub fn tasks(&self) -> Option<&HashSet<Pid>> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.tasks.as_ref()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Names of the tasks run by this process, read from `/proc/[pid]/task/[tid]/comm` when
+    /// refreshing with both [`ProcessRefreshKind::tasks`] and [`ProcessRefreshKind::task_names`].
+    /// If there are none, returns `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(task_names) = process.task_names() {
+    ///         for (task_pid, name) in task_names {
+    ///             println!("Task {task_pid:?}: {name:?}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn task_names(&self) -> Option<&HashMap<Pid, OsString>> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.task_names.as_ref()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the task (thread) of this process which used the most CPU since the previous
+    /// refresh.
+    ///
+    /// It is computed when refreshing with both [`ProcessRefreshKind::tasks`] and
+    /// [`ProcessRefreshKind::cpu`]. Returns `None` if the tasks weren't refreshed or if none of
+    /// them used any CPU.
+    ///
+    /// ⚠️ The main thread of the process isn't a task (its ID is the process' one), so it is
+    /// never returned.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(tid) = process.hottest_task() {
+    ///         println!("Task {tid} is the busiest one");
+    ///     }
+    /// }
+    /// ```
+    pub fn hottest_task(&self) -> Option<Pid> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.hottest_task
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the files opened by the process, or `None` if they weren't retrieved (because
+    /// [`ProcessRefreshKind::open_files`] wasn't set or because `sysinfo` doesn't have the
+    /// rights to access this information).
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux and macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// let pid = Pid::from(1337);
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::Some(&[pid]),
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_open_files(),
+    /// );
+    /// if let Some(files) = s.process(pid).and_then(|process| process.open_files()) {
+    ///     for file in files {
+    ///         println!("{}: {:?} {:?}", file.fd, file.kind, file.path);
+    ///     }
+    /// }
+    /// ```
+    pub fn open_files(&self) -> Option<&[OpenFile]> {
+        self.inner.open_files()
+    }
+
+    /// Returns the number of TCP sockets of the process by state, or `None` if they weren't
+    /// retrieved.
+    ///
+    /// It is computed when refreshing with [`ProcessRefreshKind::connections`].
+    ///
+    /// ⚠️ `TIME_WAIT` sockets are usually not owned by any process anymore, so they are rarely
+    /// counted.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// let pid = Pid::from(1337);
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::Some(&[pid]),
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_connections(),
+    /// );
+    /// if let Some(summary) = s.process(pid).and_then(|process| process.socket_summary()) {
+    ///     if summary.listening > 0 {
+    ///         println!("this process is a server");
+    ///     }
+    /// }
+    /// ```
+    pub fn socket_summary(&self) -> Option<SocketSummary> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.socket_summary()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// If the process is a thread, it'll return `Some` with the kind of thread it is. Returns
+    /// `None` otherwise.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    ///
+    /// for (_, process) in s.processes() {
+    ///     if let Some(thread_kind) = process.thread_kind() {
+    ///         println!("Process {:?} is a {thread_kind:?} thread", process.pid());
+    ///     }
+    /// }
+    /// ```
+    pub fn thread_kind(&self) -> Option<ThreadKind> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.thread_kind()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the process is a kernel thread (like `kthreadd` and its children on
+    /// Linux). It can be used to filter out kernel threads from a processes list.
+    ///
+    /// On Linux, it is detected with the `PF_KTHREAD` flag of the process, which is more reliable
+    /// than checking for an empty [`Process::cmd`] or [`Process::exe`].
+    ///
+    /// ⚠️ This method always returns `false` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if !process.is_kernel_thread() {
+    ///         println!("{pid}: {:?}", process.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_kernel_thread(&self) -> bool {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.thread_kind() == Some(ThreadKind::Kernel)
+            } else {
+                false
+            }
+        }
+    }
+
+    /// Returns a coarse classification of the process, which can be used to group processes in
+    /// a UI:
+    ///
+    ///  * [`ProcessKind::Kernel`] if it is a kernel thread (see [`Process::is_kernel_thread`]).
+    ///  * [`ProcessKind::System`] if it is run by a system account (`root`, `nobody` and users
+    ///    with a user ID lower than `UID_MIN` from `/etc/login.defs` (`1000` by default) on
+    ///    Linux and FreeBSD, lower than `500` on macOS, the `SYSTEM`, `LOCAL SERVICE` and
+    ///    `NETWORK SERVICE` accounts or a process running in session 0 on Windows).
+    ///  * [`ProcessKind::User`] if it is run by any other user.
+    ///  * [`ProcessKind::Unknown`] if the user of the process couldn't be retrieved.
+    ///
+    /// ⚠️ You need to have refreshed the processes with [`ProcessRefreshKind::user`] for this
+    /// method to return something else than [`ProcessKind::Unknown`] or [`ProcessKind::Kernel`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessKind, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if process.kind() == ProcessKind::User {
+    ///         println!("{pid}: {:?}", process.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn kind(&self) -> ProcessKind {
+        if self.is_kernel_thread() {
+            return ProcessKind::Kernel;
+        }
+        if cfg!(windows) && self.session_id() == Some(Pid::from_u32(0)) {
+            return ProcessKind::System;
+        }
+        match self.user_id() {
+            Some(uid) if is_system_user(uid) => ProcessKind::System,
+            Some(_) => ProcessKind::User,
+            None => ProcessKind::Unknown,
+        }
+    }
+
+    /// Returns the umask of the process (the permissions removed from the files and directories
+    /// it creates).
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux and on Linux versions
+    /// older than 4.7.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(umask) = process.umask() {
+    ///         println!("umask: {umask:04o}");
+    ///     }
+    /// }
+    /// ```
+    pub fn umask(&self) -> Option<u32> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.umask()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the pid of the process tracing this one (for example a debugger attached with
+    /// `ptrace`), or `None` if it isn't traced.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(tracer) = process.tracer_pid() {
+    ///         println!("traced by {tracer}");
+    ///     }
+    /// }
+    /// ```
+    pub fn tracer_pid(&self) -> Option<Pid> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.tracer_pid()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the soft and hard limits of the number of files the process can open, as
+    /// `(soft, hard)`. If a limit is "unlimited", `u64::MAX` is returned for it.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some((soft, hard)) = process.open_files_limit() {
+    ///         println!("open files limit: {soft} (hard limit: {hard})");
+    ///     }
+    /// }
+    /// ```
+    pub fn open_files_limit(&self) -> Option<(u64, u64)> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.open_files_limit()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the user ID of the effective owner of this process or `None` if
-    /// this information couldn't be retrieved. If you want to get the [`User`]
-    /// from it, take a look at [`Users::get_user_by_id`].
+    /// Returns the realtime scheduling parameters of the process if it uses the `SCHED_FIFO`,
+    /// `SCHED_RR` or `SCHED_DEADLINE` policy. Returns `None` for processes using a "normal"
+    /// scheduling policy.
     ///
-    /// If you run something with `sudo`, the real user ID of the launched
-    /// process will be the ID of the user you are logged in as but effective
-    /// user ID will be `0` (i-e root).
+    /// For `SCHED_FIFO` and `SCHED_RR` processes, only [`RtParams::priority`] is set, the other
+    /// fields are `0`.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// ⚠️ This information is computed every time this method is called.
     ///
-    /// [`User`]: crate::User
-    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
-    ///
+    /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.effective_user_id());
+    ///     if let Some(params) = process.realtime_params() {
+    ///         println!("realtime priority: {}", params.priority);
+    ///     }
     /// }
     /// ```
-    pub fn effective_user_id(&self) -> Option<&Uid> {
-        self.inner.effective_user_id()
+    pub fn realtime_params(&self) -> Option<RtParams> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.realtime_params()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the process group ID of the process.
+    /// Returns the scheduling autogroup of the process, read from `/proc/[pid]/autogroup`.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// Returns `None` if autogroups are disabled (or not supported by the kernel).
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
-    ///
+    /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Group ID for process 1337: {:?}", process.group_id());
+    ///     if let Some(autogroup) = process.autogroup() {
+    ///         println!("autogroup {} has nice {}", autogroup.id, autogroup.nice);
+    ///     }
     /// }
     /// ```
-    pub fn group_id(&self) -> Option<Gid> {
-        self.inner.group_id()
+    pub fn autogroup(&self) -> Option<AutogroupInfo> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.autogroup()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the effective group ID of the process.
+    /// Returns the scheduling statistics of the process: how long it ran, how long it waited to
+    /// be run and how many timeslices it got.
     ///
-    /// If you run something with `sudo`, the real group ID of the launched
-    /// process will be the primary group ID you are logged in as but effective
-    /// group ID will be `0` (i-e root).
+    /// Returns `None` if the kernel doesn't provide this information (it requires the
+    /// `CONFIG_SCHED_INFO` option).
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
-    ///
+    /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.effective_group_id());
+    ///     if let Some(stats) = process.sched_stats() {
+    ///         println!("{stats:?}");
+    ///     }
     /// }
     /// ```
-    pub fn effective_group_id(&self) -> Option<Gid> {
-        self.inner.effective_group_id()
+    pub fn sched_stats(&self) -> Option<SchedStats> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.sched_stats()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the session ID for the current process or `None` if it couldn't
-    /// be retrieved.
+    /// Returns the scheduling policy, nice value, last CPU, context switches and run queue wait
+    /// time of the process, read in a single call.
+    ///
+    /// Returns `None` if the process information couldn't be read. Each field of the returned
+    /// [`SchedulingSummary`] is `None` if it couldn't be retrieved.
     ///
     /// ⚠️ This information is computed every time this method is called.
     ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
-    ///
+    /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Session ID for process 1337: {:?}", process.session_id());
+    ///     if let Some(summary) = process.scheduling_summary() {
+    ///         println!("preempted {:?} times", summary.involuntary_context_switches);
+    ///     }
     /// }
     /// ```
-    pub fn session_id(&self) -> Option<Pid> {
-        self.inner.session_id()
+    pub fn scheduling_summary(&self) -> Option<SchedulingSummary> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.scheduling_summary()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Tasks run by this process. If there are none, returns `None`.
+    /// Returns how the resident memory of the process is split between anonymous, file-backed
+    /// and shared memory pages.
+    ///
+    /// Returns `None` if the kernel doesn't provide this information (it was added in Linux 4.5).
+    ///
+    /// ⚠️ This information is computed every time this method is called.
     ///
     /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
-    ///
+    /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if let Some(tasks) = process.tasks() {
-    ///         println!("Listing tasks for process {:?}", process.pid());
-    ///         for task_pid in tasks {
-    ///             if let Some(task) = s.process(*task_pid) {
-    ///                 println!("Task {:?}: {:?}", task.pid(), task.name());
-    ///             }
-    ///         }
+    ///     if let Some(rss) = process.rss_breakdown() {
+    ///         println!("{rss:?}");
     ///     }
     /// }
     /// ```
-    pub fn tasks(&self) -> Option<&HashSet<Pid>> {
+    pub fn rss_breakdown(&self) -> Option<RssBreakdown> {
         cfg_if! {
             if #[cfg(all(
                 any(target_os = "linux", target_os = "android"),
                 not(feature = "unknown-ci")
             ))] {
-                self.inner.tasks.as_ref()
+                self.inner.rss_breakdown()
             } else {
                 None
             }
         }
     }
 
-    /// If the process is a thread, it'll return `Some` with the kind of thread it is. Returns
-    /// `None` otherwise.
+    /// Returns the security context of the process, as provided by the active Linux Security
+    /// Module (the SELinux label or the AppArmor profile for example). Returns `None` if no
+    /// such module is active or if it couldn't be retrieved.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
     ///
     /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     ///
-    /// for (_, process) in s.processes() {
-    ///     if let Some(thread_kind) = process.thread_kind() {
-    ///         println!("Process {:?} is a {thread_kind:?} thread", process.pid());
-    ///     }
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Security context: {:?}", process.security_context());
     /// }
     /// ```
-    pub fn thread_kind(&self) -> Option<ThreadKind> {
+    pub fn security_context(&self) -> Option<String> {
         cfg_if! {
             if #[cfg(all(
                 any(target_os = "linux", target_os = "android"),
                 not(feature = "unknown-ci")
             ))] {
-                self.inner.thread_kind()
+                self.inner.security_context()
             } else {
                 None
             }
@@ -1834,6 +4807,43 @@ impl UpdateKind {
     }
 }
 
+/// Error returned by [`System::refresh_processes_specifics_checked`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RefreshError {
+    /// Some processes couldn't be refreshed.
+    Processes {
+        /// Number of processes which were refreshed.
+        nb_updated: usize,
+        /// PID of each process which couldn't be refreshed, along with the reason.
+        errors: Vec<(Pid, io::Error)>,
+    },
+}
+
+impl fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Processes { errors, .. } => {
+                write!(f, "failed to refresh {} process(es)", errors.len())?;
+                if let Some((pid, error)) = errors.first() {
+                    write!(f, " (first one being {pid}: {error})")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for RefreshError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Processes { errors, .. } => errors
+                .first()
+                .map(|(_, error)| error as &(dyn std::error::Error + 'static)),
+        }
+    }
+}
+
 /// This enum allows you to specify if you want all processes to be updated or just
 /// some of them.
 ///
@@ -1914,6 +4924,10 @@ pub struct ProcessRefreshKind {
     cmd: UpdateKind,
     exe: UpdateKind,
     tasks: bool,
+    task_names: bool,
+    open_files: bool,
+    connections: bool,
+    name_source: NameSource,
 }
 
 /// Creates a new `ProcessRefreshKind` with every refresh set to `false`, except for `tasks`.
@@ -1933,6 +4947,10 @@ impl Default for ProcessRefreshKind {
             cmd: UpdateKind::default(),
             exe: UpdateKind::default(),
             tasks: true, // Process by default includes all tasks.
+            task_names: false,
+            open_files: false,
+            connections: false,
+            name_source: NameSource::Comm,
         }
     }
 }
@@ -1977,6 +4995,10 @@ impl ProcessRefreshKind {
             cmd: UpdateKind::OnlyIfNotSet,
             exe: UpdateKind::OnlyIfNotSet,
             tasks: true,
+            task_names: true,
+            open_files: true,
+            connections: true,
+            name_source: NameSource::Comm,
         }
     }
 
@@ -2027,6 +5049,91 @@ It will retrieve the following information:
     impl_get_set!(ProcessRefreshKind, cmd, with_cmd, without_cmd, UpdateKind);
     impl_get_set!(ProcessRefreshKind, exe, with_exe, without_exe, UpdateKind);
     impl_get_set!(ProcessRefreshKind, tasks, with_tasks, without_tasks);
+    impl_get_set!(
+        ProcessRefreshKind,
+        task_names,
+        with_task_names,
+        without_task_names,
+        "\
+It will retrieve the names of the tasks of the process (returned by [`Process::task_names`]) if
+[`ProcessRefreshKind::tasks`] is enabled as well. It requires reading one more file per task so
+it is not enabled by default."
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        open_files,
+        with_open_files,
+        without_open_files,
+        "\
+It will retrieve the list of files opened by the process (returned by
+[`Process::open_files`])."
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        connections,
+        with_connections,
+        without_connections,
+        "\
+It will retrieve the number of TCP sockets of the process by state (returned by
+[`Process::socket_summary`]). The TCP tables are read once per network namespace on each
+refresh."
+    );
+
+    /// Returns where the name of the processes (returned by [`Process::name`]) comes from.
+    ///
+    /// ```
+    /// use sysinfo::{NameSource, ProcessRefreshKind};
+    ///
+    /// let r = ProcessRefreshKind::nothing();
+    /// assert_eq!(r.name_source(), NameSource::Comm);
+    /// ```
+    pub fn name_source(&self) -> NameSource {
+        self.name_source
+    }
+
+    /// Sets where the name of the processes (returned by [`Process::name`]) comes from.
+    ///
+    /// ⚠️ It is only used on Linux. On other platforms, the name is always retrieved from the
+    /// system.
+    ///
+    /// ```
+    /// use sysinfo::{NameSource, ProcessRefreshKind};
+    ///
+    /// let r = ProcessRefreshKind::nothing().with_name_source(NameSource::Exe);
+    /// assert_eq!(r.name_source(), NameSource::Exe);
+    /// ```
+    #[must_use]
+    pub fn with_name_source(mut self, source: NameSource) -> Self {
+        self.name_source = source;
+        self
+    }
+}
+
+/// Where the name of a process (returned by [`Process::name`]) comes from on Linux.
+///
+/// It is used by [`ProcessRefreshKind::with_name_source`].
+///
+/// ```no_run
+/// use sysinfo::{NameSource, ProcessesToUpdate, ProcessRefreshKind, System};
+///
+/// let mut system = System::new();
+/// system.refresh_processes_specifics(
+///     ProcessesToUpdate::All,
+///     true,
+///     ProcessRefreshKind::nothing().with_name_source(NameSource::Exe),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NameSource {
+    /// The name comes from `/proc/[pid]/comm`. It is truncated to 15 characters.
+    #[default]
+    Comm,
+    /// The name is the file name of the executable of the process. If it cannot be retrieved
+    /// (like for kernel threads), the name comes from `/proc/[pid]/comm`.
+    Exe,
+    /// The name is the first argument of the command line of the process. If it is empty (like
+    /// for kernel threads), the name comes from `/proc/[pid]/comm`.
+    Cmdline,
 }
 
 /// Used to determine what you want to refresh specifically on the [`Cpu`] type.
@@ -2226,6 +5333,71 @@ impl RefreshKind {
     impl_get_set!(RefreshKind, cpu, with_cpu, without_cpu, CpuRefreshKind);
 }
 
+/// Returns the current memory and swap information.
+///
+/// Unlike [`System::refresh_memory`], it doesn't require a [`System`] and reads the values
+/// directly (from `/proc/meminfo` on Linux and `GlobalMemoryStatusEx` on Windows).
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ```no_run
+/// let info = sysinfo::memory_info();
+/// println!("total memory: {} bytes", info.total);
+/// println!("free swap: {} bytes", info.free_swap);
+/// ```
+pub fn memory_info() -> MemoryInfo {
+    SystemInner::memory_info()
+}
+
+/// Information about how much of a file is in the page cache.
+///
+/// It is returned by [`cached_pages`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheInfo {
+    /// Number of pages of the file.
+    pub total_pages: u64,
+    /// Number of pages of the file currently resident in the page cache.
+    pub cached_pages: u64,
+}
+
+/// Returns how many pages of the file at `path` are currently in the page cache.
+///
+/// It maps the file in memory and uses `mincore` to check which pages are resident, without
+/// reading (and thus loading) them.
+///
+/// Returns `None` if the file couldn't be opened or mapped.
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ⚠️ This function always returns `None` on Windows and on unsupported platforms.
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// if let Some(info) = sysinfo::cached_pages(Path::new("/var/lib/db/data")) {
+///     println!("{}/{} pages cached", info.cached_pages, info.total_pages);
+/// }
+/// ```
+pub fn cached_pages(path: &Path) -> Option<CacheInfo> {
+    cfg_if! {
+        if #[cfg(all(
+            any(
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "android",
+                target_os = "macos",
+                target_os = "ios",
+            ),
+            not(feature = "unknown-ci"),
+        ))] {
+            crate::unix::utils::cached_pages(path)
+        } else {
+            let _ = path;
+            None
+        }
+    }
+}
+
 /// Returns the pid for the current process.
 ///
 /// `Err` is returned in case the platform isn't supported.
@@ -2322,6 +5494,43 @@ impl Cpu {
         self.inner.cpu_usage()
     }
 
+    /// Returns the percentage of time this CPU was waiting for the hypervisor to run something
+    /// else (also called "steal time") since the last refresh.
+    ///
+    /// It is useful in virtualized environments to detect when the host is overloaded. On a
+    /// non-virtualized system, it will always be `0`.
+    ///
+    /// Like [`Cpu::cpu_usage`], you need to refresh CPU usage at least twice to get an
+    /// accurate value.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    ///
+    /// // Wait a bit because steal time is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again to get actual value.
+    /// s.refresh_cpu_usage();
+    ///
+    /// for cpu in s.cpus() {
+    ///     println!("{:?}%", cpu.steal_time_percent());
+    /// }
+    /// ```
+    pub fn steal_time_percent(&self) -> Option<f32> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                Some(self.inner.steal_time_percent())
+            } else {
+                None
+            }
+        }
+    }
+
     /// Returns this CPU's name.
     ///
     /// ```no_run
@@ -2385,6 +5594,33 @@ impl Cpu {
     pub fn frequency(&self) -> u64 {
         self.inner.frequency()
     }
+
+    /// Returns the name of the cpufreq governor used by this CPU (like `powersave`,
+    /// `performance` or `schedutil`), or `None` if the CPU doesn't support frequency scaling.
+    ///
+    /// It is updated when the CPU frequency is refreshed.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_frequency()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {:?}", cpu.name(), cpu.frequency_governor());
+    /// }
+    /// ```
+    pub fn frequency_governor(&self) -> Option<&str> {
+        cfg_if! {
+            if #[cfg(all(any(target_os = "linux", target_os = "android"), not(feature = "unknown-ci")))] {
+                self.inner.frequency_governor()
+            } else {
+                None
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -2392,6 +5628,23 @@ mod test {
     use crate::*;
     use std::str::FromStr;
 
+    #[test]
+    #[cfg(any(not(windows), feature = "unknown-ci"))]
+    fn check_push_shell_quoted() {
+        let quote = |arg: &str| {
+            let mut out = String::new();
+            super::push_shell_quoted(&mut out, arg);
+            out
+        };
+        assert_eq!(quote("ls"), "ls");
+        assert_eq!(quote("--color=auto"), "--color=auto");
+        assert_eq!(quote("/usr/bin/a.out"), "/usr/bin/a.out");
+        assert_eq!(quote(""), "''");
+        assert_eq!(quote("a b"), "'a b'");
+        assert_eq!(quote("$HOME"), "'$HOME'");
+        assert_eq!(quote("it's"), r"'it'\''s'");
+    }
+
     // In case `Process::updated` is misused, `System::refresh_processes` might remove them
     // so this test ensures that it doesn't happen.
     #[test]
@@ -348,6 +348,24 @@ impl Users {
     pub fn get_user_by_id(&self, user_id: &Uid) -> Option<&User> {
         self.users.iter().find(|user| user.id() == user_id)
     }
+
+    /// Returns the [`User`] running the current process (its effective user on Unix systems).
+    ///
+    /// **Important**: The user list must be filled before using this method, otherwise it will
+    /// always return `None` (through the `refresh_*` methods).
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// if let Some(user) = users.current_user() {
+    ///     println!("running as {}", user.name());
+    /// }
+    /// ```
+    pub fn current_user(&self) -> Option<&User> {
+        let user_id = crate::sys::get_current_user_id()?;
+        self.get_user_by_id(&user_id)
+    }
 }
 
 /// Interacting with groups.
@@ -71,16 +71,25 @@ cfg_if! {
 #[cfg(feature = "component")]
 pub use crate::common::component::{Component, Components};
 #[cfg(feature = "disk")]
-pub use crate::common::disk::{Disk, DiskKind, DiskRefreshKind, Disks};
+pub use crate::common::disk::{
+    CompressionInfo, Disk, DiskKind, DiskRefreshKind, Disks, MountEntry, MountKind,
+    MountPropagation, OverlayInfo,
+};
 #[cfg(feature = "network")]
 pub use crate::common::network::{
-    IpNetwork, IpNetworkFromStrError, MacAddr, MacAddrFromStrError, NetworkData, Networks,
+    IpNetwork, IpNetworkFromStrError, MacAddr, MacAddrFromStrError, NetworkData, NetworkDiff,
+    NetworkTotals, Networks,
 };
 #[cfg(feature = "system")]
 pub use crate::common::system::{
-    get_current_pid, CGroupLimits, Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process,
-    ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, RefreshKind, Signal, System, ThreadKind,
-    UpdateKind,
+    cached_pages, get_current_pid, memory_info, ArchDetails, AutogroupInfo, Bitness, CGroupLimits,
+    CGroupMembership, CGroupUsage, CacheInfo, Cpu, CpuBaseline, CpuRefreshKind, Endianness,
+    ExePermissions, FdKind, IoSyscalls, KernelStats, LoadAvg, MemoryInfo, MemoryRefreshKind,
+    NameSource, OpenFile, Pid, PidUsage, Pressure, PressureStats, Process, ProcessKind,
+    ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, RefreshError, RefreshKind,
+    RefreshTimings, RssBreakdown, RtParams, SchedPolicy, SchedStats, SchedulingSummary, Signal,
+    SocketSummary, SwapActivity, System, SystemSummary, ThpMode, ThpStatus, ThreadKind, TpmInfo,
+    TpmVersion, UpdateKind,
 };
 #[cfg(feature = "user")]
 pub use crate::common::user::{Group, Groups, User, Users};
@@ -105,6 +114,7 @@ pub(crate) use crate::sys::{DiskInner, DisksInner};
 #[cfg(feature = "network")]
 pub(crate) use crate::sys::{NetworkDataInner, NetworksInner};
 
+pub use crate::common::format::{format_bytes, format_bytes_si};
 pub use crate::sys::IS_SUPPORTED_SYSTEM;
 
 #[cfg(feature = "c-interface")]
@@ -182,6 +192,33 @@ pub fn set_open_files_limit(mut _new_limit: isize) -> bool {
     }
 }
 
+/// Returns the number of clock ticks per second (`CLK_TCK`), which is the unit of the tick-based
+/// values of the kernel (like the CPU times in `/proc/[pid]/stat` on Linux).
+///
+/// On Unix, it is retrieved with `sysconf(_SC_CLK_TCK)`. On other platforms (like Windows) or
+/// if it cannot be retrieved, `100` is returned.
+///
+/// ```no_run
+/// use sysinfo::clock_ticks_per_second;
+///
+/// let ticks = 4200;
+/// println!("{ticks} ticks = {}s", ticks / clock_ticks_per_second());
+/// ```
+pub fn clock_ticks_per_second() -> u64 {
+    const DEFAULT_CLOCK_TICKS: u64 = 100;
+
+    cfg_if! {
+        if #[cfg(all(unix, not(feature = "unknown-ci")))] {
+            match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+                ticks if ticks > 0 => ticks as u64,
+                _ => DEFAULT_CLOCK_TICKS,
+            }
+        } else {
+            DEFAULT_CLOCK_TICKS
+        }
+    }
+}
+
 #[cfg(doctest)]
 mod doctest {
     macro_rules! compile_fail_import {
@@ -197,31 +234,71 @@ use sysinfo::", stringify!($imports), r";
     #[cfg(not(feature = "system"))]
     compile_fail_import!(
         no_system_feature =>
+        cached_pages,
         get_current_pid,
+        memory_info,
+        ArchDetails,
+        AutogroupInfo,
+        Bitness,
+        CacheInfo,
         CGroupLimits,
+        CGroupMembership,
+        CGroupUsage,
         Cpu,
+        CpuBaseline,
         CpuRefreshKind,
         DiskUsage,
+        Endianness,
+        ExePermissions,
+        FdKind,
+        IoSyscalls,
+        KernelStats,
         LoadAvg,
+        MemoryInfo,
         MemoryRefreshKind,
+        NameSource,
+        OpenFile,
         Pid,
+        PidUsage,
+        Pressure,
+        PressureStats,
         Process,
+        ProcessKind,
         ProcessesToUpdate,
         ProcessRefreshKind,
         ProcessStatus,
+        RefreshError,
         RefreshKind,
+        RefreshTimings,
+        RssBreakdown,
+        RtParams,
+        SchedPolicy,
+        SchedStats,
+        SchedulingSummary,
         Signal,
+        SocketSummary,
+        SwapActivity,
         System,
+        SystemSummary,
+        ThpMode,
+        ThpStatus,
         ThreadKind,
+        TpmInfo,
+        TpmVersion,
         UpdateKind,
     );
 
     #[cfg(not(feature = "disk"))]
     compile_fail_import!(
         no_disk_feature =>
+        CompressionInfo,
         Disk,
         Disks,
         DiskKind,
+        MountEntry,
+        MountKind,
+        MountPropagation,
+        OverlayInfo,
     );
 
     #[cfg(not(feature = "component"))]
@@ -237,6 +314,8 @@ use sysinfo::", stringify!($imports), r";
         IpNetwork,
         MacAddr,
         NetworkData,
+        NetworkDiff,
+        NetworkTotals,
         Networks,
     );
 
@@ -268,6 +347,15 @@ mod test {
         check_is_supported(IS_SUPPORTED_SYSTEM);
     }
 
+    #[test]
+    fn check_clock_ticks_per_second() {
+        let ticks = clock_ticks_per_second();
+        assert!(ticks > 0);
+        if !IS_SUPPORTED_SYSTEM || cfg!(windows) {
+            assert_eq!(ticks, 100);
+        }
+    }
+
     // If this test doesn't compile, it means the current OS doesn't implement them correctly.
     #[cfg(feature = "system")]
     #[test]
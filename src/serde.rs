@@ -15,8 +15,8 @@ impl Serialize for crate::Disk {
     where
         S: Serializer,
     {
-        // `7` corresponds to the (maximum) number of fields.
-        let mut state = serializer.serialize_struct("Disk", 7)?;
+        // `8` corresponds to the (maximum) number of fields.
+        let mut state = serializer.serialize_struct("Disk", 8)?;
 
         state.serialize_field("DiskKind", &self.kind())?;
         if let Some(s) = self.name().to_str() {
@@ -26,6 +26,7 @@ impl Serialize for crate::Disk {
         state.serialize_field("mount_point", &self.mount_point())?;
         state.serialize_field("total_space", &self.total_space())?;
         state.serialize_field("available_space", &self.available_space())?;
+        state.serialize_field("free_space", &self.free_space())?;
         state.serialize_field("is_removable", &self.is_removable())?;
 
         state.end()
@@ -62,6 +63,44 @@ impl Serialize for crate::DiskKind {
     }
 }
 
+#[cfg(feature = "disk")]
+impl Serialize for crate::MountKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Block => (0, "Block"),
+            Self::Bind => (1, "Bind"),
+            Self::Overlay => (2, "Overlay"),
+            Self::Tmpfs => (3, "Tmpfs"),
+            Self::Network => (4, "Network"),
+            Self::Pseudo => (5, "Pseudo"),
+            Self::Unknown => (6, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("MountKind", index, variant)
+    }
+}
+
+#[cfg(feature = "disk")]
+impl Serialize for crate::MountPropagation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Shared => (0, "Shared"),
+            Self::Slave => (1, "Slave"),
+            Self::SharedAndSlave => (2, "SharedAndSlave"),
+            Self::Private => (3, "Private"),
+            Self::Unbindable => (4, "Unbindable"),
+        };
+
+        serializer.serialize_unit_variant("MountPropagation", index, variant)
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::Pid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -176,6 +215,62 @@ impl Serialize for crate::CGroupLimits {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::ProcessKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Kernel => (0, "Kernel"),
+            Self::System => (1, "System"),
+            Self::User => (2, "User"),
+            Self::Unknown => (3, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("ProcessKind", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::Bitness {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Bit32 => (0, "Bit32"),
+            Self::Bit64 => (1, "Bit64"),
+        };
+
+        serializer.serialize_unit_variant("Bitness", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::SchedPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::Other => (0, "Other", None),
+            Self::Fifo => (1, "Fifo", None),
+            Self::RoundRobin => (2, "RoundRobin", None),
+            Self::Batch => (3, "Batch", None),
+            Self::Idle => (4, "Idle", None),
+            Self::Deadline => (5, "Deadline", None),
+            Self::Unknown(n) => (6, "Unknown", Some(n)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("SchedPolicy", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("SchedPolicy", index, variant)
+        }
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::ThreadKind {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -191,6 +286,24 @@ impl Serialize for crate::ThreadKind {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::FdKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::File => (0, "File"),
+            Self::Socket => (1, "Socket"),
+            Self::Pipe => (2, "Pipe"),
+            Self::Anon => (3, "Anon"),
+            Self::Char => (4, "Char"),
+        };
+
+        serializer.serialize_unit_variant("FdKind", index, variant)
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::Signal {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -252,6 +365,27 @@ impl Serialize for crate::LoadAvg {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::SystemSummary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `8` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SystemSummary", 8)?;
+
+        state.serialize_field("global_cpu_usage", &self.global_cpu_usage)?;
+        state.serialize_field("used_memory", &self.used_memory)?;
+        state.serialize_field("total_memory", &self.total_memory)?;
+        state.serialize_field("used_swap", &self.used_swap)?;
+        state.serialize_field("total_swap", &self.total_swap)?;
+        state.serialize_field("process_count", &self.process_count)?;
+        state.serialize_field("uptime", &self.uptime)?;
+        state.serialize_field("load_average", &self.load_average)?;
+        state.end()
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::ProcessStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -15,6 +15,8 @@ impl ComponentInner {
         None
     }
 
+    pub(crate) fn reset_max(&mut self) {}
+
     pub(crate) fn critical(&self) -> Option<f32> {
         None
     }
@@ -4,7 +4,7 @@ use std::ffi::{OsStr, OsString};
 use std::path::Path;
 use std::process::ExitStatus;
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{Bitness, DiskUsage, Gid, OpenFile, Pid, ProcessStatus, Signal, Uid};
 
 pub(crate) struct ProcessInner;
 
@@ -45,6 +45,14 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn memory_delta(&self) -> i64 {
+        0
+    }
+
+    pub(crate) fn bitness(&self) -> Option<Bitness> {
+        None
+    }
+
     pub(crate) fn virtual_memory(&self) -> u64 {
         0
     }
@@ -69,6 +77,10 @@ impl ProcessInner {
         0.0
     }
 
+    pub(crate) fn cpu_count(&self) -> usize {
+        0
+    }
+
     pub(crate) fn accumulated_cpu_time(&self) -> u64 {
         0
     }
@@ -101,6 +113,14 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn open_files(&self) -> Option<&[OpenFile]> {
+        None
+    }
+
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        None
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         false
     }
@@ -1,7 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::disk::sum_disks_usage;
 use crate::{sys::ffi, DiskUsage};
-use crate::{Disk, DiskKind, DiskRefreshKind};
+use crate::{CompressionInfo, Disk, DiskKind, DiskRefreshKind, MountEntry};
 
 use objc2_core_foundation::{
     kCFAllocatorDefault, kCFTypeArrayCallBacks, kCFURLVolumeAvailableCapacityForImportantUsageKey,
@@ -14,7 +15,7 @@ use objc2_core_foundation::{
 
 use libc::c_void;
 
-use std::ffi::{CStr, OsStr, OsString};
+use std::ffi::{CStr, CString, OsStr, OsString};
 use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::path::{Path, PathBuf};
 use std::ptr;
@@ -29,6 +30,9 @@ pub(crate) struct DiskInner {
     volume_url: CFRetained<CFURL>,
     pub(crate) total_space: u64,
     pub(crate) available_space: u64,
+    /// Value of `available_space` before the last refresh, `None` until it was retrieved.
+    pub(crate) old_available_space: Option<u64>,
+    pub(crate) free_space: u64,
     pub(crate) is_removable: bool,
     pub(crate) is_read_only: bool,
     pub(crate) old_written_bytes: u64,
@@ -63,6 +67,36 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn available_space_delta(&self) -> i64 {
+        self.old_available_space
+            .map_or(0, |old| self.available_space as i64 - old as i64)
+    }
+
+    pub(crate) fn compression(&self) -> Option<CompressionInfo> {
+        // APFS always supports transparent compression.
+        if self.file_system != "apfs" {
+            return None;
+        }
+        Some(CompressionInfo {
+            is_enabled: true,
+            algorithm: None,
+            ratio: None,
+        })
+    }
+
+    fn set_available_space(&mut self, available_space: u64) {
+        // The first time, there is no previous value so the delta is `0`.
+        self.old_available_space = Some(
+            self.old_available_space
+                .map_or(available_space, |_| self.available_space),
+        );
+        self.available_space = available_space;
+    }
+
+    pub(crate) fn free_space(&self) -> u64 {
+        self.free_space
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -91,11 +125,17 @@ impl DiskInner {
                                 }
                             }
                             match get_available_volume_space(&disk_props) {
-                                Some(available_space) => self.available_space = available_space,
+                                Some(available_space) => self.set_available_space(available_space),
                                 None => {
                                     sysinfo_debug!("Failed to get disk available space");
                                 }
                             }
+                            match get_free_space(&self.mount_point) {
+                                Some(free_space) => self.free_space = free_space,
+                                None => {
+                                    sysinfo_debug!("Failed to get disk free space");
+                                }
+                            }
                         }
                         None => {
                             sysinfo_debug!("Failed to get disk properties");
@@ -201,6 +241,14 @@ impl crate::DisksInner {
         }
     }
 
+    pub(crate) fn total_io(&self) -> DiskUsage {
+        sum_disks_usage(
+            self.disks
+                .iter()
+                .filter_map(|disk| Some((disk.inner.bsd_name.as_ref()?, disk.usage()))),
+        )
+    }
+
     pub(crate) fn list(&self) -> &[Disk] {
         &self.disks
     }
@@ -208,25 +256,70 @@ impl crate::DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn list_mounts() -> Vec<MountEntry> {
+        unsafe { get_raw_disks() }
+            .unwrap_or_default()
+            .iter()
+            .map(|c_disk| MountEntry {
+                device: c_buf_to_os_string(&c_disk.f_mntfromname),
+                mount_point: PathBuf::from(c_buf_to_os_string(&c_disk.f_mntonname)),
+                fs_type: c_buf_to_os_string(&c_disk.f_fstypename),
+                options: mount_options(c_disk.f_flags),
+            })
+            .collect()
+    }
 }
 
-unsafe fn get_list(container: &mut Vec<Disk>, refresh_kind: DiskRefreshKind) {
-    let raw_disks = {
-        let count = libc::getfsstat(ptr::null_mut(), 0, libc::MNT_NOWAIT);
-        if count < 1 {
-            return;
-        }
-        let bufsize = count * std::mem::size_of::<libc::statfs>() as libc::c_int;
-        let mut disks = Vec::with_capacity(count as _);
-        let count = libc::getfsstat(disks.as_mut_ptr(), bufsize, libc::MNT_NOWAIT);
+/// Returns the list of the mounted file systems. With `MNT_NOWAIT`, the file systems are not
+/// queried so it can't block.
+unsafe fn get_raw_disks() -> Option<Vec<libc::statfs>> {
+    let count = libc::getfsstat(ptr::null_mut(), 0, libc::MNT_NOWAIT);
+    if count < 1 {
+        return None;
+    }
+    let bufsize = count * std::mem::size_of::<libc::statfs>() as libc::c_int;
+    let mut disks = Vec::with_capacity(count as _);
+    let count = libc::getfsstat(disks.as_mut_ptr(), bufsize, libc::MNT_NOWAIT);
 
-        if count < 1 {
-            return;
-        }
+    if count < 1 {
+        return None;
+    }
+
+    disks.set_len(count as usize);
+
+    Some(disks)
+}
+
+fn c_buf_to_os_string(buf: &[libc::c_char]) -> OsString {
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    OsString::from_vec(buf[..len].iter().map(|c| *c as u8).collect())
+}
 
-        disks.set_len(count as usize);
+/// Converts the `MNT_*` flags of a mount point into a list of options.
+fn mount_options(flags: u32) -> String {
+    let mut options = vec![if flags & libc::MNT_RDONLY as u32 != 0 {
+        "ro"
+    } else {
+        "rw"
+    }];
+    for (flag, option) in [
+        (libc::MNT_NOSUID, "nosuid"),
+        (libc::MNT_NOEXEC, "noexec"),
+        (libc::MNT_NODEV, "nodev"),
+        (libc::MNT_SYNCHRONOUS, "sync"),
+        (libc::MNT_NOATIME, "noatime"),
+    ] {
+        if flags & flag as u32 != 0 {
+            options.push(option);
+        }
+    }
+    options.join(",")
+}
 
-        disks
+unsafe fn get_list(container: &mut Vec<Disk>, refresh_kind: DiskRefreshKind) {
+    let Some(raw_disks) = get_raw_disks() else {
+        return;
     };
 
     // Currently we query maximum 9 properties.
@@ -342,6 +435,19 @@ fn get_disk_properties(
     }
 }
 
+/// Returns the number of free bytes on the disk, including the blocks reserved for the
+/// superuser.
+fn get_free_space(mount_point: &Path) -> Option<u64> {
+    let path = CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+    unsafe {
+        let mut stat: libc::statfs = std::mem::zeroed();
+        if libc::statfs(path.as_ptr(), &mut stat) != 0 {
+            return None;
+        }
+        Some((stat.f_bfree as u64).saturating_mul(stat.f_bsize as u64))
+    }
+}
+
 fn get_available_volume_space(disk_props: &CFDictionary) -> Option<u64> {
     // We prefer `AvailableCapacityForImportantUsage` over `AvailableCapacity` because
     // it takes more of the system's properties into account, like the trash, system-managed caches,
@@ -400,13 +506,14 @@ unsafe fn new_disk(
     disk_props: &CFDictionary,
     refresh_kind: DiskRefreshKind,
 ) -> Option<Disk> {
-    let (total_space, available_space) = if refresh_kind.storage() {
+    let (total_space, available_space, free_space) = if refresh_kind.storage() {
         (
             get_int_value(disk_props, kCFURLVolumeTotalCapacityKey),
             get_available_volume_space(disk_props),
+            get_free_space(&mount_point),
         )
     } else {
-        (None, None)
+        (None, None, None)
     };
 
     // We update the existing disk here to prevent having another call to get `storage` info.
@@ -416,7 +523,10 @@ unsafe fn new_disk(
             disk.total_space = total_space;
         }
         if let Some(available_space) = available_space {
-            disk.available_space = available_space;
+            disk.set_available_space(available_space);
+        }
+        if let Some(free_space) = free_space {
+            disk.free_space = free_space;
         }
         disk.refresh_io(refresh_kind);
         disk.refresh_kind(refresh_kind);
@@ -429,19 +539,7 @@ unsafe fn new_disk(
 
     let name = get_str_value(disk_props, kCFURLVolumeNameKey).map(OsString::from)?;
 
-    let file_system = {
-        let len = c_disk
-            .f_fstypename
-            .iter()
-            .position(|&b| b == 0)
-            .unwrap_or(c_disk.f_fstypename.len());
-        OsString::from_vec(
-            c_disk.f_fstypename[..len]
-                .iter()
-                .map(|c| *c as u8)
-                .collect(),
-        )
-    };
+    let file_system = c_buf_to_os_string(&c_disk.f_fstypename);
 
     #[cfg(target_os = "macos")]
     let bsd_name = get_bsd_name(&c_disk);
@@ -477,6 +575,8 @@ unsafe fn new_disk(
         volume_url,
         total_space: total_space.unwrap_or(0),
         available_space: available_space.unwrap_or(0),
+        old_available_space: available_space,
+        free_space: free_space.unwrap_or(0),
         is_removable,
         is_read_only,
         read_bytes: 0,
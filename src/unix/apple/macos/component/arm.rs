@@ -150,6 +150,12 @@ impl ComponentInner {
         Some(self.max)
     }
 
+    pub(crate) fn reset_max(&mut self) {
+        if let Some(temperature) = self.temperature {
+            self.max = temperature;
+        }
+    }
+
     pub(crate) fn critical(&self) -> Option<f32> {
         self.critical
     }
@@ -403,3 +403,33 @@ mod io_service {
     target_arch = "aarch64"
 ))]
 pub use io_service::*;
+
+// Not exposed by `libc`, from `sys/proc_info.h`.
+#[cfg(all(feature = "system", not(feature = "apple-sandbox")))]
+mod proc_info {
+    #[allow(non_camel_case_types)]
+    #[repr(C)]
+    pub struct proc_fileinfo {
+        pub fi_openflags: u32,
+        pub fi_status: u32,
+        pub fi_offset: libc::off_t,
+        pub fi_type: i32,
+        pub fi_guardflags: u32,
+    }
+
+    #[allow(non_camel_case_types)]
+    #[repr(C)]
+    pub struct vnode_fdinfowithpath {
+        pub pfi: proc_fileinfo,
+        pub pvip: libc::vnode_info_path,
+    }
+
+    pub const PROC_PIDFDVNODEPATHINFO: libc::c_int = 2;
+
+    // From `sys/vnode.h`.
+    pub const VCHR: libc::c_int = 4;
+    pub const VFIFO: libc::c_int = 7;
+}
+
+#[cfg(all(feature = "system", not(feature = "apple-sandbox")))]
+pub use proc_info::*;
@@ -8,8 +8,12 @@ use std::process::ExitStatus;
 
 use libc::{c_int, c_void, kill};
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    Bitness, DiskUsage, FdKind, Gid, OpenFile, Pid, Process, ProcessRefreshKind, ProcessStatus,
+    Signal, Uid,
+};
 
+use crate::sys::ffi;
 use crate::sys::process::ThreadStatus;
 use crate::sys::system::Wrap;
 use crate::unix::utils::cstr_to_rust_with_size;
@@ -25,12 +29,15 @@ pub(crate) struct ProcessInner {
     pub(crate) root: Option<PathBuf>,
     pub(crate) memory: u64,
     pub(crate) virtual_memory: u64,
+    old_memory: u64,
     old_utime: u64,
     old_stime: u64,
     start_time: u64,
     run_time: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
+    /// Number of CPUs when `cpu_usage` was last computed.
+    pub(crate) cpu_count: usize,
     user_id: Option<Uid>,
     effective_user_id: Option<Uid>,
     group_id: Option<Gid>,
@@ -46,6 +53,7 @@ pub(crate) struct ProcessInner {
     pub(crate) read_bytes: u64,
     pub(crate) written_bytes: u64,
     accumulated_cpu_time: u64,
+    open_files: Option<Vec<OpenFile>>,
 }
 
 impl ProcessInner {
@@ -61,7 +69,9 @@ impl ProcessInner {
             root: None,
             memory: 0,
             virtual_memory: 0,
+            old_memory: 0,
             cpu_usage: 0.,
+            cpu_count: 0,
             old_utime: 0,
             old_stime: 0,
             updated: true,
@@ -78,6 +88,7 @@ impl ProcessInner {
             read_bytes: 0,
             written_bytes: 0,
             accumulated_cpu_time: 0,
+            open_files: None,
         }
     }
 
@@ -93,7 +104,9 @@ impl ProcessInner {
             root: None,
             memory: 0,
             virtual_memory: 0,
+            old_memory: 0,
             cpu_usage: 0.,
+            cpu_count: 0,
             old_utime: 0,
             old_stime: 0,
             updated: true,
@@ -110,6 +123,7 @@ impl ProcessInner {
             read_bytes: 0,
             written_bytes: 0,
             accumulated_cpu_time: 0,
+            open_files: None,
         }
     }
 
@@ -138,6 +152,10 @@ impl ProcessInner {
         &self.environ
     }
 
+    pub(crate) fn open_files(&self) -> Option<&[OpenFile]> {
+        self.open_files.as_deref()
+    }
+
     pub(crate) fn cwd(&self) -> Option<&Path> {
         self.cwd.as_deref()
     }
@@ -150,6 +168,24 @@ impl ProcessInner {
         self.memory
     }
 
+    pub(crate) fn bitness(&self) -> Option<Bitness> {
+        // `PROC_FLAG_LP64` from `<sys/proc_info.h>`.
+        const PROC_FLAG_LP64: u32 = 0x10;
+
+        if let Some(info) = unsafe { get_bsd_info(self.pid) } {
+            return Some(if info.pbi_flags & PROC_FLAG_LP64 != 0 {
+                Bitness::Bit64
+            } else {
+                Bitness::Bit32
+            });
+        }
+        macho_bitness(self.exe.as_deref()?)
+    }
+
+    pub(crate) fn memory_delta(&self) -> i64 {
+        self.memory as i64 - self.old_memory as i64
+    }
+
     pub(crate) fn virtual_memory(&self) -> u64 {
         self.virtual_memory
     }
@@ -181,6 +217,10 @@ impl ProcessInner {
         self.cpu_usage
     }
 
+    pub(crate) fn cpu_count(&self) -> usize {
+        self.cpu_count
+    }
+
     pub(crate) fn accumulated_cpu_time(&self) -> u64 {
         self.accumulated_cpu_time
     }
@@ -225,6 +265,17 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        unsafe {
+            let group_id = libc::getpgid(self.pid.0);
+            if group_id < 0 {
+                None
+            } else {
+                Some(Pid(group_id))
+            }
+        }
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         std::mem::replace(&mut self.updated, false)
     }
@@ -320,6 +371,26 @@ fn check_if_pid_is_alive(pid: Pid, check_if_alive: bool) -> bool {
     }
 }
 
+/// Returns the bitness of the Mach-O executable at `path`. Universal binaries contain both so
+/// `None` is returned for them.
+fn macho_bitness(path: &Path) -> Option<Bitness> {
+    use std::io::Read;
+
+    const MH_MAGIC: u32 = 0xfeedface;
+    const MH_MAGIC_64: u32 = 0xfeedfacf;
+
+    let mut magic = [0; 4];
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .ok()?;
+    // The header is in the byte order of the targeted architecture.
+    match (u32::from_le_bytes(magic), u32::from_be_bytes(magic)) {
+        (MH_MAGIC, _) | (_, MH_MAGIC) => Some(Bitness::Bit32),
+        (MH_MAGIC_64, _) | (_, MH_MAGIC_64) => Some(Bitness::Bit64),
+        _ => None,
+    }
+}
+
 unsafe fn get_bsd_info(pid: Pid) -> Option<libc::proc_bsdinfo> {
     let mut info = mem::zeroed::<libc::proc_bsdinfo>();
 
@@ -388,6 +459,8 @@ unsafe fn create_new_process(
         }
         if refresh_kind.memory() {
             p.memory = task_info.pti_resident_size;
+            // No previous value for a new process.
+            p.old_memory = p.memory;
             p.virtual_memory = task_info.pti_virtual_size;
         }
     }
@@ -400,6 +473,9 @@ unsafe fn create_new_process(
     if refresh_kind.disk_usage() {
         update_proc_disk_activity(&mut p);
     }
+    if refresh_kind.open_files() {
+        p.open_files = get_open_files(pid);
+    }
     Ok(Some(Process { inner: p }))
 }
 
@@ -447,6 +523,66 @@ unsafe fn convert_node_path_info(node: &libc::vnode_info_path) -> Option<PathBuf
     .map(PathBuf::from)
 }
 
+unsafe fn get_open_files(pid: Pid) -> Option<Vec<OpenFile>> {
+    let fdinfo_size = mem::size_of::<libc::proc_fdinfo>();
+    // We first retrieve the size of the buffer we need.
+    let size = libc::proc_pidinfo(pid.0, libc::PROC_PIDLISTFDS, 0, std::ptr::null_mut(), 0);
+    if size <= 0 {
+        sysinfo_debug!("Failed to retrieve file descriptors for {}", pid.0);
+        return None;
+    }
+    let mut fds: Vec<libc::proc_fdinfo> = Vec::with_capacity(size as usize / fdinfo_size);
+    let size = libc::proc_pidinfo(
+        pid.0,
+        libc::PROC_PIDLISTFDS,
+        0,
+        fds.as_mut_ptr() as *mut c_void,
+        (fds.capacity() * fdinfo_size) as _,
+    );
+    if size <= 0 {
+        sysinfo_debug!("Failed to retrieve file descriptors for {}", pid.0);
+        return None;
+    }
+    fds.set_len(size as usize / fdinfo_size);
+
+    Some(
+        fds.iter()
+            .map(|fd| {
+                let (kind, path) = match fd.proc_fdtype as c_int {
+                    libc::PROX_FDTYPE_VNODE => {
+                        let mut info = mem::zeroed::<ffi::vnode_fdinfowithpath>();
+                        if libc::proc_pidfdinfo(
+                            pid.0,
+                            fd.proc_fd,
+                            ffi::PROC_PIDFDVNODEPATHINFO,
+                            &mut info as *mut _ as *mut c_void,
+                            mem::size_of::<ffi::vnode_fdinfowithpath>() as _,
+                        ) > 0
+                        {
+                            let kind = match info.pvip.vip_vi.vi_type {
+                                ffi::VCHR => FdKind::Char,
+                                ffi::VFIFO => FdKind::Pipe,
+                                _ => FdKind::File,
+                            };
+                            (kind, convert_node_path_info(&info.pvip))
+                        } else {
+                            (FdKind::File, None)
+                        }
+                    }
+                    libc::PROX_FDTYPE_SOCKET => (FdKind::Socket, None),
+                    libc::PROX_FDTYPE_PIPE => (FdKind::Pipe, None),
+                    _ => (FdKind::Anon, None),
+                };
+                OpenFile {
+                    fd: fd.proc_fd,
+                    kind,
+                    path,
+                }
+            })
+            .collect(),
+    )
+}
+
 unsafe fn get_cwd_root(process: &mut ProcessInner, refresh_kind: ProcessRefreshKind) {
     let cwd_needs_update = refresh_kind.cwd().needs_update(|| process.cwd.is_none());
     let root_needs_update = refresh_kind.root().needs_update(|| process.root.is_none());
@@ -676,6 +812,9 @@ pub(crate) fn update_process(
             if refresh_kind.disk_usage() {
                 update_proc_disk_activity(p);
             }
+            if refresh_kind.open_files() {
+                p.open_files = get_open_files(pid);
+            }
 
             let mut thread_info = mem::zeroed::<libc::proc_threadinfo>();
             let (user_time, system_time, thread_status) = if libc::proc_pidinfo(
@@ -714,6 +853,7 @@ pub(crate) fn update_process(
                         * timebase_to_ms) as u64;
                 }
                 if refresh_kind.memory() {
+                    p.old_memory = p.memory;
                     p.memory = task_info.pti_resident_size;
                     p.virtual_memory = task_info.pti_virtual_size;
                 }
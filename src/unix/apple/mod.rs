@@ -51,7 +51,7 @@ cfg_if! {
         pub mod users;
 
         pub(crate) use crate::unix::groups::get_groups;
-        pub(crate) use crate::unix::users::{get_users, UserInner};
+        pub(crate) use crate::unix::users::{get_current_user_id, get_users, UserInner};
     }
 }
 
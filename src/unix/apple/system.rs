@@ -6,7 +6,7 @@ use crate::sys::process::*;
 use crate::sys::utils::{get_sys_value, get_sys_value_by_name};
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
+    Cpu, CpuRefreshKind, LoadAvg, MemoryInfo, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
     ProcessesToUpdate,
 };
 
@@ -86,6 +86,7 @@ pub(crate) struct SystemInner {
     #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
     clock_info: Option<crate::sys::macos::system::SystemTimeInfo>,
     cpus: CpusWrapper,
+    physical_core_count: Option<Option<usize>>,
 }
 
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
@@ -121,6 +122,69 @@ fn boot_time() -> u64 {
     }
 }
 
+/// Returns the total and the available swap.
+unsafe fn get_swap_usage() -> Option<(u64, u64)> {
+    let mut mib = [libc::CTL_VM as _, libc::VM_SWAPUSAGE as _];
+    let mut xs: libc::xsw_usage = mem::zeroed::<libc::xsw_usage>();
+    if get_sys_value(
+        mem::size_of::<libc::xsw_usage>(),
+        &mut xs as *mut _ as *mut c_void,
+        &mut mib,
+    ) {
+        Some((xs.xsu_total, xs.xsu_avail))
+    } else {
+        None
+    }
+}
+
+unsafe fn get_total_memory() -> u64 {
+    let mut mib = [libc::CTL_HW as _, libc::HW_MEMSIZE as _];
+    let mut mem_total = 0u64;
+    get_sys_value(
+        mem::size_of::<u64>(),
+        &mut mem_total as *mut u64 as *mut c_void,
+        &mut mib,
+    );
+    mem_total
+}
+
+/// Returns the available, used and free memory.
+unsafe fn get_vm_stats(port: mach_port_t, page_size_b: u64) -> Option<(u64, u64, u64)> {
+    let mut count: u32 = libc::HOST_VM_INFO64_COUNT as _;
+    let mut stat = mem::zeroed::<vm_statistics64>();
+    if host_statistics64(
+        port,
+        libc::HOST_VM_INFO64,
+        &mut stat as *mut vm_statistics64 as *mut _,
+        &mut count,
+    ) != libc::KERN_SUCCESS
+    {
+        return None;
+    }
+    // From the apple documentation:
+    //
+    // /*
+    //  * NB: speculative pages are already accounted for in "free_count",
+    //  * so "speculative_count" is the number of "free" pages that are
+    //  * used to hold data that was read speculatively from disk but
+    //  * haven't actually been used by anyone so far.
+    //  */
+    let available = u64::from(stat.free_count)
+        .saturating_add(u64::from(stat.inactive_count))
+        .saturating_add(u64::from(stat.purgeable_count))
+        .saturating_sub(u64::from(stat.compressor_page_count))
+        .saturating_mul(page_size_b);
+    let used = u64::from(stat.active_count)
+        .saturating_add(u64::from(stat.wire_count))
+        .saturating_add(u64::from(stat.compressor_page_count))
+        .saturating_add(u64::from(stat.speculative_count))
+        .saturating_mul(page_size_b);
+    let free = u64::from(stat.free_count)
+        .saturating_sub(u64::from(stat.speculative_count))
+        .saturating_mul(page_size_b);
+    Some((available, used, free))
+}
+
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
 fn get_now() -> u64 {
     SystemTime::now()
@@ -148,68 +212,27 @@ impl SystemInner {
                 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
                 clock_info: crate::sys::macos::system::SystemTimeInfo::new(port),
                 cpus: CpusWrapper::new(),
+                physical_core_count: None,
             }
         }
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
-        let mut mib = [libc::CTL_VM as _, libc::VM_SWAPUSAGE as _];
-
         unsafe {
             if refresh_kind.swap() {
-                // get system values
-                // get swap info
-                let mut xs: libc::xsw_usage = mem::zeroed::<libc::xsw_usage>();
-                if get_sys_value(
-                    mem::size_of::<libc::xsw_usage>(),
-                    &mut xs as *mut _ as *mut c_void,
-                    &mut mib,
-                ) {
-                    self.swap_total = xs.xsu_total;
-                    self.swap_free = xs.xsu_avail;
+                if let Some((swap_total, swap_free)) = get_swap_usage() {
+                    self.swap_total = swap_total;
+                    self.swap_free = swap_free;
                 }
             }
             if refresh_kind.ram() {
-                mib[0] = libc::CTL_HW as _;
-                mib[1] = libc::HW_MEMSIZE as _;
-                // get ram info
                 if self.mem_total < 1 {
-                    get_sys_value(
-                        mem::size_of::<u64>(),
-                        &mut self.mem_total as *mut u64 as *mut c_void,
-                        &mut mib,
-                    );
+                    self.mem_total = get_total_memory();
                 }
-                let mut count: u32 = libc::HOST_VM_INFO64_COUNT as _;
-                let mut stat = mem::zeroed::<vm_statistics64>();
-                if host_statistics64(
-                    self.port,
-                    libc::HOST_VM_INFO64,
-                    &mut stat as *mut vm_statistics64 as *mut _,
-                    &mut count,
-                ) == libc::KERN_SUCCESS
-                {
-                    // From the apple documentation:
-                    //
-                    // /*
-                    //  * NB: speculative pages are already accounted for in "free_count",
-                    //  * so "speculative_count" is the number of "free" pages that are
-                    //  * used to hold data that was read speculatively from disk but
-                    //  * haven't actually been used by anyone so far.
-                    //  */
-                    self.mem_available = u64::from(stat.free_count)
-                        .saturating_add(u64::from(stat.inactive_count))
-                        .saturating_add(u64::from(stat.purgeable_count))
-                        .saturating_sub(u64::from(stat.compressor_page_count))
-                        .saturating_mul(self.page_size_b);
-                    self.mem_used = u64::from(stat.active_count)
-                        .saturating_add(u64::from(stat.wire_count))
-                        .saturating_add(u64::from(stat.compressor_page_count))
-                        .saturating_add(u64::from(stat.speculative_count))
-                        .saturating_mul(self.page_size_b);
-                    self.mem_free = u64::from(stat.free_count)
-                        .saturating_sub(u64::from(stat.speculative_count))
-                        .saturating_mul(self.page_size_b);
+                if let Some((available, used, free)) = get_vm_stats(self.port, self.page_size_b) {
+                    self.mem_available = available;
+                    self.mem_used = used;
+                    self.mem_free = free;
                 }
             }
         }
@@ -223,6 +246,18 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind, self.port);
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        // The global CPU usage is computed from the usage of each CPU.
+        self.cpus
+            .refresh(CpuRefreshKind::nothing().with_cpu_usage(), self.port);
+    }
+
+    pub(crate) fn refresh_cpu(&mut self, index: usize, refresh_kind: CpuRefreshKind) -> bool {
+        // The times of all CPUs are retrieved at once.
+        self.cpus.refresh(refresh_kind, self.port);
+        index < self.cpus.cpus.len()
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus = CpusWrapper::new();
         self.cpus.refresh(refresh_kind, self.port);
@@ -314,6 +349,16 @@ impl SystemInner {
             entries.into_iter().for_each(|entry| {
                 self.process_list.insert(entry.pid(), entry);
             });
+            if refresh_kind.cpu() {
+                let cpu_count = std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1);
+                for process in self.process_list.values_mut() {
+                    if process.inner.updated {
+                        process.inner.cpu_count = cpu_count;
+                    }
+                }
+            }
             nb_updated.into_inner()
         } else {
             0
@@ -373,6 +418,24 @@ impl SystemInner {
         self.swap_total - self.swap_free
     }
 
+    pub(crate) fn memory_info() -> MemoryInfo {
+        unsafe {
+            #[allow(deprecated)]
+            let port = libc::mach_host_self();
+            let (available, used, free) =
+                get_vm_stats(port, sysconf(_SC_PAGESIZE) as _).unwrap_or_default();
+            let (total_swap, free_swap) = get_swap_usage().unwrap_or_default();
+            MemoryInfo {
+                total: get_total_memory(),
+                free,
+                available,
+                used,
+                total_swap,
+                free_swap,
+            }
+        }
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe {
             let csec = libc::time(::std::ptr::null_mut());
@@ -457,6 +520,10 @@ impl SystemInner {
         get_system_info(libc::KERN_HOSTNAME, None)
     }
 
+    pub(crate) fn fqdn() -> Option<String> {
+        crate::unix::utils::fqdn(&Self::host_name()?)
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         get_system_info(libc::KERN_OSRELEASE, None)
     }
@@ -523,9 +590,29 @@ impl SystemInner {
         }
     }
 
+    #[cfg(target_os = "macos")]
+    pub(crate) fn active_desktop_user() -> Option<String> {
+        use std::os::unix::fs::MetadataExt;
+
+        // `/dev/console` is owned by the user logged in the graphical session, which is what
+        // `SCDynamicStoreCopyConsoleUser` returns as well. It's owned by `root` when nobody is
+        // logged in.
+        let uid = std::fs::metadata("/dev/console").ok()?.uid();
+        if uid == 0 {
+            return None;
+        }
+        crate::unix::utils::user_name(uid)
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         physical_core_count()
     }
+
+    pub(crate) fn physical_core_count_cached(&mut self) -> Option<usize> {
+        *self
+            .physical_core_count
+            .get_or_insert_with(Self::physical_core_count)
+    }
 }
 
 fn get_system_info(value: c_int, default: Option<&str>) -> Option<String> {
@@ -15,7 +15,7 @@ fn endswith(s1: *const c_char, s2: &[u8]) -> bool {
     unsafe {
         let mut len = strlen(s1) as isize - 1;
         let mut i = s2.len() as isize - 1;
-        while len >= 0 && i >= 0 && *s1.offset(len) == s2[i as usize] as _ {
+        while len >= 0 && i >= 0 && *s1.offset(len) == s2[i as usize] as c_char {
             i -= 1;
             len -= 1;
         }
@@ -15,8 +15,9 @@ use super::ffi::{
     geom_stats_snapshot_get, geom_stats_snapshot_next, geom_stats_snapshot_reset, DEVSTAT_READ,
     DEVSTAT_WRITE,
 };
-use super::utils::{c_buf_to_utf8_str, get_sys_value_str_by_name};
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use super::utils::{c_buf_to_os_string, c_buf_to_utf8_str, get_sys_value_str_by_name};
+use crate::common::disk::sum_disks_usage;
+use crate::{CompressionInfo, Disk, DiskKind, DiskRefreshKind, DiskUsage, MountEntry};
 
 #[derive(Debug)]
 pub(crate) struct DiskInner {
@@ -26,6 +27,9 @@ pub(crate) struct DiskInner {
     mount_point: PathBuf,
     total_space: u64,
     available_space: u64,
+    /// Value of `available_space` before the last refresh, `None` until it was retrieved.
+    old_available_space: Option<u64>,
+    free_space: u64,
     file_system: OsString,
     is_removable: bool,
     is_read_only: bool,
@@ -62,6 +66,24 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn available_space_delta(&self) -> i64 {
+        self.old_available_space
+            .map_or(0, |old| self.available_space as i64 - old as i64)
+    }
+
+    fn set_available_space(&mut self, available_space: u64) {
+        // The first time, there is no previous value so the delta is `0`.
+        self.old_available_space = Some(
+            self.old_available_space
+                .map_or(available_space, |_| self.available_space),
+        );
+        self.available_space = available_space;
+    }
+
+    pub(crate) fn free_space(&self) -> u64 {
+        self.free_space
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -70,6 +92,10 @@ impl DiskInner {
         self.is_read_only
     }
 
+    pub(crate) fn compression(&self) -> Option<CompressionInfo> {
+        None
+    }
+
     pub(crate) fn refresh_specifics(&mut self, refresh_kind: DiskRefreshKind) -> bool {
         refresh_disk(self, refresh_kind)
     }
@@ -99,6 +125,14 @@ impl crate::DisksInner {
         unsafe { get_all_list(&mut self.disks, remove_not_listed_disks, refresh_kind) }
     }
 
+    pub(crate) fn total_io(&self) -> DiskUsage {
+        sum_disks_usage(
+            self.disks
+                .iter()
+                .filter_map(|disk| Some((disk.inner.dev_id.as_ref()?, disk.usage()))),
+        )
+    }
+
     pub(crate) fn list(&self) -> &[Disk] {
         &self.disks
     }
@@ -106,6 +140,10 @@ impl crate::DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn list_mounts() -> Vec<MountEntry> {
+        unsafe { get_mounts() }
+    }
 }
 
 trait GetValues {
@@ -161,11 +199,11 @@ impl GetValues for DiskInner {
     }
 }
 
-/// Returns `(total_space, available_space, is_read_only)`.
+/// Returns `(total_space, available_space, free_space, is_read_only)`.
 unsafe fn get_statvfs(
     c_mount_point: &[libc::c_char],
     vfs: &mut libc::statvfs,
-) -> Option<(u64, u64, bool)> {
+) -> Option<(u64, u64, u64, bool)> {
     if libc::statvfs(c_mount_point.as_ptr() as *const _, vfs as *mut _) < 0 {
         sysinfo_debug!("statvfs failed");
         None
@@ -174,6 +212,7 @@ unsafe fn get_statvfs(
         Some((
             vfs.f_blocks.saturating_mul(block_size),
             vfs.f_favail.saturating_mul(block_size),
+            vfs.f_bfree.saturating_mul(block_size),
             (vfs.f_flag & libc::ST_RDONLY) != 0,
         ))
     }
@@ -183,11 +222,12 @@ fn refresh_disk(disk: &mut DiskInner, refresh_kind: DiskRefreshKind) -> bool {
     if refresh_kind.storage() {
         unsafe {
             let mut vfs: libc::statvfs = std::mem::zeroed();
-            if let Some((total_space, available_space, is_read_only)) =
+            if let Some((total_space, available_space, free_space, is_read_only)) =
                 get_statvfs(&disk.c_mount_point, &mut vfs)
             {
                 disk.total_space = total_space;
-                disk.available_space = available_space;
+                disk.set_available_space(available_space);
+                disk.free_space = free_space;
                 disk.is_read_only = is_read_only;
             }
         }
@@ -314,6 +354,49 @@ fn get_disks_mapping() -> HashMap<String, String> {
     disk_mapping
 }
 
+/// Converts the `MNT_*` flags of a mount point into a list of options.
+fn mount_options(fs_info: &libc::statfs) -> String {
+    let flags = fs_info.f_flags;
+    let mut options = vec![if flags & libc::MNT_RDONLY as u64 != 0 {
+        "ro"
+    } else {
+        "rw"
+    }];
+    for (flag, option) in [
+        (libc::MNT_NOSUID, "nosuid"),
+        (libc::MNT_NOEXEC, "noexec"),
+        (libc::MNT_SYNCHRONOUS, "sync"),
+        (libc::MNT_NOATIME, "noatime"),
+    ] {
+        if flags & flag as u64 != 0 {
+            options.push(option);
+        }
+    }
+    options.join(",")
+}
+
+unsafe fn get_mounts() -> Vec<MountEntry> {
+    let mut fs_infos: *mut libc::statfs = null_mut();
+
+    // With `MNT_NOWAIT`, the file systems are not queried so it can't block.
+    let count = libc::getmntinfo(&mut fs_infos, libc::MNT_NOWAIT);
+
+    if count < 1 {
+        return Vec::new();
+    }
+    let fs_infos: &[libc::statfs] = std::slice::from_raw_parts(fs_infos as _, count as _);
+
+    fs_infos
+        .iter()
+        .map(|fs_info| MountEntry {
+            device: c_buf_to_os_string(&fs_info.f_mntfromname),
+            mount_point: PathBuf::from(c_buf_to_os_string(&fs_info.f_mntonname)),
+            fs_type: c_buf_to_os_string(&fs_info.f_fstypename),
+            options: mount_options(fs_info),
+        })
+        .collect()
+}
+
 pub unsafe fn get_all_list(
     container: &mut Vec<Disk>,
     remove_not_listed_disks: bool,
@@ -405,6 +488,8 @@ pub unsafe fn get_all_list(
                 dev_id: disk_mapping.get(dev_mount_point).map(ToString::to_string),
                 total_space: 0,
                 available_space: 0,
+                old_available_space: None,
+                free_space: 0,
                 file_system: OsString::from_vec(fs_type),
                 is_removable,
                 is_read_only: false,
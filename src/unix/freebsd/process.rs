@@ -1,6 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::unix::utils::elf_bitness;
+use crate::{
+    Bitness, DiskUsage, Gid, OpenFile, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -51,8 +54,11 @@ pub(crate) struct ProcessInner {
     pub(crate) root: Option<PathBuf>,
     pub(crate) memory: u64,
     pub(crate) virtual_memory: u64,
+    old_memory: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
+    /// Number of CPUs when `cpu_usage` was last computed.
+    pub(crate) cpu_count: usize,
     start_time: u64,
     run_time: u64,
     pub(crate) status: ProcessStatus,
@@ -105,6 +111,14 @@ impl ProcessInner {
         self.memory
     }
 
+    pub(crate) fn bitness(&self) -> Option<Bitness> {
+        self.exe.as_deref().and_then(elf_bitness)
+    }
+
+    pub(crate) fn memory_delta(&self) -> i64 {
+        self.memory as i64 - self.old_memory as i64
+    }
+
     pub(crate) fn virtual_memory(&self) -> u64 {
         self.virtual_memory
     }
@@ -129,6 +143,10 @@ impl ProcessInner {
         self.cpu_usage
     }
 
+    pub(crate) fn cpu_count(&self) -> usize {
+        self.cpu_count
+    }
+
     pub(crate) fn accumulated_cpu_time(&self) -> u64 {
         self.accumulated_cpu_time
     }
@@ -173,6 +191,21 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        unsafe {
+            let group_id = libc::getpgid(self.pid.0);
+            if group_id < 0 {
+                None
+            } else {
+                Some(Pid(group_id))
+            }
+        }
+    }
+
+    pub(crate) fn open_files(&self) -> Option<&[OpenFile]> {
+        None
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         std::mem::replace(&mut self.updated, false)
     }
@@ -237,6 +270,7 @@ pub(crate) unsafe fn get_process_data(
             proc_.status = status;
             if refresh_kind.memory() {
                 proc_.virtual_memory = virtual_memory;
+                proc_.old_memory = proc_.memory;
                 proc_.memory = memory;
             }
             proc_.run_time = now.saturating_sub(proc_.start_time);
@@ -282,8 +316,10 @@ pub(crate) unsafe fn get_process_data(
             start_time,
             run_time: now.saturating_sub(start_time),
             cpu_usage,
+            cpu_count: 0,
             virtual_memory,
             memory,
+            old_memory: memory,
             // procstat_getfiles
             cwd: None,
             exe: None,
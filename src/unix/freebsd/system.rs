@@ -1,7 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessInner,
+    Cpu, CpuRefreshKind, LoadAvg, MemoryInfo, MemoryRefreshKind, Pid, Process, ProcessInner,
     ProcessRefreshKind, ProcessesToUpdate,
 };
 
@@ -72,6 +72,7 @@ pub(crate) struct SystemInner {
     swap_used: u64,
     system_info: SystemInfo,
     cpus: CpusWrapper,
+    physical_core_count: Option<Option<usize>>,
 }
 
 impl SystemInner {
@@ -85,6 +86,7 @@ impl SystemInner {
             swap_used: 0,
             system_info: SystemInfo::new(),
             cpus: CpusWrapper::new(),
+            physical_core_count: None,
         }
     }
 
@@ -111,6 +113,18 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind)
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        // Both `kern.cp_time` and `kern.cp_times` are retrieved at the same time.
+        self.cpus
+            .refresh(CpuRefreshKind::nothing().with_cpu_usage())
+    }
+
+    pub(crate) fn refresh_cpu(&mut self, index: usize, refresh_kind: CpuRefreshKind) -> bool {
+        // The times of all CPUs are retrieved at once with `kern.cp_times`.
+        self.cpus.refresh(refresh_kind);
+        index < self.cpus.cpus.len()
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus = CpusWrapper::new();
         self.cpus.refresh(refresh_kind);
@@ -177,6 +191,21 @@ impl SystemInner {
         self.swap_used
     }
 
+    pub(crate) fn memory_info() -> MemoryInfo {
+        let system_info = SystemInfo::new();
+        let free = system_info.get_free_memory();
+        let (used_swap, total_swap) = system_info.get_swap_info();
+        MemoryInfo {
+            total: system_info.get_total_memory(),
+            free,
+            // Like `available_memory`, it's the same as the free memory.
+            available: free,
+            used: system_info.get_used_memory(),
+            total_swap,
+            free_swap: total_swap.saturating_sub(used_swap),
+        }
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe {
             let csec = libc::time(std::ptr::null_mut());
@@ -235,6 +264,10 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn fqdn() -> Option<String> {
+        crate::unix::utils::fqdn(&Self::host_name()?)
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         let mut kern_version: [c_int; 2] = [0; 2];
         unsafe {
@@ -272,6 +305,12 @@ impl SystemInner {
     pub(crate) fn physical_core_count() -> Option<usize> {
         physical_core_count()
     }
+
+    pub(crate) fn physical_core_count_cached(&mut self) -> Option<usize> {
+        *self
+            .physical_core_count
+            .get_or_insert_with(Self::physical_core_count)
+    }
 }
 
 impl SystemInner {
@@ -357,9 +396,17 @@ impl SystemInner {
         let kvm_procs: &mut [utils::KInfoProc] =
             std::slice::from_raw_parts_mut(kvm_procs as _, count as _);
 
+        let cpu_count = if refresh_kind.cpu() {
+            utils::get_nb_cpus()
+        } else {
+            0
+        };
         for kproc in kvm_procs {
             if let Some(process) = self.process_list.get_mut(&Pid(kproc.ki_pid)) {
                 add_missing_proc_info(&mut self.system_info, kproc, process, refresh_kind);
+                if refresh_kind.cpu() && process.inner.updated {
+                    process.inner.cpu_count = cpu_count;
+                }
             }
         }
         nb_updated.into_inner()
@@ -90,6 +90,12 @@ pub(crate) struct ComponentInner {
     input_file: Option<PathBuf>,
     /// `temp[1-*]_highest file` to read if available highest value.
     highest_file: Option<PathBuf>,
+    /// Content of the `type` file of the thermal zone (`/sys/class/thermal/thermal_zoneN`)
+    /// backing this component, if any.
+    thermal_zone_type: Option<String>,
+    /// Set by [`Component::reset_max`]: the `highest` value from the kernel can only be reset by
+    /// root, so it's ignored from then on.
+    max_reset: bool,
     pub(crate) updated: bool,
 }
 
@@ -110,6 +116,7 @@ impl ComponentInner {
         if let Some(temp) = temperature {
             self.temperature = Some(temp);
         }
+        let max = if self.max_reset { temperature } else { max };
         match (max, self.max) {
             (Some(new_max), Some(old_max)) => self.max = Some(new_max.max(old_max)),
             (Some(max), None) => self.max = Some(max),
@@ -118,13 +125,22 @@ impl ComponentInner {
         if input_file.is_some() && input_file != self.input_file {
             self.input_file = input_file;
         }
-        if highest_file.is_some() && highest_file != self.highest_file {
+        if !self.max_reset && highest_file.is_some() && highest_file != self.highest_file {
             self.highest_file = highest_file;
         }
         self.updated = true;
     }
 }
 
+// The `hwmon` devices created for thermal zones have the thermal zone as parent `device`.
+fn get_thermal_zone_type(hwmon_folder: &Path) -> Option<String> {
+    let device = std::fs::read_link(hwmon_folder.join("device")).ok()?;
+    if !device.file_name()?.to_str()?.starts_with("thermal_zone") {
+        return None;
+    }
+    get_file_line(&hwmon_folder.join("device/type"), 16)
+}
+
 // Read arbitrary data from sysfs.
 fn get_file_line(file: &Path, capacity: usize) -> Option<String> {
     let mut reader = String::with_capacity(capacity);
@@ -293,6 +309,7 @@ impl ComponentInner {
             component.name = name.unwrap_or_default();
             let device_model = get_file_line(&folder.join("device/model"), 16);
             component.device_model = device_model;
+            component.thermal_zone_type = get_thermal_zone_type(folder);
             fill_component(component, item, folder, filename);
         }
         for (id, mut new_comp) in matchings
@@ -351,6 +368,12 @@ impl ComponentInner {
         self.max
     }
 
+    pub(crate) fn reset_max(&mut self) {
+        self.highest_file = None;
+        self.max_reset = true;
+        self.max = self.temperature;
+    }
+
     pub(crate) fn critical(&self) -> Option<f32> {
         self.threshold_critical
     }
@@ -359,6 +382,10 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn thermal_zone_type(&self) -> Option<&str> {
+        self.thermal_zone_type.as_deref()
+    }
+
     pub(crate) fn refresh(&mut self) {
         let current = self
             .input_file
@@ -370,9 +397,8 @@ impl ComponentInner {
             .as_ref()
             .and_then(|file| get_temperature_from_file(file.as_path()))
             .or_else(|| {
-                let last = self.temperature?;
                 let current = current?;
-                Some(last.max(current))
+                Some(self.max.map_or(current, |max| max.max(current)))
             });
         self.max = max;
         self.temperature = current;
@@ -431,9 +457,54 @@ impl ComponentsInner {
                 let mut component = ComponentInner::default();
                 fill_component(&mut component, "input", thermal_path, "temp");
                 let name = get_file_line(&thermal_path.join("type"), 16);
+                component.thermal_zone_type = name.clone();
                 component.name = name.unwrap_or_default();
                 self.components.push(Component { inner: component });
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ComponentInner;
+    use std::fs;
+
+    #[test]
+    fn test_is_critical_from_hwmon() {
+        let hwmon = tempfile::tempdir().expect("failed to create temporary directory");
+        let hwmon = hwmon.path();
+        let write = |file: &str, content: &str| {
+            fs::write(hwmon.join(file), content).expect("failed to write file");
+        };
+        write("name", "coretemp\n");
+        // Above the critical threshold.
+        write("temp1_input", "90000\n");
+        write("temp1_crit", "85000\n");
+        // Exactly at the critical threshold.
+        write("temp2_input", "85000\n");
+        write("temp2_crit", "85000\n");
+        // Below the critical threshold.
+        write("temp3_input", "40000\n");
+        write("temp3_crit", "85000\n");
+        // No critical threshold.
+        write("temp4_input", "90000\n");
+
+        let mut components = Vec::new();
+        ComponentInner::from_hwmon(&mut components, hwmon);
+        let mut is_critical = components
+            .iter()
+            .map(|c| (c.label().to_owned(), c.is_critical()))
+            .collect::<Vec<_>>();
+        is_critical.sort();
+        assert_eq!(
+            is_critical,
+            [
+                ("coretemp temp1".to_owned(), true),
+                ("coretemp temp2".to_owned(), true),
+                ("coretemp temp3".to_owned(), false),
+                ("coretemp temp4".to_owned(), false),
+            ],
+        );
+    }
+}
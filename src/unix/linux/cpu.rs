@@ -3,8 +3,9 @@
 #![allow(clippy::too_many_arguments)]
 
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
 use std::time::Instant;
 
 use crate::sys::utils::to_u64;
@@ -22,6 +23,10 @@ pub(crate) struct CpusWrapper {
     got_cpu_frequency: bool,
     /// This field is needed to prevent updating when not enough time passed since last update.
     last_update: Option<Instant>,
+    /// Number of runnable threads read from `/proc/stat` during the last CPU usage update.
+    pub(crate) procs_running: Option<u32>,
+    /// Number of threads blocked on I/O read from `/proc/stat` during the last CPU usage update.
+    pub(crate) procs_blocked: Option<u32>,
 }
 
 impl CpusWrapper {
@@ -31,6 +36,8 @@ impl CpusWrapper {
             cpus: Vec::with_capacity(4),
             got_cpu_frequency: false,
             last_update: None,
+            procs_running: None,
+            procs_blocked: None,
         }
     }
 
@@ -91,6 +98,8 @@ impl CpusWrapper {
                     );
                 }
                 if first || !only_update_global_cpu {
+                    // Whether the CPU at the same position in `self.cpus` is listed in this sample.
+                    let mut listed = vec![false; self.cpus.len()];
                     while let Some(Ok(line)) = it.next() {
                         if &line[..3] != b"cpu" {
                             break;
@@ -121,23 +130,35 @@ impl CpusWrapper {
                                 ),
                             });
                         } else {
-                            parts.next(); // we don't want the name again
-                            self.cpus[i].inner.set(
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                                parts.next().map(to_u64).unwrap_or(0),
-                            );
+                            let name = to_str!(parts.next().unwrap_or(&[]));
+                            let values =
+                                std::array::from_fn(|_| parts.next().map(to_u64).unwrap_or(0));
+                            update_cpu_usage(&mut self.cpus, &mut listed, i, name, values);
                         }
 
                         i += 1;
                     }
+                    if !first {
+                        set_listed_cpus(&mut self.cpus, listed);
+                    }
+                }
+                self.procs_running = None;
+                self.procs_blocked = None;
+                for line in it.map_while(Result::ok) {
+                    let mut parts = line.split(|x| *x == b' ');
+                    let field = match parts.next() {
+                        Some(b"procs_running") => &mut self.procs_running,
+                        Some(b"procs_blocked") => &mut self.procs_blocked,
+                        _ => continue,
+                    };
+                    *field = parts
+                        .next()
+                        .and_then(|value| std::str::from_utf8(value).ok())
+                        .and_then(|value| value.parse().ok());
+                    // No need to read the (large) lines which come after.
+                    if self.procs_running.is_some() && self.procs_blocked.is_some() {
+                        break;
+                    }
                 }
             }
         }
@@ -167,12 +188,58 @@ impl CpusWrapper {
             // `get_cpu_frequency` is very slow, so better run it in parallel.
             iter_mut(&mut self.cpus)
                 .enumerate()
-                .for_each(|(pos, proc_)| proc_.inner.frequency = get_cpu_frequency(pos));
+                .for_each(|(pos, proc_)| {
+                    proc_.inner.frequency = get_cpu_frequency(pos);
+                    proc_.inner.governor = get_cpu_governor(pos);
+                });
 
             self.got_cpu_frequency = true;
         }
     }
 
+    /// Refreshes only the CPU at `index`. Returns `false` if there is no CPU at this index.
+    pub(crate) fn refresh_cpu(&mut self, index: usize, refresh_kind: CpuRefreshKind) -> bool {
+        if self.cpus.is_empty() {
+            // The list of CPUs hasn't been filled yet.
+            self.refresh(false, refresh_kind);
+            return index < self.cpus.len();
+        }
+        let Some(cpu) = self.cpus.get_mut(index) else {
+            return false;
+        };
+        if refresh_kind.cpu_usage() {
+            let f = match File::open("/proc/stat") {
+                Ok(f) => f,
+                Err(_e) => {
+                    sysinfo_debug!("failed to retrieve CPU information: {:?}", _e);
+                    return true;
+                }
+            };
+            // The first line is the global CPU. Offline CPUs are not listed, so we look for this
+            // CPU by its name instead of its position.
+            let name = cpu.inner.name.as_bytes();
+            let line = BufReader::new(f)
+                .split(b'\n')
+                .skip(1)
+                .map_while(Result::ok)
+                .take_while(|line| line.starts_with(b"cpu"))
+                .find(|line| line.split(|x| *x == b' ').next() == Some(name));
+            match line {
+                Some(line) => {
+                    let mut parts = line.split(|x| *x == b' ').filter(|s| !s.is_empty()).skip(1);
+                    let values = std::array::from_fn(|_| parts.next().map(to_u64).unwrap_or(0));
+                    cpu.inner.update_usage(values);
+                }
+                None => cpu.inner.set_unlisted(),
+            }
+        }
+        if refresh_kind.frequency() {
+            cpu.inner.frequency = get_cpu_frequency(index);
+            cpu.inner.governor = get_cpu_governor(index);
+        }
+        true
+    }
+
     pub(crate) fn get_global_raw_times(&self) -> (u64, u64) {
         (self.global_cpu.total_time, self.global_cpu.old_total_time)
     }
@@ -181,6 +248,10 @@ impl CpusWrapper {
         self.cpus.len()
     }
 
+    pub(crate) fn is_hybrid(&self) -> bool {
+        is_hybrid_from_sysfs(Path::new("/sys/devices"), self.cpus.len())
+    }
+
     pub(crate) fn is_empty(&self) -> bool {
         self.cpus.is_empty()
     }
@@ -255,6 +326,7 @@ impl CpuValues {
 #[derive(Default)]
 pub(crate) struct CpuUsage {
     percent: f32,
+    steal_percent: f32,
     old_values: CpuValues,
     new_values: CpuValues,
     total_time: u64,
@@ -282,6 +354,7 @@ impl CpuUsage {
             old_values: CpuValues::default(),
             new_values,
             percent: 0f32,
+            steal_percent: 0f32,
             total_time: 0,
             old_total_time: 0,
         }
@@ -321,6 +394,12 @@ impl CpuUsage {
         if self.percent > 100. {
             self.percent = 100.; // to prevent the percentage to go above 100%
         }
+        self.steal_percent = min!(self.new_values.steal, self.old_values.steal, 0.)
+            / min!(self.total_time, self.old_total_time, 1.)
+            * 100.;
+        if self.steal_percent > 100. {
+            self.steal_percent = 100.;
+        }
     }
 
     pub(crate) fn usage(&self) -> f32 {
@@ -330,8 +409,11 @@ impl CpuUsage {
 
 pub(crate) struct CpuInner {
     usage: CpuUsage,
+    /// Whether this CPU was listed in the last sample of `/proc/stat` (offline CPUs aren't).
+    listed: bool,
     pub(crate) name: String,
     pub(crate) frequency: u64,
+    pub(crate) governor: Option<String>,
     pub(crate) vendor_id: String,
     pub(crate) brand: String,
 }
@@ -357,8 +439,10 @@ impl CpuInner {
             usage: CpuUsage::new_with_values(
                 user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice,
             ),
+            listed: true,
             name: name.to_owned(),
             frequency,
+            governor: None,
             vendor_id,
             brand,
         }
@@ -382,10 +466,38 @@ impl CpuInner {
         );
     }
 
+    /// Updates the usage with the `values` read from `/proc/stat`. If the CPU wasn't listed in
+    /// the previous sample, they are only used as the new baseline.
+    fn update_usage(&mut self, values: [u64; 10]) {
+        let [user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice] = values;
+        if self.listed {
+            self.set(
+                user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice,
+            );
+        } else {
+            self.usage = CpuUsage::new_with_values(
+                user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice,
+            );
+        }
+        self.listed = true;
+    }
+
+    /// Marks the CPU as not listed in `/proc/stat`.
+    fn set_unlisted(&mut self) {
+        // An offline CPU doesn't run anything.
+        self.usage.percent = 0.;
+        self.usage.steal_percent = 0.;
+        self.listed = false;
+    }
+
     pub(crate) fn cpu_usage(&self) -> f32 {
         self.usage.percent
     }
 
+    pub(crate) fn steal_time_percent(&self) -> f32 {
+        self.usage.steal_percent
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -395,6 +507,10 @@ impl CpuInner {
         self.frequency
     }
 
+    pub(crate) fn frequency_governor(&self) -> Option<&str> {
+        self.governor.as_deref()
+    }
+
     pub(crate) fn vendor_id(&self) -> &str {
         &self.vendor_id
     }
@@ -404,6 +520,60 @@ impl CpuInner {
     }
 }
 
+/// Updates the usage of the CPU called `name` with the `values` read from `/proc/stat`, `pos`
+/// being the position of the CPU in this file.
+///
+/// Offline CPUs are not listed in `/proc/stat`, so a CPU that wasn't listed in the previous sample
+/// (because it was just brought online) has no baseline to compute its usage from. In this case,
+/// the values are only used as the new baseline and its usage is `0.0` until the next refresh.
+fn update_cpu_usage(
+    cpus: &mut Vec<Cpu>,
+    listed: &mut Vec<bool>,
+    pos: usize,
+    name: &str,
+    values: [u64; 10],
+) {
+    let [user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice] = values;
+    let index = if cpus.get(pos).is_some_and(|cpu| cpu.inner.name == name) {
+        Some(pos)
+    } else {
+        cpus.iter().position(|cpu| cpu.inner.name == name)
+    };
+    match index {
+        Some(index) => {
+            cpus[index].inner.update_usage(values);
+            listed[index] = true;
+        }
+        None => {
+            // It's a CPU we never saw, so we insert it at its position in `/proc/stat`.
+            let (vendor_id, brand) = cpus
+                .first()
+                .map(|cpu| (cpu.inner.vendor_id.clone(), cpu.inner.brand.clone()))
+                .unwrap_or_default();
+            let index = pos.min(cpus.len());
+            cpus.insert(
+                index,
+                Cpu {
+                    inner: CpuInner::new_with_values(
+                        name, user, nice, system, idle, iowait, irq, softirq, steal, guest,
+                        guest_nice, 0, vendor_id, brand,
+                    ),
+                },
+            );
+            listed.insert(index, true);
+        }
+    }
+}
+
+/// Stores which CPUs were listed in the last sample of `/proc/stat`.
+fn set_listed_cpus(cpus: &mut [Cpu], listed: Vec<bool>) {
+    for (cpu, listed) in cpus.iter_mut().zip(listed) {
+        if !listed {
+            cpu.inner.set_unlisted();
+        }
+    }
+}
+
 pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
     let mut s = String::new();
     if File::open(format!(
@@ -439,6 +609,47 @@ pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
         .unwrap_or_default()
 }
 
+fn get_cpu_governor(cpu_core_index: usize) -> Option<String> {
+    let governor = fs::read_to_string(format!(
+        "/sys/devices/system/cpu/cpu{cpu_core_index}/cpufreq/scaling_governor",
+    ))
+    .ok()?;
+    let governor = governor.trim();
+    if governor.is_empty() {
+        None
+    } else {
+        Some(governor.to_owned())
+    }
+}
+
+/// Returns `true` if the CPUs don't all have the same kind, like "performance" and "efficiency"
+/// cores.
+fn is_hybrid_from_sysfs(sysfs_devices: &Path, nb_cpus: usize) -> bool {
+    // Intel hybrid CPUs have a PMU for each kind of core.
+    let has_cpus = |pmu: &str| {
+        fs::read_to_string(sysfs_devices.join(pmu).join("cpus"))
+            .is_ok_and(|cpus| !cpus.trim().is_empty())
+    };
+    if has_cpus("cpu_core") && has_cpus("cpu_atom") {
+        return true;
+    }
+    // On ARM (big.LITTLE), the cores of different kinds have a different capacity.
+    let mut capacities = (0..nb_cpus).filter_map(|pos| {
+        fs::read_to_string(
+            sysfs_devices
+                .join("system/cpu")
+                .join(format!("cpu{pos}"))
+                .join("cpu_capacity"),
+        )
+        .ok()
+        .and_then(|capacity| capacity.trim().parse::<u64>().ok())
+    });
+    match capacities.next() {
+        Some(first) => capacities.any(|capacity| capacity != first),
+        None => false,
+    }
+}
+
 #[allow(unused_assignments)]
 pub(crate) fn get_physical_core_count() -> Option<usize> {
     let mut s = String::new();
@@ -835,3 +1046,126 @@ pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
     }
     cpus
 }
+
+#[cfg(test)]
+mod test {
+    use super::{is_hybrid_from_sysfs, set_listed_cpus, update_cpu_usage, CpuInner};
+    use crate::Cpu;
+    use std::fs;
+    use std::path::Path;
+
+    fn create_cpu(sysfs_devices: &Path, pos: usize, capacity: u64) {
+        let cpu_dir = sysfs_devices.join(format!("system/cpu/cpu{pos}"));
+        fs::create_dir_all(&cpu_dir).expect("failed to create subdirectory");
+        fs::write(cpu_dir.join("cpu_capacity"), format!("{capacity}\n"))
+            .expect("failed to write file");
+    }
+
+    #[test]
+    fn check_is_hybrid_from_cpu_capacity() {
+        let sysfs_devices = tempfile::tempdir().expect("failed to create temporary directory");
+        let sysfs_devices = sysfs_devices.path();
+
+        assert!(!is_hybrid_from_sysfs(sysfs_devices, 0));
+        create_cpu(sysfs_devices, 0, 1024);
+        create_cpu(sysfs_devices, 1, 1024);
+        assert!(!is_hybrid_from_sysfs(sysfs_devices, 2));
+        create_cpu(sysfs_devices, 2, 446);
+        assert!(!is_hybrid_from_sysfs(sysfs_devices, 2));
+        assert!(is_hybrid_from_sysfs(sysfs_devices, 3));
+    }
+
+    #[test]
+    fn check_is_hybrid_from_intel_pmu() {
+        let sysfs_devices = tempfile::tempdir().expect("failed to create temporary directory");
+        let sysfs_devices = sysfs_devices.path();
+
+        fs::create_dir(sysfs_devices.join("cpu_core")).expect("failed to create subdirectory");
+        fs::write(sysfs_devices.join("cpu_core/cpus"), "0-7\n").expect("failed to write file");
+        assert!(!is_hybrid_from_sysfs(sysfs_devices, 0));
+        fs::create_dir(sysfs_devices.join("cpu_atom")).expect("failed to create subdirectory");
+        fs::write(sysfs_devices.join("cpu_atom/cpus"), "\n").expect("failed to write file");
+        assert!(!is_hybrid_from_sysfs(sysfs_devices, 0));
+        fs::write(sysfs_devices.join("cpu_atom/cpus"), "8-15\n").expect("failed to write file");
+        assert!(is_hybrid_from_sysfs(sysfs_devices, 0));
+    }
+
+    #[test]
+    fn check_update_cpu_usage_with_new_cpu() {
+        fn sample(cpus: &mut Vec<Cpu>, sample: &[(&str, u64, u64)]) {
+            let mut listed = vec![false; cpus.len()];
+            for (pos, (name, user, idle)) in sample.iter().enumerate() {
+                update_cpu_usage(
+                    cpus,
+                    &mut listed,
+                    pos,
+                    name,
+                    [*user, 0, 0, *idle, 0, 0, 0, 0, 0, 0],
+                );
+            }
+            set_listed_cpus(cpus, listed);
+        }
+        fn names_and_usages(cpus: &[Cpu]) -> Vec<(&str, f32)> {
+            cpus.iter()
+                .map(|cpu| (cpu.inner.name(), cpu.inner.cpu_usage()))
+                .collect()
+        }
+
+        let mut cpus = ["cpu0", "cpu2"]
+            .into_iter()
+            .map(|name| Cpu {
+                inner: CpuInner::new_with_values(
+                    name,
+                    100,
+                    0,
+                    0,
+                    100,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    String::new(),
+                    String::new(),
+                ),
+            })
+            .collect::<Vec<_>>();
+
+        // `cpu1` was brought online: it has no baseline yet.
+        sample(
+            &mut cpus,
+            &[("cpu0", 150, 150), ("cpu1", 1000, 0), ("cpu2", 200, 100)],
+        );
+        assert_eq!(
+            names_and_usages(&cpus),
+            [("cpu0", 50.), ("cpu1", 0.), ("cpu2", 100.)]
+        );
+
+        // `cpu2` goes offline.
+        sample(&mut cpus, &[("cpu0", 200, 200), ("cpu1", 1050, 50)]);
+        assert_eq!(
+            names_and_usages(&cpus),
+            [("cpu0", 50.), ("cpu1", 50.), ("cpu2", 0.)]
+        );
+
+        // `cpu2` is back online: its previous values can't be used as baseline.
+        sample(
+            &mut cpus,
+            &[("cpu0", 250, 250), ("cpu1", 1100, 100), ("cpu2", 5000, 0)],
+        );
+        assert_eq!(
+            names_and_usages(&cpus),
+            [("cpu0", 50.), ("cpu1", 50.), ("cpu2", 0.)]
+        );
+        sample(
+            &mut cpus,
+            &[("cpu0", 300, 300), ("cpu1", 1150, 150), ("cpu2", 5100, 0)],
+        );
+        assert_eq!(
+            names_and_usages(&cpus),
+            [("cpu0", 50.), ("cpu1", 50.), ("cpu2", 100.)]
+        );
+    }
+}
@@ -1,16 +1,23 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::disk::sum_disks_usage;
 use crate::sys::utils::{get_all_utf8_data, to_cpath};
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{
+    CompressionInfo, Disk, DiskKind, DiskRefreshKind, DiskUsage, MountEntry, MountKind,
+    MountPropagation, OverlayInfo,
+};
 
 use libc::statvfs;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fs;
+use std::io::{Read, Seek, SeekFrom};
 use std::mem::MaybeUninit;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{mpsc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Copied from [`psutil`]:
 ///
@@ -28,6 +35,15 @@ use std::str::FromStr;
 /// [`psutil`]: <https://github.com/giampaolo/psutil/blob/master/psutil/_pslinux.py#L103>
 const SECTOR_SIZE: u64 = 512;
 
+/// How long we wait for `statvfs` to return on a network mount point before considering its
+/// space unavailable.
+const NETWORK_STATVFS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Offset of the superblock of ext2/3/4 file systems on their device.
+const EXT_SUPERBLOCK_OFFSET: u64 = 1024;
+const EXT_SUPERBLOCK_SIZE: usize = 1024;
+const EXT_SUPER_MAGIC: u16 = 0xEF53;
+
 macro_rules! cast {
     ($x:expr) => {
         u64::from($x)
@@ -38,16 +54,29 @@ pub(crate) struct DiskInner {
     type_: DiskKind,
     device_name: OsString,
     actual_device_name: Option<String>,
+    /// Major and minor numbers of the device, as listed in `/proc/self/mountinfo`.
+    device_id: Option<(u32, u32)>,
     file_system: OsString,
     mount_point: PathBuf,
     total_space: u64,
     available_space: u64,
+    /// Value of `available_space` before the last refresh, `None` until it was retrieved.
+    old_available_space: Option<u64>,
+    free_space: u64,
     is_removable: bool,
     is_read_only: bool,
     old_written_bytes: u64,
     old_read_bytes: u64,
     written_bytes: u64,
     read_bytes: u64,
+    space_unavailable: bool,
+    is_network: bool,
+    mount_kind: MountKind,
+    mount_id: u32,
+    parent_mount_id: u32,
+    propagation: MountPropagation,
+    /// Compression settings read from the mount options, only set for btrfs.
+    compression: Option<CompressionInfo>,
     updated: bool,
 }
 
@@ -76,6 +105,24 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn available_space_delta(&self) -> i64 {
+        self.old_available_space
+            .map_or(0, |old| self.available_space as i64 - old as i64)
+    }
+
+    fn set_available_space(&mut self, available_space: u64) {
+        // The first time, there is no previous value so the delta is `0`.
+        self.old_available_space = Some(
+            self.old_available_space
+                .map_or(available_space, |_| self.available_space),
+        );
+        self.available_space = available_space;
+    }
+
+    pub(crate) fn free_space(&self) -> u64 {
+        self.free_space
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -84,19 +131,66 @@ impl DiskInner {
         self.is_read_only
     }
 
+    pub(crate) fn space_unavailable(&self) -> bool {
+        self.space_unavailable
+    }
+
+    pub(crate) fn is_network(&self) -> bool {
+        self.is_network
+    }
+
+    pub(crate) fn mount_kind(&self) -> MountKind {
+        self.mount_kind
+    }
+
+    pub(crate) fn mount_id(&self) -> u32 {
+        self.mount_id
+    }
+
+    pub(crate) fn parent_mount_id(&self) -> u32 {
+        self.parent_mount_id
+    }
+
+    pub(crate) fn propagation(&self) -> MountPropagation {
+        self.propagation
+    }
+
+    pub(crate) fn mounted_since(&self) -> Option<SystemTime> {
+        if !matches!(self.file_system.as_bytes(), b"ext2" | b"ext3" | b"ext4") {
+            return None;
+        }
+        let mut file = fs::File::open(&self.device_name).ok()?;
+        let mut superblock = [0; EXT_SUPERBLOCK_SIZE];
+        file.seek(SeekFrom::Start(EXT_SUPERBLOCK_OFFSET)).ok()?;
+        file.read_exact(&mut superblock).ok()?;
+        let secs = parse_ext_mount_time(&superblock)?;
+        Some(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    pub(crate) fn compression(&self) -> Option<CompressionInfo> {
+        self.compression.clone()
+    }
+
     pub(crate) fn refresh_specifics(&mut self, refresh_kind: DiskRefreshKind) -> bool {
-        self.efficient_refresh(refresh_kind, &disk_stats(&refresh_kind), false)
+        self.efficient_refresh(refresh_kind, &disk_stats(&refresh_kind), None, false)
     }
 
     fn efficient_refresh(
         &mut self,
         refresh_kind: DiskRefreshKind,
         procfs_disk_stats: &HashMap<String, DiskStat>,
+        statvfs_timeout: Option<Duration>,
         first: bool,
     ) -> bool {
         if refresh_kind.io_usage() {
             if self.actual_device_name.is_none() {
-                self.actual_device_name = Some(get_actual_device_name(&self.device_name));
+                self.actual_device_name = Some(
+                    self.device_id
+                        .and_then(|device_id| {
+                            get_device_name_from_id(Path::new("/sys/dev/block"), device_id)
+                        })
+                        .unwrap_or_else(|| get_actual_device_name(&self.device_name)),
+                );
             }
             if let Some(stat) = self
                 .actual_device_name
@@ -117,11 +211,39 @@ impl DiskInner {
         }
 
         if refresh_kind.storage() {
-            if let Some((total_space, available_space, is_read_only)) =
-                unsafe { load_statvfs_values(&self.mount_point) }
-            {
+            // `statvfs` may never return on a network mount point if its server is unreachable.
+            let statvfs_timeout = if self.is_network {
+                Some(statvfs_timeout.map_or(NETWORK_STATVFS_TIMEOUT, |timeout| {
+                    timeout.min(NETWORK_STATVFS_TIMEOUT)
+                }))
+            } else {
+                statvfs_timeout
+            };
+            let values = match statvfs_timeout {
+                Some(timeout) => {
+                    match load_statvfs_values_with_timeout(&self.mount_point, timeout) {
+                        Ok(values) => values,
+                        Err(()) => {
+                            sysinfo_debug!(
+                                "statvfs on {:?} didn't return in time",
+                                self.mount_point
+                            );
+                            self.space_unavailable = true;
+                            self.total_space = 0;
+                            self.available_space = 0;
+                            self.old_available_space = None;
+                            self.free_space = 0;
+                            return true;
+                        }
+                    }
+                }
+                None => unsafe { load_statvfs_values(&self.mount_point) },
+            };
+            self.space_unavailable = false;
+            if let Some((total_space, available_space, free_space, is_read_only)) = values {
                 self.total_space = total_space;
-                self.available_space = available_space;
+                self.set_available_space(available_space);
+                self.free_space = free_space;
                 if first {
                     self.is_read_only = is_read_only;
                 }
@@ -152,11 +274,25 @@ impl crate::DisksInner {
         &mut self,
         remove_not_listed_disks: bool,
         refresh_kind: DiskRefreshKind,
+    ) {
+        self.refresh_inner(remove_not_listed_disks, refresh_kind, None);
+    }
+
+    pub(crate) fn refresh_list_non_blocking(&mut self, timeout: Duration) {
+        self.refresh_inner(true, DiskRefreshKind::everything(), Some(timeout));
+    }
+
+    fn refresh_inner(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+        statvfs_timeout: Option<Duration>,
     ) {
         get_all_list(
             &mut self.disks,
-            &get_all_utf8_data("/proc/mounts", 16_385).unwrap_or_default(),
+            &get_all_utf8_data("/proc/self/mountinfo", 16_385).unwrap_or_default(),
             refresh_kind,
+            statvfs_timeout,
         );
 
         if remove_not_listed_disks {
@@ -174,6 +310,50 @@ impl crate::DisksInner {
         }
     }
 
+    pub(crate) fn list_mounts() -> Vec<MountEntry> {
+        get_all_utf8_data("/proc/self/mountinfo", 16_385)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(MountInfo::parse)
+            .map(|mount_info| MountEntry {
+                options: mount_info.options(),
+                device: mount_info.source.into(),
+                mount_point: mount_info.mount_point.into(),
+                fs_type: mount_info.fs_type.into(),
+            })
+            .collect()
+    }
+
+    pub(crate) fn overlay_layers(mount_point: &Path) -> Option<OverlayInfo> {
+        get_all_utf8_data("/proc/self/mountinfo", 16_385)
+            .ok()?
+            .lines()
+            .filter_map(MountInfo::parse)
+            // If more than one file system is mounted on the same mount point, the last one is
+            // the visible one.
+            .rfind(|mount_info| Path::new(&mount_info.mount_point) == mount_point)
+            .filter(|mount_info| mount_info.fs_type == "overlay")
+            .map(|mount_info| parse_overlay_options(&mount_info.super_options))
+    }
+
+    pub(crate) fn total_io(&self) -> DiskUsage {
+        let devices = self
+            .disks
+            .iter()
+            .filter_map(|disk| disk.inner.actual_device_name.as_deref())
+            .collect::<HashSet<_>>();
+        sum_disks_usage(self.disks.iter().filter_map(|disk| {
+            let device = disk.inner.actual_device_name.as_deref()?;
+            // The I/O of a partition is already included in the one of its parent device.
+            if get_parent_device_name(Path::new("/sys/class/block"), device)
+                .is_some_and(|parent| devices.contains(parent.as_str()))
+            {
+                return None;
+            }
+            Some((device, disk.usage()))
+        }))
+    }
+
     pub(crate) fn list(&self) -> &[Disk] {
         &self.disks
     }
@@ -183,7 +363,18 @@ impl crate::DisksInner {
     }
 }
 
-/// Resolves the actual device name for a specified `device` from `/proc/mounts`
+/// Returns the name of the block device whose major and minor numbers are `device_id`, or `None`
+/// if there is no such block device (like for `tmpfs` or `overlay`).
+fn get_device_name_from_id(sys_dev_block: &Path, (major, minor): (u32, u32)) -> Option<String> {
+    // `/sys/dev/block/<major>:<minor>` links to `/sys/devices/.../<device>`.
+    fs::read_link(sys_dev_block.join(format!("{major}:{minor}")))
+        .ok()?
+        .file_name()?
+        .to_str()
+        .map(str::to_owned)
+}
+
+/// Resolves the actual device name for a specified `device` from `/proc/self/mountinfo`
 ///
 /// This function is inspired by the [`bottom`] crate implementation and essentially does the following:
 ///     1. Canonicalizes the specified device path to its absolute form
@@ -202,7 +393,22 @@ fn get_actual_device_name(device: &OsStr) -> String {
         .unwrap_or_default()
 }
 
-unsafe fn load_statvfs_values(mount_point: &Path) -> Option<(u64, u64, bool)> {
+/// Returns the name of the device `device` is a partition of, or `None` if it isn't a partition.
+fn get_parent_device_name(sys_class_block: &Path, device: &str) -> Option<String> {
+    let device_path = sys_class_block.join(device);
+    if !device_path.join("partition").exists() {
+        return None;
+    }
+    // `/sys/class/block/<partition>` links to `/sys/devices/.../<device>/<partition>`.
+    fs::canonicalize(device_path)
+        .ok()?
+        .parent()?
+        .file_name()?
+        .to_str()
+        .map(str::to_owned)
+}
+
+unsafe fn load_statvfs_values(mount_point: &Path) -> Option<(u64, u64, u64, bool)> {
     let mount_point_cpath = to_cpath(mount_point);
     let mut stat: MaybeUninit<statvfs> = MaybeUninit::uninit();
     if retry_eintr!(statvfs(
@@ -215,27 +421,77 @@ unsafe fn load_statvfs_values(mount_point: &Path) -> Option<(u64, u64, bool)> {
         let bsize = cast!(stat.f_bsize);
         let blocks = cast!(stat.f_blocks);
         let bavail = cast!(stat.f_bavail);
+        let bfree = cast!(stat.f_bfree);
         let total = bsize.saturating_mul(blocks);
         if total == 0 {
             return None;
         }
         let available = bsize.saturating_mul(bavail);
+        let free = bsize.saturating_mul(bfree);
         let is_read_only = (stat.f_flag & libc::ST_RDONLY) != 0;
 
-        Some((total, available, is_read_only))
+        Some((total, available, free, is_read_only))
     } else {
         None
     }
 }
 
-fn new_disk(
-    device_name: &OsStr,
+/// Same as `load_statvfs_values` but calls `statvfs` from another thread so the caller isn't
+/// blocked if the mount point doesn't respond (like a NFS share mounted with the `hard` option
+/// whose server is down).
+///
+/// Returns `Err(())` if `statvfs` didn't return before `timeout`. In this case, the thread is
+/// left running in the background until `statvfs` returns and no other thread is spawned for this
+/// mount point until then (`Err(())` is returned right away instead).
+fn load_statvfs_values_with_timeout(
     mount_point: &Path,
-    file_system: &OsStr,
+    timeout: Duration,
+) -> Result<Option<(u64, u64, u64, bool)>, ()> {
+    type Receiver = mpsc::Receiver<Option<(u64, u64, u64, bool)>>;
+    // The threads which didn't return in time, by mount point.
+    static PENDING: OnceLock<Mutex<HashMap<PathBuf, Receiver>>> = OnceLock::new();
+
+    let pending = PENDING.get_or_init(Default::default);
+    let lock = || pending.lock().unwrap_or_else(|e| e.into_inner());
+    {
+        let mut pending = lock();
+        if let Some(receiver) = pending.remove(mount_point) {
+            if let Err(mpsc::TryRecvError::Empty) = receiver.try_recv() {
+                // The previous `statvfs` call is still blocked.
+                pending.insert(mount_point.to_owned(), receiver);
+                return Err(());
+            }
+        }
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let path = mount_point.to_owned();
+    std::thread::Builder::new()
+        .name("sysinfo-statvfs".to_owned())
+        .spawn(move || {
+            let _ = sender.send(unsafe { load_statvfs_values(&path) });
+        })
+        .map_err(|_e| {
+            sysinfo_debug!("failed to spawn statvfs thread: {_e:?}");
+        })?;
+    match receiver.recv_timeout(timeout) {
+        Ok(values) => Ok(values),
+        Err(_) => {
+            lock().insert(mount_point.to_owned(), receiver);
+            Err(())
+        }
+    }
+}
+
+fn new_disk(
+    mount_info: &MountInfo,
     removable_entries: &[PathBuf],
     procfs_disk_stats: &HashMap<String, DiskStat>,
     refresh_kind: DiskRefreshKind,
+    statvfs_timeout: Option<Duration>,
 ) -> Disk {
+    let device_name = OsStr::new(&mount_info.source);
+    let file_system = OsStr::new(&mount_info.fs_type);
     let is_removable = removable_entries
         .iter()
         .any(|e| e.as_os_str() == device_name);
@@ -245,24 +501,248 @@ fn new_disk(
             type_: DiskKind::Unknown(-1),
             device_name: device_name.to_owned(),
             actual_device_name: None,
+            device_id: mount_info.device_id,
             file_system: file_system.to_owned(),
-            mount_point: mount_point.to_owned(),
+            mount_point: PathBuf::from(&mount_info.mount_point),
             total_space: 0,
             available_space: 0,
+            old_available_space: None,
+            free_space: 0,
             is_removable,
             is_read_only: false,
             old_read_bytes: 0,
             old_written_bytes: 0,
             read_bytes: 0,
             written_bytes: 0,
+            space_unavailable: false,
+            is_network: is_network_file_system(file_system),
+            mount_kind: mount_info.mount_kind(),
+            mount_id: mount_info.mount_id,
+            parent_mount_id: mount_info.parent_id,
+            propagation: mount_info.propagation,
+            compression: mount_info.btrfs_compression(),
             updated: true,
         },
     };
     disk.inner
-        .efficient_refresh(refresh_kind, procfs_disk_stats, true);
+        .efficient_refresh(refresh_kind, procfs_disk_stats, statvfs_timeout, true);
     disk
 }
 
+fn is_network_file_system(file_system: &OsStr) -> bool {
+    matches!(file_system.as_bytes(), b"cifs" | b"smb3" | b"nfs" | b"nfs4")
+}
+
+/// Information about a mount point, coming from a line of `/proc/self/mountinfo`.
+///
+/// The format of a line is as follows (more information in `man 5 proc_pid_mountinfo`):
+///
+/// ```text
+/// 36 35 98:0 /mnt1 /mnt2 rw,noatime master:1 - ext3 /dev/root rw,errors=continue
+/// (1)(2)(3)   (4)   (5)      (6)      (7)   (8) (9)   (10)         (11)
+/// ```
+///
+/// (1) mount ID, (2) parent ID, (3) major:minor of the device, (4) root of the mount within the
+/// file system, (5) mount point, (6) mount options, (7) optional fields, (8) separator,
+/// (9) file system type, (10) mount source, (11) super block options.
+#[derive(Debug, PartialEq)]
+struct MountInfo {
+    mount_id: u32,
+    parent_id: u32,
+    device_id: Option<(u32, u32)>,
+    root: String,
+    mount_point: String,
+    mount_options: String,
+    propagation: MountPropagation,
+    fs_type: String,
+    source: String,
+    super_options: String,
+}
+
+impl MountInfo {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split_whitespace();
+        let mount_id = u32::from_str(fields.next()?).ok()?;
+        let parent_id = u32::from_str(fields.next()?).ok()?;
+        let device_id = fields.next()?.split_once(':').and_then(|(major, minor)| {
+            Some((u32::from_str(major).ok()?, u32::from_str(minor).ok()?))
+        });
+        let root = unescape_mount_path(fields.next()?);
+        let mount_point = unescape_mount_path(fields.next()?);
+        let mount_options = fields.next()?.to_owned();
+        // The optional fields go until the separator.
+        let (mut shared, mut slave, mut unbindable) = (false, false, false);
+        for field in fields.by_ref().take_while(|field| *field != "-") {
+            if field.starts_with("shared:") {
+                shared = true;
+            } else if field.starts_with("master:") {
+                slave = true;
+            } else if field == "unbindable" {
+                unbindable = true;
+            }
+        }
+        let propagation = match (shared, slave) {
+            (true, true) => MountPropagation::SharedAndSlave,
+            (true, false) => MountPropagation::Shared,
+            (false, true) => MountPropagation::Slave,
+            (false, false) if unbindable => MountPropagation::Unbindable,
+            (false, false) => MountPropagation::Private,
+        };
+        Some(Self {
+            mount_id,
+            parent_id,
+            device_id,
+            root,
+            mount_point,
+            mount_options,
+            propagation,
+            fs_type: fields.next()?.to_owned(),
+            source: unescape_mount_path(fields.next()?),
+            super_options: fields.next().unwrap_or("").to_owned(),
+        })
+    }
+
+    /// Returns the mount options followed by the super block options, like in `/proc/mounts`.
+    fn options(&self) -> String {
+        let mut options = self.mount_options.clone();
+        // `rw`/`ro` is already part of the mount options.
+        for option in self
+            .super_options
+            .split(',')
+            .filter(|option| !option.is_empty() && *option != "rw" && *option != "ro")
+        {
+            options.push(',');
+            options.push_str(option);
+        }
+        options
+    }
+
+    /// Returns the compression settings of a btrfs file system, `None` for other file systems.
+    fn btrfs_compression(&self) -> Option<CompressionInfo> {
+        if self.fs_type != "btrfs" {
+            return None;
+        }
+        Some(parse_btrfs_compression(&self.super_options))
+    }
+
+    fn mount_kind(&self) -> MountKind {
+        if is_network_file_system(OsStr::new(&self.fs_type)) {
+            return MountKind::Network;
+        }
+        // If the root of the mount isn't the root of the file system, it's a bind mount. The
+        // exception being btrfs subvolumes which also have a different root.
+        if self.root != "/"
+            && !(self.fs_type == "btrfs"
+                && self
+                    .super_options
+                    .split(',')
+                    .any(|option| option.strip_prefix("subvol=") == Some(self.root.as_str())))
+        {
+            return MountKind::Bind;
+        }
+        match self.fs_type.as_str() {
+            "overlay" => MountKind::Overlay,
+            "tmpfs" | "ramfs" => MountKind::Tmpfs,
+            "autofs" | "binfmt_misc" | "bpf" | "cgroup" | "cgroup2" | "configfs" | "debugfs"
+            | "devpts" | "devtmpfs" | "efivarfs" | "fusectl" | "hugetlbfs" | "mqueue" | "nsfs"
+            | "proc" | "pstore" | "rpc_pipefs" | "securityfs" | "sysfs" | "tracefs" => {
+                MountKind::Pseudo
+            }
+            _ => MountKind::Block,
+        }
+    }
+}
+
+/// Parses the `compress` and `compress-force` options of a btrfs mount, like `compress=zstd:3`.
+///
+/// If both are set, the last one wins, like in the kernel.
+fn parse_btrfs_compression(super_options: &str) -> CompressionInfo {
+    let mut compression = CompressionInfo::default();
+    for option in super_options.split(',') {
+        let (key, value) = option.split_once('=').unwrap_or((option, ""));
+        if key != "compress" && key != "compress-force" {
+            continue;
+        }
+        // The compression level is appended to the algorithm, like `zstd:3`.
+        let algorithm = value.split(':').next().unwrap_or_default();
+        compression = match algorithm {
+            "no" => CompressionInfo::default(),
+            // Without a value, the default algorithm is used.
+            "" => CompressionInfo {
+                is_enabled: true,
+                algorithm: Some("zlib".to_owned()),
+                ratio: None,
+            },
+            algorithm => CompressionInfo {
+                is_enabled: true,
+                algorithm: Some(algorithm.to_owned()),
+                ratio: None,
+            },
+        };
+    }
+    compression
+}
+
+/// Parses the `lowerdir`, `upperdir` and `workdir` options of an overlay mount.
+///
+/// Newer kernels can list each lower layer in its own `lowerdir+` option instead of using a
+/// colon-separated `lowerdir` option.
+fn parse_overlay_options(super_options: &str) -> OverlayInfo {
+    let mut overlay = OverlayInfo::default();
+    for option in super_options.split(',') {
+        let Some((key, value)) = option.split_once('=') else {
+            continue;
+        };
+        match key {
+            "lowerdir" => overlay.lower_dirs.extend(
+                split_overlay_lower_dirs(value)
+                    .into_iter()
+                    .map(|dir| PathBuf::from(unescape_mount_path(&dir))),
+            ),
+            "lowerdir+" => overlay
+                .lower_dirs
+                .push(PathBuf::from(unescape_mount_path(value))),
+            "upperdir" => overlay.upper_dir = Some(PathBuf::from(unescape_mount_path(value))),
+            "workdir" => overlay.work_dir = Some(PathBuf::from(unescape_mount_path(value))),
+            _ => {}
+        }
+    }
+    overlay
+}
+
+/// Splits the `lowerdir` option of an overlay mount on `:`, which is escaped as `\:` in paths.
+fn split_overlay_lower_dirs(value: &str) -> Vec<String> {
+    let mut dirs = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(':') => current.push(':'),
+                Some(next) => {
+                    current.push('\\');
+                    current.push(next);
+                }
+                None => current.push('\\'),
+            },
+            ':' => dirs.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        dirs.push(current);
+    }
+    dirs
+}
+
+/// Replaces the octal escape sequences used in `/proc/self/mountinfo` paths.
+fn unescape_mount_path(path: &str) -> String {
+    path.replace("\\134", "\\")
+        .replace("\\040", " ")
+        .replace("\\011", "\t")
+        .replace("\\012", "\n")
+}
+
 #[allow(clippy::manual_range_contains)]
 fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
     // The format of devices are as follows:
@@ -334,7 +814,12 @@ fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
     }
 }
 
-fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefreshKind) {
+fn get_all_list(
+    container: &mut Vec<Disk>,
+    content: &str,
+    refresh_kind: DiskRefreshKind,
+    statvfs_timeout: Option<Duration>,
+) {
     // The goal of this array is to list all removable devices (the ones whose name starts with
     // "usb-").
     let removable_entries = match fs::read_dir("/dev/disk/by-id/") {
@@ -356,28 +841,13 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
 
     let procfs_disk_stats = disk_stats(&refresh_kind);
 
-    for (fs_spec, fs_file, fs_vfstype) in content
+    for mount_info in content
         .lines()
-        .map(|line| {
-            let line = line.trim_start();
-            // mounts format
-            // http://man7.org/linux/man-pages/man5/fstab.5.html
-            // fs_spec<tab>fs_file<tab>fs_vfstype<tab>other fields
-            let mut fields = line.split_whitespace();
-            let fs_spec = fields.next().unwrap_or("");
-            let fs_file = fields
-                .next()
-                .unwrap_or("")
-                .replace("\\134", "\\")
-                .replace("\\040", " ")
-                .replace("\\011", "\t")
-                .replace("\\012", "\n");
-            let fs_vfstype = fields.next().unwrap_or("");
-            (fs_spec, fs_file, fs_vfstype)
-        })
-        .filter(|(fs_spec, fs_file, fs_vfstype)| {
-            // Check if fs_vfstype is one of our 'ignored' file systems.
-            let filtered = match *fs_vfstype {
+        .filter_map(MountInfo::parse)
+        .filter(|mount_info| {
+            let fs_file = mount_info.mount_point.as_str();
+            // Check if the file system type is one of our 'ignored' file systems.
+            let filtered = match mount_info.fs_type.as_str() {
                 "rootfs" | // https://www.kernel.org/doc/Documentation/filesystems/ramfs-rootfs-initramfs.txt
                 "sysfs" | // pseudo file system for kernel objects
                 "proc" |  // another pseudo file system
@@ -393,7 +863,8 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
                 "mqueue" // https://man7.org/linux/man-pages/man7/mq_overview.7.html
                 => true,
                 "tmpfs" => !cfg!(feature = "linux-tmpfs"),
-                // calling statvfs on a mounted CIFS or NFS may hang, when they are mounted with option: hard
+                // calling statvfs on a mounted CIFS or NFS may hang, when they are mounted with
+                // option: hard. When they're listed, `statvfs` is called with a timeout.
                 "cifs" | "nfs" | "nfs4" => !cfg!(feature = "linux-netdevs"),
                 _ => false,
             };
@@ -402,27 +873,30 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
                fs_file.starts_with("/sys") || // check if fs_file is an 'ignored' mount point
                fs_file.starts_with("/proc") ||
                (fs_file.starts_with("/run") && !fs_file.starts_with("/run/media")) ||
-               fs_spec.starts_with("sunrpc"))
+               mount_info.source.starts_with("sunrpc"))
         })
     {
-        let mount_point = Path::new(&fs_file);
+        let mount_point = Path::new(&mount_info.mount_point);
         if let Some(disk) = container.iter_mut().find(|d| {
             d.inner.mount_point == mount_point
-                && d.inner.device_name == fs_spec
-                && d.inner.file_system == fs_vfstype
+                && d.inner.device_name == mount_info.source.as_str()
+                && d.inner.file_system == mount_info.fs_type.as_str()
         }) {
             disk.inner
-                .efficient_refresh(refresh_kind, &procfs_disk_stats, false);
+                .efficient_refresh(refresh_kind, &procfs_disk_stats, statvfs_timeout, false);
+            // The propagation type can be changed without remounting.
+            disk.inner.propagation = mount_info.propagation;
+            // The compression can be changed when remounting.
+            disk.inner.compression = mount_info.btrfs_compression();
             disk.inner.updated = true;
             continue;
         }
         container.push(new_disk(
-            fs_spec.as_ref(),
-            mount_point,
-            fs_vfstype.as_ref(),
+            &mount_info,
             &removable_entries,
             &procfs_disk_stats,
             refresh_kind,
+            statvfs_timeout,
         ));
     }
 }
@@ -510,10 +984,33 @@ fn disk_stats_inner(content: &str) -> HashMap<String, DiskStat> {
     data
 }
 
+/// Returns the last mount time (`s_mtime`) in seconds since the epoch from an ext2/3/4
+/// superblock, or `None` if it isn't one or if it was never mounted.
+fn parse_ext_mount_time(superblock: &[u8]) -> Option<u64> {
+    let magic = u16::from_le_bytes(superblock.get(0x38..0x3A)?.try_into().ok()?);
+    if magic != EXT_SUPER_MAGIC {
+        return None;
+    }
+    let low = u32::from_le_bytes(superblock.get(0x2C..0x30)?.try_into().ok()?);
+    // `s_mtime_hi` holds the upper 8 bits of the 40-bit timestamp (it is 0 on ext2/3).
+    let high = superblock.get(0x275).copied().unwrap_or(0);
+    match u64::from(low) | u64::from(high) << 32 {
+        0 => None,
+        secs => Some(secs),
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{disk_stats_inner, DiskStat};
+    use super::{
+        disk_stats_inner, get_device_name_from_id, get_parent_device_name, is_network_file_system,
+        parse_btrfs_compression, parse_ext_mount_time, parse_overlay_options, DiskStat, MountInfo,
+        EXT_SUPERBLOCK_SIZE,
+    };
+    use crate::{CompressionInfo, MountKind, MountPropagation, OverlayInfo};
     use std::collections::HashMap;
+    use std::ffi::OsStr;
+    use std::fs;
 
     #[test]
     fn test_disk_stat_parsing() {
@@ -612,4 +1109,208 @@ mod test {
 
         assert_eq!(data, expected_data);
     }
+
+    #[test]
+    fn test_get_parent_device_name() {
+        let sys_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let class_block = sys_dir.path().join("class/block");
+        let sda = sys_dir.path().join("devices/sda");
+        fs::create_dir_all(&class_block).expect("failed to create subdirectory");
+        fs::create_dir_all(sda.join("sda1")).expect("failed to create subdirectory");
+        fs::write(sda.join("sda1/partition"), "1\n").expect("failed to write file");
+        std::os::unix::fs::symlink("../../devices/sda", class_block.join("sda"))
+            .expect("failed to create symlink");
+        std::os::unix::fs::symlink("../../devices/sda/sda1", class_block.join("sda1"))
+            .expect("failed to create symlink");
+
+        assert_eq!(
+            get_parent_device_name(&class_block, "sda1").as_deref(),
+            Some("sda")
+        );
+        assert_eq!(get_parent_device_name(&class_block, "sda"), None);
+        assert_eq!(get_parent_device_name(&class_block, "sdb"), None);
+    }
+
+    #[test]
+    fn test_is_network_file_system() {
+        for file_system in ["cifs", "smb3", "nfs", "nfs4"] {
+            assert!(is_network_file_system(OsStr::new(file_system)));
+        }
+        for file_system in ["ext4", "btrfs", "tmpfs", "nfsd"] {
+            assert!(!is_network_file_system(OsStr::new(file_system)));
+        }
+    }
+
+    #[test]
+    fn test_mount_info_parse() {
+        let info = MountInfo::parse(
+            "36 35 98:0 /mnt1 /mnt\\0402 rw,noatime master:1 shared:2 - ext3 /dev/root rw,errors=continue",
+        )
+        .unwrap();
+        assert_eq!(
+            info,
+            MountInfo {
+                mount_id: 36,
+                parent_id: 35,
+                device_id: Some((98, 0)),
+                root: "/mnt1".to_owned(),
+                mount_point: "/mnt 2".to_owned(),
+                mount_options: "rw,noatime".to_owned(),
+                propagation: MountPropagation::SharedAndSlave,
+                fs_type: "ext3".to_owned(),
+                source: "/dev/root".to_owned(),
+                super_options: "rw,errors=continue".to_owned(),
+            }
+        );
+        assert_eq!(info.options(), "rw,noatime,errors=continue");
+        assert_eq!(MountInfo::parse("36 35 98:0 / /mnt rw"), None);
+    }
+
+    #[test]
+    fn test_mount_info_propagation() {
+        let propagation = |line: &str| MountInfo::parse(line).unwrap().propagation;
+
+        assert_eq!(
+            propagation("29 1 259:2 / / rw,relatime shared:1 - ext4 /dev/nvme0n1p2 rw"),
+            MountPropagation::Shared
+        );
+        assert_eq!(
+            propagation("30 29 259:2 / /mnt rw,relatime master:1 - ext4 /dev/nvme0n1p2 rw"),
+            MountPropagation::Slave
+        );
+        assert_eq!(
+            propagation("31 29 0:25 / /tmp rw - tmpfs tmpfs rw"),
+            MountPropagation::Private
+        );
+        assert_eq!(
+            propagation("32 29 0:25 / /tmp rw unbindable - tmpfs tmpfs rw"),
+            MountPropagation::Unbindable
+        );
+    }
+
+    #[test]
+    fn test_parse_overlay_options() {
+        assert_eq!(
+            parse_overlay_options(
+                "rw,lowerdir=/l/a:/l/b\\:c:/l/d\\040e,upperdir=/u,workdir=/w,index=off"
+            ),
+            OverlayInfo {
+                lower_dirs: vec!["/l/a".into(), "/l/b:c".into(), "/l/d e".into()],
+                upper_dir: Some("/u".into()),
+                work_dir: Some("/w".into()),
+            },
+        );
+        assert_eq!(
+            parse_overlay_options("ro,lowerdir+=/l/a,lowerdir+=/l/b,redirect_dir=nofollow"),
+            OverlayInfo {
+                lower_dirs: vec!["/l/a".into(), "/l/b".into()],
+                upper_dir: None,
+                work_dir: None,
+            },
+        );
+        assert_eq!(parse_overlay_options("rw"), OverlayInfo::default());
+    }
+
+    #[test]
+    fn test_parse_btrfs_compression() {
+        let compressed = |algorithm: &str| CompressionInfo {
+            is_enabled: true,
+            algorithm: Some(algorithm.to_owned()),
+            ratio: None,
+        };
+        assert_eq!(
+            parse_btrfs_compression("rw,compress=zstd:3,ssd,space_cache=v2,subvol=/@"),
+            compressed("zstd"),
+        );
+        assert_eq!(
+            parse_btrfs_compression("rw,compress-force=lzo"),
+            compressed("lzo")
+        );
+        assert_eq!(parse_btrfs_compression("rw,compress"), compressed("zlib"));
+        assert_eq!(
+            parse_btrfs_compression("rw,compress=zstd,compress=no"),
+            CompressionInfo::default(),
+        );
+        assert_eq!(
+            parse_btrfs_compression("rw,ssd,space_cache=v2"),
+            CompressionInfo::default(),
+        );
+    }
+
+    #[test]
+    fn test_mount_kind() {
+        let kind = |line: &str| MountInfo::parse(line).unwrap().mount_kind();
+
+        assert_eq!(
+            kind("29 1 259:2 / / rw,relatime shared:1 - ext4 /dev/nvme0n1p2 rw"),
+            MountKind::Block
+        );
+        assert_eq!(
+            kind("30 29 259:2 /var/lib/data /data rw,relatime shared:1 - ext4 /dev/nvme0n1p2 rw"),
+            MountKind::Bind
+        );
+        assert_eq!(
+            kind("31 1 0:27 /@home /home rw,relatime shared:2 - btrfs /dev/sda2 rw,subvol=/@home"),
+            MountKind::Block
+        );
+        assert_eq!(
+            kind("32 1 0:27 /@home/user /mnt rw,relatime shared:2 - btrfs /dev/sda2 rw,subvol=/@home"),
+            MountKind::Bind
+        );
+        assert_eq!(
+            kind("33 1 0:50 / /merged rw,relatime - overlay overlay rw,lowerdir=/l,upperdir=/u,workdir=/w"),
+            MountKind::Overlay
+        );
+        assert_eq!(
+            kind("34 1 0:25 / /tmp rw,nosuid,nodev shared:5 - tmpfs tmpfs rw"),
+            MountKind::Tmpfs
+        );
+        assert_eq!(
+            kind("35 1 0:51 / /nfs rw,relatime shared:6 - nfs4 server:/export rw,vers=4.2"),
+            MountKind::Network
+        );
+        assert_eq!(
+            kind("36 1 0:41 / /efi rw,relatime shared:7 - autofs systemd-1 rw,fd=181"),
+            MountKind::Pseudo
+        );
+    }
+
+    #[test]
+    fn test_get_device_name_from_id() {
+        let sys_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let dev_block = sys_dir.path().join("dev/block");
+        fs::create_dir_all(&dev_block).expect("failed to create subdirectory");
+        std::os::unix::fs::symlink("../../devices/sda/sda1", dev_block.join("8:1"))
+            .expect("failed to create symlink");
+
+        assert_eq!(
+            get_device_name_from_id(&dev_block, (8, 1)).as_deref(),
+            Some("sda1")
+        );
+        assert_eq!(get_device_name_from_id(&dev_block, (0, 27)), None);
+    }
+
+    #[test]
+    fn test_parse_ext_mount_time() {
+        let mut superblock = [0; EXT_SUPERBLOCK_SIZE];
+        // Not an ext superblock.
+        assert_eq!(parse_ext_mount_time(&superblock), None);
+
+        superblock[0x38..0x3A].copy_from_slice(&0xEF53u16.to_le_bytes());
+        // Never mounted.
+        assert_eq!(parse_ext_mount_time(&superblock), None);
+
+        superblock[0x2C..0x30].copy_from_slice(&1_700_000_000u32.to_le_bytes());
+        assert_eq!(parse_ext_mount_time(&superblock), Some(1_700_000_000));
+
+        // `s_wtime_hi` must not be used.
+        superblock[0x274] = 2;
+        superblock[0x275] = 1;
+        assert_eq!(
+            parse_ext_mount_time(&superblock),
+            Some(1_700_000_000 + (1 << 32))
+        );
+
+        assert_eq!(parse_ext_mount_time(&superblock[..0x30]), None);
+    }
 }
@@ -38,6 +38,25 @@ fn read<P: AsRef<Path>>(parent: P, path: &str, data: &mut Vec<u8>) -> u64 {
     0
 }
 
+/// Same as [`read`] but returns `None` if the file doesn't exist or doesn't contain a number.
+fn read_optional(parent: &Path, path: &str) -> Option<u64> {
+    std::fs::read_to_string(parent.join(path))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Returns the name of the interface this one is attached to (like a bridge or a bond) by reading
+/// the `master` symlink.
+fn read_master(parent: &Path) -> Option<String> {
+    std::fs::read_link(parent.join("master"))
+        .ok()?
+        .file_name()?
+        .to_str()
+        .map(str::to_owned)
+}
+
 fn refresh_networks_list_from_sysfs(
     interfaces: &mut HashMap<String, NetworkData>,
     remove_not_listed_interfaces: bool,
@@ -66,6 +85,18 @@ fn refresh_networks_list_from_sysfs(
             // let rx_compressed = read(parent, "rx_compressed", &mut data);
             // let tx_compressed = read(parent, "tx_compressed", &mut data);
             let mtu = read(entry_path, "mtu", &mut data);
+            let master = read_master(entry_path);
+            let carrier_changes = read_optional(entry_path, "carrier_changes");
+            let collisions = read_optional(parent, "collisions");
+            let rx_dropped = read_optional(parent, "rx_dropped");
+            let tx_dropped = read_optional(parent, "tx_dropped");
+            let fifo_errors = match (
+                read_optional(parent, "rx_fifo_errors"),
+                read_optional(parent, "tx_fifo_errors"),
+            ) {
+                (None, None) => None,
+                (rx, tx) => Some(rx.unwrap_or(0).saturating_add(tx.unwrap_or(0))),
+            };
 
             match interfaces.entry(entry) {
                 hash_map::Entry::Occupied(mut e) => {
@@ -83,6 +114,12 @@ fn refresh_networks_list_from_sysfs(
                     if interface.mtu != mtu {
                         interface.mtu = mtu;
                     }
+                    interface.master = master;
+                    interface.carrier_changes = carrier_changes;
+                    interface.collisions = collisions;
+                    interface.rx_dropped = rx_dropped;
+                    interface.tx_dropped = tx_dropped;
+                    interface.fifo_errors = fifo_errors;
                     interface.updated = true;
                 }
                 hash_map::Entry::Vacant(e) => {
@@ -107,6 +144,12 @@ fn refresh_networks_list_from_sysfs(
                             // tx_compressed,
                             // old_tx_compressed: tx_compressed,
                             mtu,
+                            master,
+                            carrier_changes,
+                            collisions,
+                            rx_dropped,
+                            tx_dropped,
+                            fifo_errors,
                             updated: true,
                         },
                     });
@@ -179,6 +222,18 @@ pub(crate) struct NetworkDataInner {
     pub(crate) ip_networks: Vec<IpNetwork>,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    /// Name of the interface this one is attached to (bridge, bond...)
+    master: Option<String>,
+    /// Number of times the link went up or down.
+    carrier_changes: Option<u64>,
+    /// Number of collisions detected on the interface.
+    collisions: Option<u64>,
+    /// Number of received packets dropped by the interface.
+    rx_dropped: Option<u64>,
+    /// Number of packets dropped by the interface before being transmitted.
+    tx_dropped: Option<u64>,
+    /// Number of FIFO overruns (received) and underruns (transmitted).
+    fifo_errors: Option<u64>,
     // /// Indicates the number of compressed packets received by this
     // /// network device. This value might only be relevant for interfaces
     // /// that support packet compression (e.g: PPP).
@@ -253,6 +308,30 @@ impl NetworkDataInner {
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn master_interface(&self) -> Option<&str> {
+        self.master.as_deref()
+    }
+
+    pub(crate) fn carrier_changes(&self) -> Option<u64> {
+        self.carrier_changes
+    }
+
+    pub(crate) fn collisions(&self) -> Option<u64> {
+        self.collisions
+    }
+
+    pub(crate) fn drops_on_received(&self) -> Option<u64> {
+        self.rx_dropped
+    }
+
+    pub(crate) fn drops_on_transmitted(&self) -> Option<u64> {
+        self.tx_dropped
+    }
+
+    pub(crate) fn fifo_errors(&self) -> Option<u64> {
+        self.fifo_errors
+    }
 }
 
 #[cfg(test)]
@@ -301,4 +380,74 @@ mod test {
         refresh_networks_list_from_sysfs(&mut interfaces, true, sys_net_dir.path());
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf2"]);
     }
+
+    #[test]
+    fn refresh_networks_list_master_interface() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let br_dir = sys_net_dir.path().join("br0");
+        let veth_dir = sys_net_dir.path().join("veth0");
+        fs::create_dir(&br_dir).expect("failed to create subdirectory");
+        fs::create_dir(&veth_dir).expect("failed to create subdirectory");
+        std::os::unix::fs::symlink("../br0", veth_dir.join("master"))
+            .expect("failed to create symlink");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["br0"].inner.master_interface(), None);
+        assert_eq!(interfaces["veth0"].inner.master_interface(), Some("br0"));
+
+        fs::remove_file(veth_dir.join("master")).expect("failed to remove symlink");
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["veth0"].inner.master_interface(), None);
+    }
+
+    #[test]
+    fn refresh_networks_list_carrier_changes_and_collisions() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let itf_dir = sys_net_dir.path().join("itf1");
+        fs::create_dir_all(itf_dir.join("statistics")).expect("failed to create subdirectory");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["itf1"].inner.carrier_changes(), None);
+        assert_eq!(interfaces["itf1"].inner.collisions(), None);
+
+        fs::write(itf_dir.join("carrier_changes"), "4\n").expect("failed to write file");
+        fs::write(itf_dir.join("statistics/collisions"), "12\n").expect("failed to write file");
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["itf1"].inner.carrier_changes(), Some(4));
+        assert_eq!(interfaces["itf1"].inner.collisions(), Some(12));
+    }
+
+    #[test]
+    fn refresh_networks_list_drops_and_fifo_errors() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let itf_dir = sys_net_dir.path().join("itf1");
+        fs::create_dir_all(itf_dir.join("statistics")).expect("failed to create subdirectory");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["itf1"].inner.drops_on_received(), None);
+        assert_eq!(interfaces["itf1"].inner.drops_on_transmitted(), None);
+        assert_eq!(interfaces["itf1"].inner.fifo_errors(), None);
+
+        let stats_dir = itf_dir.join("statistics");
+        fs::write(stats_dir.join("rx_dropped"), "3\n").expect("failed to write file");
+        fs::write(stats_dir.join("tx_dropped"), "5\n").expect("failed to write file");
+        fs::write(stats_dir.join("rx_fifo_errors"), "7\n").expect("failed to write file");
+        fs::write(stats_dir.join("tx_fifo_errors"), "1\n").expect("failed to write file");
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        assert_eq!(interfaces["itf1"].inner.drops_on_received(), Some(3));
+        assert_eq!(interfaces["itf1"].inner.drops_on_transmitted(), Some(5));
+        assert_eq!(interfaces["itf1"].inner.fifo_errors(), Some(8));
+    }
 }
@@ -5,25 +5,30 @@ use std::collections::{HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::fs::{self, DirEntry, File};
-use std::io::Read;
+use std::io::{self, Read};
 use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::FileTypeExt;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::str::{self, FromStr};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 use libc::{c_ulong, gid_t, uid_t};
 
 use crate::sys::system::SystemInfo;
 use crate::sys::utils::{
-    get_all_data_from_file, get_all_utf8_data, realpath, PathHandler, PathPush,
+    get_all_data_from_file, get_all_utf8_data, realpath, to_cpath, PathHandler, PathPush,
 };
+use crate::unix::utils::elf_bitness;
 use crate::{
-    DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal,
-    ThreadKind, Uid,
+    AutogroupInfo, Bitness, CGroupMembership, CGroupUsage, DiskUsage, ExePermissions, FdKind, Gid,
+    IoSyscalls, NameSource, OpenFile, Pid, Process, ProcessRefreshKind, ProcessStatus,
+    ProcessesToUpdate, RssBreakdown, RtParams, SchedPolicy, SchedStats, SchedulingSummary, Signal,
+    SocketSummary, ThreadKind, Uid, UpdateKind,
 };
 
-use crate::sys::system::remaining_files;
+use crate::sys::system::{read_cgroup_cpu_quota, remaining_files};
 
 #[doc(hidden)]
 impl From<char> for ProcessStatus {
@@ -90,6 +95,23 @@ enum ProcIndex {
     StartTime,
     VirtualSize,
     ResidentSetSize,
+    ResidentSetSizeLimit,
+    StartCode,
+    EndCode,
+    StartStack,
+    KernelStackPointer,
+    KernelInstructionPointer,
+    Signal,
+    Blocked,
+    SignalIgnore,
+    SignalCatch,
+    WaitChannel,
+    SwappedPages,
+    ChildrenSwappedPages,
+    ExitSignal,
+    Processor,
+    RealtimePriority,
+    Policy,
     // More exist but we only use the listed ones. For more, take a look at `man proc`.
 }
 
@@ -97,6 +119,8 @@ pub(crate) struct ProcessInner {
     pub(crate) name: OsString,
     pub(crate) cmd: Vec<OsString>,
     pub(crate) exe: Option<PathBuf>,
+    /// Device and inode of the executable, retrieved along with `exe`.
+    pub(crate) exe_identity: Option<(u64, u64)>,
     pub(crate) pid: Pid,
     parent: Option<Pid>,
     pub(crate) environ: Vec<OsString>,
@@ -104,26 +128,43 @@ pub(crate) struct ProcessInner {
     pub(crate) root: Option<PathBuf>,
     pub(crate) memory: u64,
     pub(crate) virtual_memory: u64,
+    old_memory: u64,
     utime: u64,
     stime: u64,
     old_utime: u64,
     old_stime: u64,
+    /// Global CPU time (divided by the number of CPUs) of the last CPU usage computation, in
+    /// clock ticks like `utime` and `stime`.
+    pub(crate) global_cpu_time: u64,
     start_time_without_boot_time: u64,
     start_time: u64,
     run_time: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
+    /// Number of CPUs when `cpu_usage` was last computed.
+    pub(crate) cpu_count: usize,
     user_id: Option<Uid>,
     effective_user_id: Option<Uid>,
     group_id: Option<Gid>,
     effective_group_id: Option<Gid>,
     pub(crate) status: ProcessStatus,
     pub(crate) tasks: Option<HashSet<Pid>>,
+    pub(crate) task_names: Option<HashMap<Pid, OsString>>,
+    /// Task with the highest CPU usage, computed along with the CPU usage.
+    pub(crate) hottest_task: Option<Pid>,
     stat_file: Option<FileCounter>,
     old_read_bytes: u64,
     old_written_bytes: u64,
     read_bytes: u64,
     written_bytes: u64,
+    /// Bytes read and written through syscalls (`rchar` and `wchar`), `None` until
+    /// `/proc/[pid]/io` could be read.
+    old_chars: Option<(u64, u64)>,
+    chars: Option<(u64, u64)>,
+    /// Number of read and write syscalls (`syscr` and `syscw`).
+    io_syscalls: Option<IoSyscalls>,
+    open_files: Option<Vec<OpenFile>>,
+    socket_summary: Option<SocketSummary>,
     thread_kind: Option<ThreadKind>,
     proc_path: PathBuf,
     accumulated_cpu_time: u64,
@@ -138,15 +179,19 @@ impl ProcessInner {
             cmd: Vec::new(),
             environ: Vec::new(),
             exe: None,
+            exe_identity: None,
             cwd: None,
             root: None,
             memory: 0,
             virtual_memory: 0,
+            old_memory: 0,
             cpu_usage: 0.,
+            cpu_count: 0,
             utime: 0,
             stime: 0,
             old_utime: 0,
             old_stime: 0,
+            global_cpu_time: 0,
             updated: true,
             start_time_without_boot_time: 0,
             start_time: 0,
@@ -157,11 +202,18 @@ impl ProcessInner {
             effective_group_id: None,
             status: ProcessStatus::Unknown(0),
             tasks: None,
+            task_names: None,
+            hottest_task: None,
             stat_file: None,
             old_read_bytes: 0,
             old_written_bytes: 0,
             read_bytes: 0,
             written_bytes: 0,
+            old_chars: None,
+            chars: None,
+            io_syscalls: None,
+            open_files: None,
+            socket_summary: None,
             thread_kind: None,
             proc_path,
             accumulated_cpu_time: 0,
@@ -193,6 +245,14 @@ impl ProcessInner {
         &self.environ
     }
 
+    pub(crate) fn open_files(&self) -> Option<&[OpenFile]> {
+        self.open_files.as_deref()
+    }
+
+    pub(crate) fn socket_summary(&self) -> Option<SocketSummary> {
+        self.socket_summary
+    }
+
     pub(crate) fn cwd(&self) -> Option<&Path> {
         self.cwd.as_deref()
     }
@@ -205,6 +265,10 @@ impl ProcessInner {
         self.memory
     }
 
+    pub(crate) fn memory_delta(&self) -> i64 {
+        self.memory as i64 - self.old_memory as i64
+    }
+
     pub(crate) fn virtual_memory(&self) -> u64 {
         self.virtual_memory
     }
@@ -229,10 +293,18 @@ impl ProcessInner {
         self.cpu_usage
     }
 
+    pub(crate) fn cpu_count(&self) -> usize {
+        self.cpu_count
+    }
+
     pub(crate) fn accumulated_cpu_time(&self) -> u64 {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn cpu_times(&self) -> (u64, u64) {
+        (self.utime.saturating_add(self.stime), self.global_cpu_time)
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -242,6 +314,22 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn char_io(&self) -> Option<DiskUsage> {
+        let (read_chars, written_chars) = self.chars?;
+        let (old_read_chars, old_written_chars) =
+            self.old_chars.unwrap_or((read_chars, written_chars));
+        Some(DiskUsage {
+            written_bytes: written_chars.saturating_sub(old_written_chars),
+            total_written_bytes: written_chars,
+            read_bytes: read_chars.saturating_sub(old_read_chars),
+            total_read_bytes: read_chars,
+        })
+    }
+
+    pub(crate) fn io_syscalls(&self) -> Option<IoSyscalls> {
+        self.io_syscalls
+    }
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         self.user_id.as_ref()
     }
@@ -273,10 +361,310 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        unsafe {
+            let group_id = libc::getpgid(self.pid.0);
+            if group_id < 0 {
+                None
+            } else {
+                Some(Pid(group_id))
+            }
+        }
+    }
+
+    pub(crate) fn last_cpu(&self) -> Option<usize> {
+        let mut file = File::open(Path::join(&self.proc_path, "stat")).ok()?;
+        let data = get_all_data_from_file(&mut file, 1024).ok()?;
+        let parts = parse_stat_file(&data)?;
+        parts
+            .str_parts
+            .get(ProcIndex::Processor as usize)?
+            .parse()
+            .ok()
+    }
+
+    pub(crate) fn scheduling_summary(&self) -> Option<SchedulingSummary> {
+        let mut file = File::open(Path::join(&self.proc_path, "stat")).ok()?;
+        let data = get_all_data_from_file(&mut file, 1024).ok()?;
+        let parts = parse_stat_file(&data)?;
+        let field = |index: ProcIndex| parts.str_parts.get(index as usize).copied();
+
+        let mut summary = SchedulingSummary {
+            policy: field(ProcIndex::Policy)
+                .and_then(|policy| i32::from_str(policy).ok())
+                .map(|policy| match policy {
+                    // `SCHED_OTHER` is named `SCHED_NORMAL` on Android.
+                    0 => SchedPolicy::Other,
+                    libc::SCHED_FIFO => SchedPolicy::Fifo,
+                    libc::SCHED_RR => SchedPolicy::RoundRobin,
+                    libc::SCHED_BATCH => SchedPolicy::Batch,
+                    libc::SCHED_IDLE => SchedPolicy::Idle,
+                    // `SCHED_DEADLINE` is only available in recent versions of the `libc` crate.
+                    6 => SchedPolicy::Deadline,
+                    policy => SchedPolicy::Unknown(policy as _),
+                }),
+            nice: field(ProcIndex::Nice).and_then(|nice| i32::from_str(nice).ok()),
+            last_cpu: field(ProcIndex::Processor).and_then(|cpu| usize::from_str(cpu).ok()),
+            wait_time_ns: self.sched_stats().map(|stats| stats.wait_time_ns),
+            ..Default::default()
+        };
+        if let Ok(status) = get_all_utf8_data(Path::join(&self.proc_path, "status"), 2048) {
+            for line in status.lines() {
+                let Some((key, value)) = line.split_once(':') else {
+                    continue;
+                };
+                match key {
+                    "voluntary_ctxt_switches" => {
+                        summary.voluntary_context_switches = u64::from_str(value.trim()).ok();
+                    }
+                    "nonvoluntary_ctxt_switches" => {
+                        summary.involuntary_context_switches = u64::from_str(value.trim()).ok();
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Some(summary)
+    }
+
+    pub(crate) fn foreground_process_group_id(&self) -> Option<Pid> {
+        let mut file = File::open(Path::join(&self.proc_path, "stat")).ok()?;
+        let data = get_all_data_from_file(&mut file, 1024).ok()?;
+        let parts = parse_stat_file(&data)?;
+        match parts
+            .str_parts
+            .get(ProcIndex::ForegroundProcessGroupId as usize)?
+            .parse()
+        {
+            // `-1` means that the process has no controlling terminal.
+            Ok(pid) if pid > 0 => Some(Pid(pid)),
+            _ => None,
+        }
+    }
+
     pub(crate) fn thread_kind(&self) -> Option<ThreadKind> {
         self.thread_kind
     }
 
+    pub(crate) fn security_context(&self) -> Option<String> {
+        // `attr/current` is provided by the "major" LSM (SELinux for example). When LSMs are
+        // stacked, AppArmor might only be reachable through its own sub-folder.
+        ["attr/current", "attr/apparmor/current"]
+            .iter()
+            .find_map(|file| {
+                let data = get_all_utf8_data(Path::join(&self.proc_path, file), 256).ok()?;
+                let context = data.trim_end_matches(['\0', '\n']);
+                if context.is_empty() {
+                    None
+                } else {
+                    Some(context.to_owned())
+                }
+            })
+    }
+
+    pub(crate) fn umask(&self) -> Option<u32> {
+        // The `Umask` field was added in Linux 4.7.
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 16_385).ok()?;
+        let umask = data.lines().find_map(|line| line.strip_prefix("Umask:"))?;
+        u32::from_str_radix(umask.trim(), 8).ok()
+    }
+
+    pub(crate) fn tracer_pid(&self) -> Option<Pid> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 16_385).ok()?;
+        let tracer = data
+            .lines()
+            .find_map(|line| line.strip_prefix("TracerPid:"))?;
+        match tracer.trim().parse() {
+            Ok(0) | Err(_) => None,
+            Ok(pid) => Some(Pid(pid)),
+        }
+    }
+
+    pub(crate) fn rss_breakdown(&self) -> Option<RssBreakdown> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 16_385).ok()?;
+        parse_rss_breakdown(&data)
+    }
+
+    pub(crate) fn sched_stats(&self) -> Option<SchedStats> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "schedstat"), 64).ok()?;
+        let mut parts = data.split_whitespace().map(u64::from_str);
+        Some(SchedStats {
+            run_time_ns: parts.next()?.ok()?,
+            wait_time_ns: parts.next()?.ok()?,
+            timeslices: parts.next()?.ok()?,
+        })
+    }
+
+    pub(crate) fn autogroup(&self) -> Option<AutogroupInfo> {
+        // The `autogroup` file is still there when autogroups are disabled at runtime.
+        if get_all_utf8_data("/proc/sys/kernel/sched_autogroup_enabled", 4)
+            .ok()?
+            .trim()
+            != "1"
+        {
+            return None;
+        }
+        parse_autogroup(&get_all_utf8_data(Path::join(&self.proc_path, "autogroup"), 64).ok()?)
+    }
+
+    pub(crate) fn effective_cpu_count(&self) -> Option<f32> {
+        let affinity = unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            if libc::sched_getaffinity(self.pid.0, std::mem::size_of::<libc::cpu_set_t>(), &mut set)
+                != 0
+            {
+                return None;
+            }
+            libc::CPU_COUNT(&set) as f32
+        };
+        Some(match cgroup_cpu_quota(&self.proc_path) {
+            Some(quota) => affinity.min(quota),
+            None => affinity,
+        })
+    }
+
+    pub(crate) fn cgroups(&self) -> Option<Vec<CGroupMembership>> {
+        read_cgroups(&self.proc_path)
+    }
+
+    pub(crate) fn cgroup_usage(&self) -> Option<CGroupUsage> {
+        let dir = cgroup_v2_path(&self.proc_path)?;
+        let read = |file| {
+            get_all_utf8_data(Path::join(&dir, file), 32)
+                .ok()
+                .and_then(|d| u64::from_str(d.trim()).ok())
+        };
+        let cpu_stat = get_all_utf8_data(Path::join(&dir, "cpu.stat"), 1024).ok()?;
+        let cpu_usage_usec = cpu_stat
+            .lines()
+            .find_map(|line| line.strip_prefix("usage_usec "))?;
+        Some(CGroupUsage {
+            memory_current: read("memory.current")?,
+            memory_peak: read("memory.peak"),
+            cpu_usage_usec: u64::from_str(cpu_usage_usec.trim()).ok()?,
+        })
+    }
+
+    // `mode_t` is `u16` on some Android targets.
+    #[allow(clippy::unnecessary_cast)]
+    pub(crate) fn exe_permissions(&self) -> Option<ExePermissions> {
+        use std::os::unix::fs::MetadataExt;
+
+        let exe_link = Path::join(&self.proc_path, "exe");
+        // `metadata` follows the link so we get the executable which is actually run.
+        let exe = fs::metadata(&exe_link).ok()?;
+        Some(ExePermissions {
+            setuid: exe.mode() & libc::S_ISUID as u32 != 0,
+            setgid: exe.mode() & libc::S_ISGID as u32 != 0,
+            has_file_caps: has_file_caps(&exe_link),
+            owner_uid: Uid(exe.uid()),
+        })
+    }
+
+    pub(crate) fn exe_deleted(&self) -> Option<bool> {
+        use std::os::unix::fs::MetadataExt;
+
+        let exe_link = Path::join(&self.proc_path, "exe");
+        // When the executable is deleted, the kernel appends " (deleted)" to the link target.
+        let target = fs::read_link(&exe_link).ok()?;
+        if !target.as_os_str().as_bytes().ends_with(b" (deleted)") {
+            return Some(false);
+        }
+        // The file could actually be named like this, so we check that it's not the executable.
+        let exe = fs::metadata(&exe_link).ok()?;
+        Some(match fs::metadata(&target) {
+            Ok(target) => target.dev() != exe.dev() || target.ino() != exe.ino(),
+            Err(_) => true,
+        })
+    }
+
+    pub(crate) fn exe_identity(&self) -> Option<(u64, u64)> {
+        self.exe_identity
+    }
+
+    pub(crate) fn bitness(&self) -> Option<Bitness> {
+        elf_bitness(&Path::join(&self.proc_path, "exe"))
+    }
+
+    pub(crate) fn is_chrooted(&self) -> Option<bool> {
+        use std::os::unix::fs::MetadataExt;
+
+        // Both `root` links are followed, so we compare the directories they point to.
+        let root = fs::metadata(Path::join(&self.proc_path, "root")).ok()?;
+        let own_root = fs::metadata("/proc/self/root").ok()?;
+        Some(root.dev() != own_root.dev() || root.ino() != own_root.ino())
+    }
+
+    pub(crate) fn mount_namespace_id(&self) -> Option<u64> {
+        // The link target looks like `mnt:[4026531841]`.
+        let link = fs::read_link(Path::join(&self.proc_path, "ns/mnt")).ok()?;
+        link.to_str()?
+            .strip_prefix("mnt:[")?
+            .strip_suffix(']')?
+            .parse()
+            .ok()
+    }
+
+    pub(crate) fn open_files_limit(&self) -> Option<(u64, u64)> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "limits"), 2048).ok()?;
+        let line = data
+            .lines()
+            .find_map(|line| line.strip_prefix("Max open files"))?;
+        let mut parts = line.split_whitespace().map(|value| match value {
+            "unlimited" => Some(u64::MAX),
+            value => u64::from_str(value).ok(),
+        });
+        Some((parts.next()??, parts.next()??))
+    }
+
+    pub(crate) fn realtime_params(&self) -> Option<RtParams> {
+        // Same layout as the kernel's `struct sched_attr` (`SCHED_ATTR_SIZE_VER0`). It is
+        // declared here because it's only available in recent versions of the `libc` crate.
+        #[repr(C)]
+        #[derive(Default)]
+        struct SchedAttr {
+            size: u32,
+            sched_policy: u32,
+            sched_flags: u64,
+            sched_nice: i32,
+            sched_priority: u32,
+            sched_runtime: u64,
+            sched_deadline: u64,
+            sched_period: u64,
+        }
+
+        let mut attr = SchedAttr::default();
+        let size = std::mem::size_of::<SchedAttr>() as libc::c_uint;
+        if unsafe {
+            libc::syscall(
+                libc::SYS_sched_getattr,
+                self.pid.0,
+                &mut attr as *mut SchedAttr,
+                size,
+                0 as libc::c_uint,
+            )
+        } != 0
+        {
+            sysinfo_debug!("`sched_getattr` failed for {:?}", self.pid);
+            return None;
+        }
+        match attr.sched_policy as libc::c_int {
+            libc::SCHED_FIFO | libc::SCHED_RR => Some(RtParams {
+                priority: attr.sched_priority as i32,
+                runtime_ns: 0,
+                period_ns: 0,
+                deadline_ns: 0,
+            }),
+            libc::SCHED_DEADLINE => Some(RtParams {
+                priority: attr.sched_priority as i32,
+                runtime_ns: attr.sched_runtime,
+                period_ns: attr.sched_period,
+                deadline_ns: attr.sched_deadline,
+            }),
+            _ => None,
+        }
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         std::mem::replace(&mut self.updated, false)
     }
@@ -307,15 +695,93 @@ pub(crate) fn set_time(p: &mut ProcessInner, utime: u64, stime: u64) {
     p.updated = true;
 }
 
+/// Returns the cgroups of the process, read from `/proc/[pid]/cgroup`.
+pub(crate) fn read_cgroups(proc_path: &Path) -> Option<Vec<CGroupMembership>> {
+    let data = get_all_utf8_data(Path::join(proc_path, "cgroup"), 16_384).ok()?;
+    Some(parse_cgroups(&data))
+}
+
+fn parse_cgroups(data: &str) -> Vec<CGroupMembership> {
+    data.lines()
+        .filter_map(|line| {
+            // The format is "$ID:$CONTROLLERS:$PATH", `$CONTROLLERS` being empty for cgroups v2.
+            let mut parts = line.splitn(3, ':');
+            let _id = parts.next()?;
+            let controllers = parts.next()?;
+            let path = parts.next()?;
+            Some(CGroupMembership {
+                controllers: controllers
+                    .split(',')
+                    .filter(|controller| !controller.is_empty())
+                    .map(str::to_owned)
+                    .collect(),
+                path: path.to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the number of CPUs (possibly fractional) the cgroup of the process is allowed to use,
+/// taking into account the quotas of its parent cgroups.
+pub(crate) fn cgroup_cpu_quota(proc_path: &Path) -> Option<f32> {
+    let mut min_quota: Option<f32> = None;
+    for cgroup in read_cgroups(proc_path)? {
+        let root = if cgroup.controllers.is_empty() {
+            Path::new("/sys/fs/cgroup")
+        } else if cgroup
+            .controllers
+            .iter()
+            .any(|controller| controller == "cpu")
+        {
+            Path::new("/sys/fs/cgroup/cpu")
+        } else {
+            continue;
+        };
+        let mut dir = root.join(cgroup.path.trim_start_matches('/'));
+        loop {
+            if let Some((quota, period)) = read_cgroup_cpu_quota(&dir) {
+                let quota = quota as f32 / period as f32;
+                min_quota = Some(min_quota.map_or(quota, |min_quota| min_quota.min(quota)));
+            }
+            if dir == root || !dir.pop() {
+                break;
+            }
+        }
+    }
+    min_quota
+}
+
+/// Returns the directory of the cgroup v2 of the process.
+fn cgroup_v2_path(proc_path: &Path) -> Option<PathBuf> {
+    let cgroup = read_cgroups(proc_path)?
+        .into_iter()
+        .find(|cgroup| cgroup.controllers.is_empty())?;
+    Some(Path::new("/sys/fs/cgroup").join(cgroup.path.trim_start_matches('/')))
+}
+
 pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut PathHandler) {
     let data = match get_all_utf8_data(path.join("io"), 16_384) {
         Ok(d) => d,
-        Err(_) => return,
+        Err(_) => {
+            // We don't want to keep returning the previous deltas.
+            p.old_read_bytes = p.read_bytes;
+            p.old_written_bytes = p.written_bytes;
+            p.old_chars = p.chars;
+            return;
+        }
     };
     let mut done = 0;
+    let mut read_chars = None;
+    let mut written_chars = None;
+    let mut read_calls = None;
+    let mut write_calls = None;
     for line in data.split('\n') {
         let mut parts = line.split(": ");
         match parts.next() {
+            Some("rchar") => read_chars = parts.next().and_then(|x| x.parse::<u64>().ok()),
+            Some("wchar") => written_chars = parts.next().and_then(|x| x.parse::<u64>().ok()),
+            Some("syscr") => read_calls = parts.next().and_then(|x| x.parse::<u64>().ok()),
+            Some("syscw") => write_calls = parts.next().and_then(|x| x.parse::<u64>().ok()),
             Some("read_bytes") => {
                 p.old_read_bytes = p.read_bytes;
                 p.read_bytes = parts
@@ -333,11 +799,21 @@ pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut Path
             _ => continue,
         }
         done += 1;
-        if done > 1 {
+        if done > 5 {
             // No need to continue the reading.
             break;
         }
     }
+    if let Some(chars) = read_chars.zip(written_chars) {
+        p.old_chars = p.chars.or(Some(chars));
+        p.chars = Some(chars);
+    }
+    if let Some((read_calls, write_calls)) = read_calls.zip(write_calls) {
+        p.io_syscalls = Some(IoSyscalls {
+            read_calls,
+            write_calls,
+        });
+    }
 }
 
 struct Wrap<'a, T>(UnsafeCell<&'a mut T>);
@@ -359,9 +835,13 @@ fn compute_start_time_without_boot_time(parts: &Parts<'_>, info: &SystemInfo) ->
     u64::from_str(parts.str_parts[ProcIndex::StartTime as usize]).unwrap_or(0) / info.clock_cycle
 }
 
-fn _get_stat_data(path: &Path, stat_file: &mut Option<FileCounter>) -> Result<Vec<u8>, ()> {
-    let mut file = File::open(path.join("stat")).map_err(|_| ())?;
-    let data = get_all_data_from_file(&mut file, 1024).map_err(|_| ())?;
+fn invalid_stat_file() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "invalid stat file")
+}
+
+fn _get_stat_data(path: &Path, stat_file: &mut Option<FileCounter>) -> io::Result<Vec<u8>> {
+    let mut file = File::open(path.join("stat"))?;
+    let data = get_all_data_from_file(&mut file, 1024)?;
     *stat_file = FileCounter::new(file);
     Ok(data)
 }
@@ -394,6 +874,28 @@ fn refresh_user_group_ids(
     }
 }
 
+/// Returns `true` if the file has the `security.capability` extended attribute.
+fn has_file_caps(path: &Path) -> bool {
+    let path = to_cpath(path);
+    // With an empty buffer, `getxattr` returns the size of the attribute (and follows the link).
+    unsafe {
+        libc::getxattr(
+            path.as_ptr() as *const _,
+            b"security.capability\0".as_ptr() as *const _,
+            std::ptr::null_mut(),
+            0,
+        ) > 0
+    }
+}
+
+fn exe_identity(exe_link: &Path) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+
+    // `metadata` follows the link so we get the executable which is actually run, even if it
+    // was deleted or replaced on disk.
+    fs::metadata(exe_link).ok().map(|m| (m.dev(), m.ino()))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn update_proc_info(
     p: &mut ProcessInner,
@@ -403,6 +905,7 @@ fn update_proc_info(
     str_parts: &[&str],
     uptime: u64,
     info: &SystemInfo,
+    tcp_tables: &TcpTables,
 ) {
     update_parent_pid(p, parent_pid, str_parts);
 
@@ -414,6 +917,10 @@ fn update_proc_info(
         // See https://github.com/GuillaumeGomez/sysinfo/issues/697.
         p.exe = realpath(proc_path.join("exe"));
     }
+    // Unlike `exe`, it is refreshed every time so a change of executable is detected.
+    if refresh_kind.exe() != UpdateKind::Never {
+        p.exe_identity = exe_identity(proc_path.join("exe"));
+    }
 
     if refresh_kind.cmd().needs_update(|| p.cmd.is_empty()) {
         p.cmd = copy_from_file(proc_path.join("cmdline"));
@@ -427,6 +934,17 @@ fn update_proc_info(
     if refresh_kind.root().needs_update(|| p.root.is_none()) {
         p.root = realpath(proc_path.join("root"));
     }
+    if refresh_kind.open_files() || refresh_kind.connections() {
+        let open_files = get_open_files(proc_path.join("fd"));
+        if refresh_kind.connections() {
+            p.socket_summary = open_files.as_ref().map(|(_, socket_inodes)| {
+                get_socket_summary(&p.proc_path, socket_inodes, tcp_tables)
+            });
+        }
+        if refresh_kind.open_files() {
+            p.open_files = open_files.map(|(open_files, _)| open_files);
+        }
+    }
 
     update_time_and_memory(proc_path, p, str_parts, uptime, info, refresh_kind);
     if refresh_kind.disk_usage() {
@@ -441,6 +959,37 @@ fn update_proc_info(
     }
 }
 
+fn update_name(
+    p: &mut ProcessInner,
+    comm: &[u8],
+    refresh_kind: ProcessRefreshKind,
+    proc_path: &mut PathHandler,
+) {
+    let name = match refresh_kind.name_source() {
+        NameSource::Comm => None,
+        NameSource::Exe => match p.exe {
+            Some(ref exe) => exe.file_name().map(|name| name.to_os_string()),
+            None => realpath(proc_path.join("exe"))
+                .and_then(|exe| exe.file_name().map(|name| name.to_os_string())),
+        },
+        NameSource::Cmdline => {
+            if p.cmd.is_empty() {
+                copy_from_file(proc_path.join("cmdline")).into_iter().next()
+            } else {
+                p.cmd.first().cloned()
+            }
+        }
+    };
+    match name {
+        Some(name) if !name.is_empty() => p.name = name,
+        _ => {
+            if p.name.as_bytes() != comm {
+                p.name = OsStr::from_bytes(comm).to_os_string();
+            }
+        }
+    }
+}
+
 fn update_parent_pid(p: &mut ProcessInner, parent_pid: Option<Pid>, str_parts: &[&str]) {
     p.parent = match parent_pid {
         Some(parent_pid) if parent_pid.0 != 0 => Some(parent_pid),
@@ -451,6 +1000,7 @@ fn update_parent_pid(p: &mut ProcessInner, parent_pid: Option<Pid>, str_parts: &
     };
 }
 
+#[allow(clippy::too_many_arguments)]
 fn retrieve_all_new_process_info(
     pid: Pid,
     parent_pid: Option<Pid>,
@@ -459,6 +1009,7 @@ fn retrieve_all_new_process_info(
     info: &SystemInfo,
     refresh_kind: ProcessRefreshKind,
     uptime: u64,
+    tcp_tables: &TcpTables,
 ) -> Process {
     let mut p = ProcessInner::new(pid, path.to_owned());
     let mut proc_path = PathHandler::new(path);
@@ -487,11 +1038,16 @@ fn retrieve_all_new_process_info(
         &parts.str_parts,
         uptime,
         info,
+        tcp_tables,
     );
+    update_name(&mut p, name, refresh_kind, &mut proc_path);
+    // No previous value for a new process.
+    p.old_memory = p.memory;
 
     Process { inner: p }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn _get_process_data(
     path: &Path,
     proc_list: &mut HashMap<Pid, Process>,
@@ -500,7 +1056,8 @@ pub(crate) fn _get_process_data(
     uptime: u64,
     info: &SystemInfo,
     refresh_kind: ProcessRefreshKind,
-) -> Result<(Option<Process>, Pid), ()> {
+    tcp_tables: &TcpTables,
+) -> io::Result<(Option<Process>, Pid)> {
     let data;
     let parts = if let Some(ref mut entry) = proc_list.get_mut(&pid) {
         let entry = &mut entry.inner;
@@ -520,7 +1077,7 @@ pub(crate) fn _get_process_data(
         } else {
             _get_stat_data(path, &mut entry.stat_file)?
         };
-        let parts = parse_stat_file(&data).ok_or(())?;
+        let parts = parse_stat_file(&data).ok_or_else(invalid_stat_file)?;
         let start_time_without_boot_time = compute_start_time_without_boot_time(&parts, info);
 
         // It's possible that a new process took this same PID when the "original one" terminated.
@@ -537,16 +1094,18 @@ pub(crate) fn _get_process_data(
                 &parts.str_parts,
                 uptime,
                 info,
+                tcp_tables,
             );
 
             refresh_user_group_ids(entry, &mut proc_path, refresh_kind);
+            update_name(entry, parts.short_exe, refresh_kind, &mut proc_path);
             return Ok((None, pid));
         }
         parts
     } else {
         let mut stat_file = None;
         let data = _get_stat_data(path, &mut stat_file)?;
-        let parts = parse_stat_file(&data).ok_or(())?;
+        let parts = parse_stat_file(&data).ok_or_else(invalid_stat_file)?;
 
         let mut p = retrieve_all_new_process_info(
             pid,
@@ -556,14 +1115,23 @@ pub(crate) fn _get_process_data(
             info,
             refresh_kind,
             uptime,
+            tcp_tables,
         );
         p.inner.stat_file = stat_file;
         return Ok((Some(p), pid));
     };
 
     // If we're here, it means that the PID still exists but it's a different process.
-    let p =
-        retrieve_all_new_process_info(pid, parent_pid, &parts, path, info, refresh_kind, uptime);
+    let p = retrieve_all_new_process_info(
+        pid,
+        parent_pid,
+        &parts,
+        path,
+        info,
+        refresh_kind,
+        uptime,
+        tcp_tables,
+    );
     match proc_list.get_mut(&pid) {
         Some(ref mut entry) => **entry = p,
         // If it ever enters this case, it means that the process was removed from the HashMap
@@ -639,6 +1207,7 @@ fn update_time_and_memory(
         #[allow(clippy::collapsible_if)]
         if refresh_kind.memory() {
             // Keeping this nested level for readability reasons.
+            entry.old_memory = entry.memory;
             if !get_memory(path.join("statm"), entry, info) {
                 old_get_memory(entry, str_parts, info);
             }
@@ -657,6 +1226,7 @@ struct ProcAndTasks {
     parent_pid: Option<Pid>,
     path: PathBuf,
     tasks: Option<HashSet<Pid>>,
+    task_names: Option<HashMap<Pid, OsString>>,
 }
 
 fn get_all_pid_entries(
@@ -665,6 +1235,7 @@ fn get_all_pid_entries(
     entry: DirEntry,
     data: &mut Vec<ProcAndTasks>,
     enable_task_stats: bool,
+    enable_task_names: bool,
 ) -> Option<Pid> {
     let Ok(file_type) = entry.file_type() else {
         return None;
@@ -683,21 +1254,37 @@ fn get_all_pid_entries(
     let name = name?;
     let pid = Pid::from(usize::from_str(name.to_str()?).ok()?);
 
-    let tasks = if enable_task_stats {
+    let (tasks, task_names) = if enable_task_stats {
         let tasks_dir = Path::join(&entry, "task");
-        if let Ok(entries) = fs::read_dir(tasks_dir) {
+        if let Ok(entries) = fs::read_dir(&tasks_dir) {
             let mut tasks = HashSet::new();
+            let mut task_names = HashMap::new();
             for task in entries.into_iter().filter_map(|entry| {
-                get_all_pid_entries(Some(name), Some(pid), entry.ok()?, data, enable_task_stats)
+                get_all_pid_entries(
+                    Some(name),
+                    Some(pid),
+                    entry.ok()?,
+                    data,
+                    enable_task_stats,
+                    enable_task_names,
+                )
             }) {
                 tasks.insert(task);
+                if !enable_task_names {
+                    continue;
+                }
+                let comm = Path::join(&tasks_dir, format!("{task}/comm"));
+                if let Ok(comm) = fs::read(comm) {
+                    let comm = comm.strip_suffix(b"\n").unwrap_or(&comm);
+                    task_names.insert(task, OsStr::from_bytes(comm).to_os_string());
+                }
             }
-            Some(tasks)
+            (Some(tasks), Some(task_names).filter(|_| enable_task_names))
         } else {
-            None
+            (None, None)
         }
     } else {
-        None
+        (None, None)
     };
 
     data.push(ProcAndTasks {
@@ -705,6 +1292,7 @@ fn get_all_pid_entries(
         parent_pid,
         path: entry,
         tasks,
+        task_names,
     });
     Some(pid)
 }
@@ -727,6 +1315,11 @@ where
     val
 }
 
+/// Refreshes the processes and returns the number of updated processes along with the errors
+/// which occurred.
+///
+/// When refreshing all processes, the processes which disappeared while being refreshed are not
+/// considered as errors.
 pub(crate) fn refresh_procs(
     proc_list: &mut HashMap<Pid, Process>,
     path: &Path,
@@ -734,7 +1327,7 @@ pub(crate) fn refresh_procs(
     info: &SystemInfo,
     processes_to_update: ProcessesToUpdate<'_>,
     refresh_kind: ProcessRefreshKind,
-) -> usize {
+) -> (usize, Vec<(Pid, io::Error)>) {
     #[cfg(feature = "multithread")]
     use rayon::iter::ParallelIterator;
 
@@ -756,13 +1349,17 @@ pub(crate) fn refresh_procs(
         ProcessesToUpdate::All => (&[], &empty_filter),
         ProcessesToUpdate::Some(pids) => {
             if pids.is_empty() {
-                return 0;
+                return (0, Vec::new());
             }
             (pids, &real_filter)
         }
     };
 
     let nb_updated = AtomicUsize::new(0);
+    let tcp_tables = TcpTables::default();
+    let is_gone = |error: &io::Error| {
+        error.kind() == io::ErrorKind::NotFound || error.raw_os_error() == Some(libc::ESRCH)
+    };
 
     // FIXME: To prevent retrieving a task more than once (it can be listed in `/proc/[PID]/task`
     // subfolder and directly in `/proc` at the same time), might be interesting to use a `HashSet`.
@@ -771,7 +1368,7 @@ pub(crate) fn refresh_procs(
             Ok(d) => d,
             Err(_err) => {
                 sysinfo_debug!("Failed to read folder {path:?}: {_err:?}");
-                return 0;
+                return (0, Vec::new());
             }
         };
         let proc_list = Wrap(UnsafeCell::new(proc_list));
@@ -780,13 +1377,20 @@ pub(crate) fn refresh_procs(
             .map(|entry| {
                 let Ok(entry) = entry else { return Vec::new() };
                 let mut entries = Vec::new();
-                get_all_pid_entries(None, None, entry, &mut entries, refresh_kind.tasks());
+                get_all_pid_entries(
+                    None,
+                    None,
+                    entry,
+                    &mut entries,
+                    refresh_kind.tasks(),
+                    refresh_kind.task_names(),
+                );
                 entries
             })
             .flatten()
             .filter(|e| filter_callback(e, filter))
             .filter_map(|e| {
-                let (mut p, _) = _get_process_data(
+                let (mut p, _) = match _get_process_data(
                     e.path.as_path(),
                     proc_list.get(),
                     e.pid,
@@ -794,20 +1398,36 @@ pub(crate) fn refresh_procs(
                     uptime,
                     info,
                     refresh_kind,
-                )
-                .ok()?;
+                    &tcp_tables,
+                ) {
+                    Ok(data) => data,
+                    Err(error) if filter.is_empty() && is_gone(&error) => return None,
+                    Err(error) => return Some(Err((e.pid, error))),
+                };
                 nb_updated.fetch_add(1, Ordering::Relaxed);
                 if let Some(ref mut p) = p {
                     p.inner.tasks = e.tasks;
+                    p.inner.task_names = e.task_names;
+                } else if refresh_kind.task_names() {
+                    // Each entry is only handled once, so no other thread accesses this process.
+                    if let Some(entry) = proc_list.get().get_mut(&e.pid) {
+                        entry.inner.task_names = e.task_names;
+                    }
                 }
-                p
+                p.map(Ok)
             })
             .collect::<Vec<_>>()
     };
+    let mut errors = Vec::new();
     for proc_ in procs {
-        proc_list.insert(proc_.pid(), proc_);
+        match proc_ {
+            Ok(proc_) => {
+                proc_list.insert(proc_.pid(), proc_);
+            }
+            Err(error) => errors.push(error),
+        }
     }
-    nb_updated.into_inner()
+    (nb_updated.into_inner(), errors)
 }
 
 // FIXME: To be removed once MSRV for this crate is 1.80 nd use the `trim_ascii()` method instead.
@@ -858,6 +1478,141 @@ fn copy_from_file(entry: &Path) -> Vec<OsString> {
     }
 }
 
+/// Lists the file descriptors in `/proc/[pid]/fd` and resolves where they point to. The inodes
+/// of the sockets are returned as well.
+fn get_open_files(fd_dir: &Path) -> Option<(Vec<OpenFile>, HashSet<u64>)> {
+    let entries = match fs::read_dir(fd_dir) {
+        Ok(entries) => entries,
+        Err(_e) => {
+            sysinfo_debug!("Failed to read {fd_dir:?}: {_e:?}");
+            return None;
+        }
+    };
+    let mut socket_inodes = HashSet::new();
+    let mut open_files = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let fd = entry.file_name().to_str()?.parse().ok()?;
+            // The file descriptor might have been closed in the meantime.
+            let target = fs::read_link(entry.path()).ok()?;
+            let (kind, path) = match target.to_str() {
+                Some(target) if target.starts_with("socket:[") => {
+                    if let Some(inode) = target
+                        .strip_prefix("socket:[")
+                        .and_then(|inode| inode.strip_suffix(']'))
+                        .and_then(|inode| inode.parse().ok())
+                    {
+                        socket_inodes.insert(inode);
+                    }
+                    (FdKind::Socket, None)
+                }
+                Some(target) if target.starts_with("pipe:[") => (FdKind::Pipe, None),
+                Some(target) if target.starts_with("anon_inode:") => (FdKind::Anon, None),
+                _ => {
+                    // `metadata` follows the link so we get the type of the opened file.
+                    let kind = match fs::metadata(entry.path()) {
+                        Ok(metadata) if metadata.file_type().is_char_device() => FdKind::Char,
+                        Ok(metadata) if metadata.file_type().is_fifo() => FdKind::Pipe,
+                        _ => FdKind::File,
+                    };
+                    (kind, Some(target))
+                }
+            };
+            Some(OpenFile { fd, kind, path })
+        })
+        .collect::<Vec<_>>();
+    open_files.sort_unstable_by_key(|file| file.fd);
+    Some((open_files, socket_inodes))
+}
+
+fn parse_autogroup(data: &str) -> Option<AutogroupInfo> {
+    // The format is `/autogroup-<id> nice <nice>`.
+    let (id, nice) = data
+        .trim()
+        .strip_prefix("/autogroup-")?
+        .split_once(" nice ")?;
+    Some(AutogroupInfo {
+        id: u64::from_str(id).ok()?,
+        nice: i32::from_str(nice).ok()?,
+    })
+}
+
+/// State of each TCP socket (by inode) of a network namespace.
+type TcpTable = HashMap<u64, u8>;
+
+/// TCP sockets tables of each network namespace (by inode), so they are only read once per
+/// refresh instead of once per process.
+#[derive(Default)]
+pub(crate) struct TcpTables(Mutex<HashMap<u64, Arc<TcpTable>>>);
+
+impl TcpTables {
+    fn get(&self, proc_path: &Path) -> Arc<TcpTable> {
+        use std::os::unix::fs::MetadataExt;
+
+        let Ok(netns) = fs::metadata(Path::join(proc_path, "ns/net")).map(|m| m.ino()) else {
+            // We can't know the network namespace, so we don't cache it.
+            return Arc::new(read_tcp_table(proc_path));
+        };
+        // The lock is kept while reading the table so other processes in the same namespace wait
+        // for it instead of reading it as well.
+        let mut tables = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        tables
+            .entry(netns)
+            .or_insert_with(|| Arc::new(read_tcp_table(proc_path)))
+            .clone()
+    }
+}
+
+/// Reads the TCP sockets of the network namespace of the process.
+fn read_tcp_table(proc_path: &Path) -> TcpTable {
+    let mut table = TcpTable::new();
+    for file in ["net/tcp", "net/tcp6"] {
+        if let Ok(content) = fs::read_to_string(Path::join(proc_path, file)) {
+            parse_tcp_table(&content, &mut table);
+        }
+    }
+    table
+}
+
+fn parse_tcp_table(content: &str, table: &mut TcpTable) {
+    // The first line is the header. Then the 4th field is the state and the 10th is the inode.
+    for line in content.lines().skip(1) {
+        let mut fields = line.split_whitespace();
+        let (Some(state), Some(inode)) = (fields.nth(3), fields.nth(5)) else {
+            continue;
+        };
+        if let (Ok(state), Ok(inode)) = (u8::from_str_radix(state, 16), inode.parse::<u64>()) {
+            table.insert(inode, state);
+        }
+    }
+}
+
+/// Counts the TCP sockets of the process by state, using the socket inodes retrieved from
+/// `/proc/[pid]/fd`.
+fn get_socket_summary(
+    proc_path: &Path,
+    socket_inodes: &HashSet<u64>,
+    tcp_tables: &TcpTables,
+) -> SocketSummary {
+    if socket_inodes.is_empty() {
+        return SocketSummary::default();
+    }
+    count_tcp_sockets(&tcp_tables.get(proc_path), socket_inodes)
+}
+
+fn count_tcp_sockets(table: &TcpTable, socket_inodes: &HashSet<u64>) -> SocketSummary {
+    let mut summary = SocketSummary::default();
+    for state in socket_inodes.iter().filter_map(|inode| table.get(inode)) {
+        match state {
+            0x01 => summary.established += 1,
+            0x06 => summary.time_wait += 1,
+            0x0A => summary.listening += 1,
+            _ => {}
+        }
+    }
+    summary
+}
+
 // Fetch tuples of real and effective UID and GID.
 fn get_uid_and_gid(file_path: &Path) -> Option<((uid_t, uid_t), (gid_t, gid_t))> {
     let status_data = get_all_utf8_data(file_path, 16_385).ok()?;
@@ -910,6 +1665,20 @@ struct Parts<'a> {
     short_exe: &'a [u8],
 }
 
+/// Reads the `RssAnon`, `RssFile` and `RssShmem` fields (in kB) of a `/proc/[pid]/status` file.
+fn parse_rss_breakdown(data: &str) -> Option<RssBreakdown> {
+    let field = |name: &str| -> Option<u64> {
+        let value = data.lines().find_map(|line| line.strip_prefix(name))?;
+        let value = value.trim().trim_end_matches("kB").trim_end();
+        value.parse::<u64>().ok().map(|v| v.saturating_mul(1024))
+    };
+    Some(RssBreakdown {
+        anonymous: field("RssAnon:")?,
+        file_backed: field("RssFile:")?,
+        shmem: field("RssShmem:")?,
+    })
+}
+
 fn parse_stat_file(data: &[u8]) -> Option<Parts<'_>> {
     // The stat file is "interesting" to parse, because spaces cannot
     // be used as delimiters. The second field stores the command name
@@ -970,3 +1739,68 @@ impl Drop for FileCounter {
         remaining_files().fetch_add(1, Ordering::Relaxed);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{count_tcp_sockets, parse_autogroup, parse_cgroups, parse_tcp_table, TcpTable};
+    use crate::{AutogroupInfo, CGroupMembership, SocketSummary};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_parse_cgroups() {
+        assert_eq!(
+            parse_cgroups(
+                "12:cpu,cpuacct:/docker/abc\n1:name=systemd:/init.scope\n0::/user.slice\n"
+            ),
+            vec![
+                CGroupMembership {
+                    controllers: vec!["cpu".to_owned(), "cpuacct".to_owned()],
+                    path: "/docker/abc".to_owned(),
+                },
+                CGroupMembership {
+                    controllers: vec!["name=systemd".to_owned()],
+                    path: "/init.scope".to_owned(),
+                },
+                CGroupMembership {
+                    controllers: Vec::new(),
+                    path: "/user.slice".to_owned(),
+                },
+            ],
+        );
+        assert_eq!(parse_cgroups("invalid\n"), Vec::new());
+    }
+
+    #[test]
+    fn test_count_tcp_sockets() {
+        let content = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 101 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:1F90 0100007F:D0A2 01 00000000:00000000 00:00000000 00000000  1000        0 102 1 0000000000000000 20 4 30 10 -1
+   2: 0100007F:D0A2 0100007F:1F90 01 00000000:00000000 00:00000000 00000000  1000        0 103 1 0000000000000000 20 4 30 10 -1
+   3: 0100007F:D0A4 0100007F:1F90 06 00000000:00000000 03:00001770 00000000     0        0 104 0 0000000000000000
+   4: 0100007F:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 200 1 0000000000000000 100 0 0 10 0
+";
+        let mut table = TcpTable::new();
+        parse_tcp_table(content, &mut table);
+        assert_eq!(table.len(), 5);
+        let inodes = HashSet::from([101, 102, 103, 104, 300]);
+        assert_eq!(
+            count_tcp_sockets(&table, &inodes),
+            SocketSummary {
+                listening: 1,
+                established: 2,
+                time_wait: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_autogroup() {
+        assert_eq!(
+            parse_autogroup("/autogroup-835 nice -5\n"),
+            Some(AutogroupInfo { id: 835, nice: -5 }),
+        );
+        assert_eq!(parse_autogroup("/autogroup-835"), None);
+        assert_eq!(parse_autogroup(""), None);
+    }
+}
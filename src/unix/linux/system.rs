@@ -1,20 +1,22 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::cpu::{get_physical_core_count, CpusWrapper};
-use crate::sys::process::{compute_cpu_usage, refresh_procs};
+use crate::sys::process::{cgroup_cpu_quota, compute_cpu_usage, read_cgroups, refresh_procs};
 use crate::sys::utils::{get_all_utf8_data, to_u64};
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    Cpu, CpuRefreshKind, KernelStats, LoadAvg, MemoryInfo, MemoryRefreshKind, Pid, Pressure,
+    PressureStats, Process, ProcessRefreshKind, ProcessesToUpdate, SwapActivity, ThpMode,
+    ThpStatus, TpmInfo, TpmVersion,
 };
 
 use libc::{self, c_char, sysconf, _SC_CLK_TCK, _SC_HOST_NAME_MAX, _SC_PAGESIZE};
 
-use std::cmp::min;
+use std::cmp::{min, Ordering};
 use std::collections::HashMap;
-use std::ffi::CStr;
+use std::ffi::{CStr, OsStr, OsString};
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::{atomic::AtomicIsize, OnceLock};
@@ -151,8 +153,9 @@ impl SystemInfo {
     }
 }
 
-pub(crate) struct SystemInner {
-    process_list: HashMap<Pid, Process>,
+/// Values read from `/proc/meminfo`, in bytes.
+#[derive(Default)]
+struct MemInfo {
     mem_total: u64,
     mem_free: u64,
     mem_available: u64,
@@ -162,8 +165,53 @@ pub(crate) struct SystemInner {
     mem_slab_reclaimable: u64,
     swap_total: u64,
     swap_free: u64,
+}
+
+impl MemInfo {
+    fn refresh(&mut self) {
+        let mut mem_available_found = false;
+        read_table("/proc/meminfo", ':', |key, value_kib| {
+            let field = match key {
+                "MemTotal" => &mut self.mem_total,
+                "MemFree" => &mut self.mem_free,
+                "MemAvailable" => {
+                    mem_available_found = true;
+                    &mut self.mem_available
+                }
+                "Buffers" => &mut self.mem_buffers,
+                "Cached" => &mut self.mem_page_cache,
+                "Shmem" => &mut self.mem_shmem,
+                "SReclaimable" => &mut self.mem_slab_reclaimable,
+                "SwapTotal" => &mut self.swap_total,
+                "SwapFree" => &mut self.swap_free,
+                _ => return,
+            };
+            // /proc/meminfo reports KiB, though it says "kB". Convert it.
+            *field = value_kib.saturating_mul(1_024);
+        });
+
+        // Linux < 3.14 may not have MemAvailable in /proc/meminfo
+        // So it should fallback to the old way of estimating available memory
+        // https://github.com/KittyKatt/screenFetch/issues/386#issuecomment-249312716
+        if !mem_available_found {
+            self.mem_available = self
+                .mem_free
+                .saturating_add(self.mem_buffers)
+                .saturating_add(self.mem_page_cache)
+                .saturating_add(self.mem_slab_reclaimable)
+                .saturating_sub(self.mem_shmem);
+        }
+    }
+}
+
+pub(crate) struct SystemInner {
+    process_list: HashMap<Pid, Process>,
+    mem: MemInfo,
+    swap_activity: Option<SwapActivity>,
+    old_swap_activity: Option<SwapActivity>,
     info: SystemInfo,
     cpus: CpusWrapper,
+    physical_core_count: Option<Option<usize>>,
 }
 
 impl SystemInner {
@@ -190,10 +238,43 @@ impl SystemInner {
         let (new, old) = self.cpus.get_global_raw_times();
         let total_time = if old > new { 1 } else { new - old };
         let total_time = total_time as f32 / self.cpus.len() as f32;
+        let cpu_count = self.cpus.len();
+        let global_cpu_time = new / cpu_count as u64;
         let max_value = self.get_max_process_cpu_usage();
 
         for proc_ in self.process_list.values_mut() {
             compute_cpu_usage(&mut proc_.inner, total_time, max_value);
+            if proc_.inner.updated {
+                proc_.inner.global_cpu_time = global_cpu_time;
+                proc_.inner.cpu_count = cpu_count;
+            }
+        }
+        self.update_hottest_tasks();
+    }
+
+    /// Now that the CPU usage of all tasks is computed, stores the one with the highest CPU usage
+    /// in their process.
+    fn update_hottest_tasks(&mut self) {
+        let hottest_tasks = self
+            .process_list
+            .iter()
+            .filter_map(|(pid, proc_)| {
+                let hottest = proc_
+                    .inner
+                    .tasks
+                    .as_ref()?
+                    .iter()
+                    .filter_map(|tid| Some((*tid, self.process_list.get(tid)?.cpu_usage())))
+                    .filter(|(_, cpu_usage)| *cpu_usage > 0.)
+                    .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(tid, _)| tid);
+                Some((*pid, hottest))
+            })
+            .collect::<Vec<_>>();
+        for (pid, hottest) in hottest_tasks {
+            if let Some(proc_) = self.process_list.get_mut(&pid) {
+                proc_.inner.hottest_task = hottest;
+            }
         }
     }
 
@@ -206,17 +287,12 @@ impl SystemInner {
     pub(crate) fn new() -> Self {
         Self {
             process_list: HashMap::new(),
-            mem_total: 0,
-            mem_free: 0,
-            mem_available: 0,
-            mem_buffers: 0,
-            mem_page_cache: 0,
-            mem_shmem: 0,
-            mem_slab_reclaimable: 0,
-            swap_total: 0,
-            swap_free: 0,
+            mem: MemInfo::default(),
+            swap_activity: None,
+            old_swap_activity: None,
             cpus: CpusWrapper::new(),
             info: SystemInfo::new(),
+            physical_core_count: None,
         }
     }
 
@@ -224,40 +300,37 @@ impl SystemInner {
         if !refresh_kind.ram() && !refresh_kind.swap() {
             return;
         }
-        let mut mem_available_found = false;
-        read_table("/proc/meminfo", ':', |key, value_kib| {
-            let field = match key {
-                "MemTotal" => &mut self.mem_total,
-                "MemFree" => &mut self.mem_free,
-                "MemAvailable" => {
-                    mem_available_found = true;
-                    &mut self.mem_available
-                }
-                "Buffers" => &mut self.mem_buffers,
-                "Cached" => &mut self.mem_page_cache,
-                "Shmem" => &mut self.mem_shmem,
-                "SReclaimable" => &mut self.mem_slab_reclaimable,
-                "SwapTotal" => &mut self.swap_total,
-                "SwapFree" => &mut self.swap_free,
-                _ => return,
-            };
-            // /proc/meminfo reports KiB, though it says "kB". Convert it.
-            *field = value_kib.saturating_mul(1_024);
-        });
-
-        // Linux < 3.14 may not have MemAvailable in /proc/meminfo
-        // So it should fallback to the old way of estimating available memory
-        // https://github.com/KittyKatt/screenFetch/issues/386#issuecomment-249312716
-        if !mem_available_found {
-            self.mem_available = self
-                .mem_free
-                .saturating_add(self.mem_buffers)
-                .saturating_add(self.mem_page_cache)
-                .saturating_add(self.mem_slab_reclaimable)
-                .saturating_sub(self.mem_shmem);
+        self.mem.refresh();
+
+        if refresh_kind.swap() {
+            let mut pages_in = None;
+            let mut pages_out = None;
+            read_table("/proc/vmstat", ' ', |key, value| match key {
+                "pswpin" => pages_in = Some(value),
+                "pswpout" => pages_out = Some(value),
+                _ => {}
+            });
+            self.old_swap_activity = self.swap_activity;
+            self.swap_activity =
+                pages_in
+                    .zip(pages_out)
+                    .map(|(pages_in, pages_out)| SwapActivity {
+                        pages_in,
+                        pages_out,
+                    });
         }
     }
 
+    pub(crate) fn swap_activity(&self) -> Option<SwapActivity> {
+        self.swap_activity
+    }
+
+    pub(crate) fn is_swapping(&self) -> Option<bool> {
+        let old = self.old_swap_activity?;
+        let new = self.swap_activity?;
+        Some(new.pages_in > old.pages_in || new.pages_out > old.pages_out)
+    }
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         crate::CGroupLimits::new(self)
     }
@@ -266,13 +339,30 @@ impl SystemInner {
         self.refresh_cpus(false, refresh_kind);
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.refresh_cpus(true, CpuRefreshKind::nothing().with_cpu_usage());
+    }
+
+    pub(crate) fn refresh_cpu(&mut self, index: usize, refresh_kind: CpuRefreshKind) -> bool {
+        self.cpus.refresh_cpu(index, refresh_kind)
+    }
+
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
         refresh_kind: ProcessRefreshKind,
     ) -> usize {
+        self.refresh_processes_specifics_checked(processes_to_update, refresh_kind)
+            .0
+    }
+
+    pub(crate) fn refresh_processes_specifics_checked(
+        &mut self,
+        processes_to_update: ProcessesToUpdate<'_>,
+        refresh_kind: ProcessRefreshKind,
+    ) -> (usize, Vec<(Pid, io::Error)>) {
         let uptime = Self::uptime();
-        let nb_updated = refresh_procs(
+        let (nb_updated, errors) = refresh_procs(
             &mut self.process_list,
             Path::new("/proc"),
             uptime,
@@ -281,7 +371,7 @@ impl SystemInner {
             refresh_kind,
         );
         self.update_procs_cpu(refresh_kind);
-        nb_updated
+        (nb_updated, errors)
     }
 
     // COMMON PART
@@ -308,33 +398,102 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn runnable_process_count(&self) -> Option<u32> {
+        self.cpus.procs_running
+    }
+
+    pub(crate) fn blocked_process_count(&self) -> Option<u32> {
+        self.cpus.procs_blocked
+    }
+
+    pub(crate) fn is_hybrid_cpu(&self) -> bool {
+        self.cpus.is_hybrid()
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
-        self.mem_total
+        self.mem.mem_total
     }
 
     pub(crate) fn free_memory(&self) -> u64 {
-        self.mem_free
+        self.mem.mem_free
     }
 
     pub(crate) fn available_memory(&self) -> u64 {
-        self.mem_available
+        self.mem.mem_available
     }
 
     pub(crate) fn used_memory(&self) -> u64 {
-        self.mem_total - self.mem_available
+        self.mem.mem_total - self.mem.mem_available
     }
 
     pub(crate) fn total_swap(&self) -> u64 {
-        self.swap_total
+        self.mem.swap_total
     }
 
     pub(crate) fn free_swap(&self) -> u64 {
-        self.swap_free
+        self.mem.swap_free
     }
 
     // need to be checked
     pub(crate) fn used_swap(&self) -> u64 {
-        self.swap_total - self.swap_free
+        self.mem.swap_total - self.mem.swap_free
+    }
+
+    pub(crate) fn effective_swap_capacity(&self) -> u64 {
+        let Ok(swaps) = get_all_utf8_data("/proc/swaps", 4096) else {
+            return self.total_swap();
+        };
+        let mut zram_devices = Vec::new();
+        // The first line is the header, then each line is `<filename> <type> <size in KiB> ...`.
+        for line in swaps.lines().skip(1) {
+            let mut fields = line.split_whitespace();
+            let (Some(filename), Some(size)) = (fields.next(), fields.nth(1)) else {
+                continue;
+            };
+            let Some(device) = filename
+                .strip_prefix("/dev/")
+                .filter(|d| d.starts_with("zram"))
+            else {
+                continue;
+            };
+            let Ok(size) = u64::from_str(size) else {
+                continue;
+            };
+            let block = Path::new("/sys/block").join(device);
+            let (Some(disksize), Ok(mm_stat)) = (
+                get_all_utf8_data(block.join("disksize"), 32)
+                    .ok()
+                    .and_then(|d| u64::from_str(d.trim()).ok()),
+                get_all_utf8_data(block.join("mm_stat"), 256),
+            ) else {
+                continue;
+            };
+            zram_devices.push((size, disksize, mm_stat));
+        }
+        // All the zram devices store their compressed data in the same available memory.
+        let available_memory = self.available_memory() / zram_devices.len().max(1) as u64;
+        let mut capacity = self.total_swap();
+        for (size, disksize, mm_stat) in zram_devices {
+            if let Some(effective) = zram_effective_capacity(disksize, &mm_stat, available_memory) {
+                capacity = capacity
+                    .saturating_sub(size * 1024)
+                    .saturating_add(effective);
+            }
+        }
+        capacity
+    }
+
+    pub(crate) fn memory_info() -> MemoryInfo {
+        let mut mem = MemInfo::default();
+        mem.refresh();
+        MemoryInfo {
+            total: mem.mem_total,
+            free: mem.mem_free,
+            available: mem.mem_available,
+            used: mem.mem_total.saturating_sub(mem.mem_available),
+            total_swap: mem.swap_total,
+            free_swap: mem.swap_free,
+        }
     }
 
     pub(crate) fn uptime() -> u64 {
@@ -371,6 +530,140 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn open_file_descriptors() -> Option<(u64, u64)> {
+        // The first field of `file-nr` is the number of allocated file handles (the second one
+        // is always 0 since Linux 2.6 and the third one is the same as `file-max`).
+        let allocated = get_all_utf8_data("/proc/sys/fs/file-nr", 100)
+            .ok()?
+            .split_whitespace()
+            .next()
+            .and_then(|v| u64::from_str(v).ok())?;
+        let max = read_u64("/proc/sys/fs/file-max")?;
+        Some((allocated, max))
+    }
+
+    pub(crate) fn tpm() -> Option<TpmInfo> {
+        let dir = Path::new("/sys/class/tpm/tpm0");
+        if !dir.exists() {
+            return Some(TpmInfo {
+                version: TpmVersion::Unknown,
+                present: false,
+            });
+        }
+        // `tpm_version_major` is only available since Linux 5.6.
+        let version = match get_all_utf8_data(dir.join("tpm_version_major"), 8)
+            .ok()
+            .as_deref()
+            .map(str::trim)
+        {
+            Some("1") => TpmVersion::V1,
+            Some("2") => TpmVersion::V2,
+            _ => TpmVersion::Unknown,
+        };
+        Some(TpmInfo {
+            version,
+            present: true,
+        })
+    }
+
+    pub(crate) fn active_desktop_user() -> Option<String> {
+        use std::os::unix::fs::MetadataExt;
+
+        // We first look for an active graphical session in the logind sessions.
+        if let Ok(dir) = std::fs::read_dir("/run/systemd/sessions") {
+            for entry in dir.flatten() {
+                // The directory also contains `.ref` FIFOs.
+                if !entry.file_type().is_ok_and(|file_type| file_type.is_file()) {
+                    continue;
+                }
+                if let Some(user) = get_all_utf8_data(entry.path(), 1024)
+                    .ok()
+                    .and_then(|content| active_graphical_session_user(&content))
+                {
+                    return Some(user);
+                }
+            }
+        }
+        // Otherwise, we use the owner of the socket of the `:0` X display, unless it's a system
+        // user (like `root` or the one of the display manager showing the login screen).
+        let uid = std::fs::metadata("/tmp/.X11-unix/X0").ok()?.uid();
+        if crate::unix::utils::is_system_user(uid) {
+            return None;
+        }
+        crate::unix::utils::user_name(uid)
+    }
+
+    pub(crate) fn pid_max() -> Option<u32> {
+        read_u64("/proc/sys/kernel/pid_max").and_then(|v| u32::try_from(v).ok())
+    }
+
+    pub(crate) fn available_entropy() -> Option<u32> {
+        read_u64("/proc/sys/kernel/random/entropy_avail").and_then(|v| u32::try_from(v).ok())
+    }
+
+    pub(crate) fn init_process_name() -> Option<OsString> {
+        if let Ok(mut comm) = std::fs::read("/proc/1/comm") {
+            if comm.last() == Some(&b'\n') {
+                comm.pop();
+            }
+            if !comm.is_empty() {
+                return Some(OsString::from_vec(comm));
+            }
+        }
+        let cmdline = std::fs::read("/proc/1/cmdline").ok()?;
+        let arg0 = cmdline.split(|c| *c == 0).next()?;
+        Path::new(OsStr::from_bytes(arg0))
+            .file_name()
+            .map(|name| name.to_os_string())
+    }
+
+    pub(crate) fn kernel_stats() -> Option<KernelStats> {
+        let mut s = String::new();
+        File::open("/proc/stat")
+            .and_then(|mut f| f.read_to_string(&mut s))
+            .ok()?;
+        parse_kernel_stats(&s)
+    }
+
+    pub(crate) fn pressure() -> Option<Pressure> {
+        let read = |resource: &str| {
+            get_all_utf8_data(format!("/proc/pressure/{resource}"), 256)
+                .ok()
+                .and_then(|data| parse_pressure(&data))
+        };
+        Some(Pressure {
+            cpu: read("cpu")?,
+            memory: read("memory")?,
+            io: read("io")?,
+        })
+    }
+
+    pub(crate) fn kernel_cmdline() -> Option<String> {
+        get_all_utf8_data("/proc/cmdline", 4096)
+            .ok()
+            .map(|cmdline| cmdline.trim_end().to_owned())
+    }
+
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        // Without EFI, there is no secure boot.
+        if !Path::new("/sys/firmware/efi").exists() {
+            return None;
+        }
+        let efivars = Path::new("/sys/firmware/efi/efivars");
+        // The variable content starts with 4 bytes of attributes, followed by the value.
+        match std::fs::read(efivars.join("SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c")) {
+            Ok(data) => data.get(4).map(|&value| value == 1),
+            // The variable is only known to be absent if `efivarfs` is mounted, in which case it
+            // lists the other EFI variables.
+            Err(error) if error.kind() == io::ErrorKind::NotFound => std::fs::read_dir(efivars)
+                .ok()?
+                .next()
+                .is_some()
+                .then_some(false),
+            Err(_) => None,
+        }
+    }
+
     #[cfg(not(target_os = "android"))]
     pub(crate) fn name() -> Option<String> {
         get_system_info_linux(
@@ -445,6 +738,10 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn fqdn() -> Option<String> {
+        crate::unix::utils::fqdn(&Self::host_name()?)
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         let mut raw = std::mem::MaybeUninit::<libc::utsname>::zeroed();
 
@@ -541,12 +838,319 @@ impl SystemInner {
         get_physical_core_count()
     }
 
+    pub(crate) fn physical_core_count_cached(&mut self) -> Option<usize> {
+        *self
+            .physical_core_count
+            .get_or_insert_with(Self::physical_core_count)
+    }
+
+    pub(crate) fn smt_enabled() -> Option<bool> {
+        match get_all_utf8_data("/sys/devices/system/cpu/smt/active", 8)
+            .ok()?
+            .trim()
+        {
+            "1" => Some(true),
+            "0" => Some(false),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn reboot_required() -> Option<bool> {
+        // Created by Debian and Ubuntu when an installed package requires a reboot.
+        if Path::new("/run/reboot-required").exists()
+            || Path::new("/var/run/reboot-required").exists()
+        {
+            return Some(true);
+        }
+        newer_kernel_installed(Path::new("/boot"), &Self::kernel_version()?)
+    }
+
+    pub(crate) fn transparent_huge_pages() -> Option<ThpStatus> {
+        let read_mode = |file: &str| {
+            let path = Path::new("/sys/kernel/mm/transparent_hugepage").join(file);
+            parse_thp_mode(&get_all_utf8_data(path, 128).ok()?)
+        };
+        Some(ThpStatus {
+            enabled: read_mode("enabled")?,
+            defrag: read_mode("defrag")?,
+        })
+    }
+
+    pub(crate) fn available_parallelism() -> usize {
+        let mut count = unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            if libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) == 0 {
+                libc::CPU_COUNT(&set) as usize
+            } else {
+                sysinfo_debug!("`sched_getaffinity` failed");
+                0
+            }
+        };
+        if count == 0 {
+            count = std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1);
+        }
+        // The quota is rounded up to the number of CPUs needed to use it.
+        if let Some(quota) = cgroup_cpu_quota(Path::new("/proc/self")) {
+            count = count.min(quota.ceil() as usize);
+        }
+        count.max(1)
+    }
+
+    pub(crate) fn cpuset_cpus() -> Option<Vec<usize>> {
+        let cpus = read_cgroup_cpuset()?;
+        let online = get_all_utf8_data("/sys/devices/system/cpu/online", 64)
+            .ok()
+            .and_then(|list| parse_cpu_list(&list));
+        // If the cpuset contains all the online CPUs, there is no restriction.
+        if online.as_ref() == Some(&cpus) {
+            return None;
+        }
+        Some(cpus)
+    }
+
+    pub(crate) fn online_cpu_count() -> usize {
+        get_all_utf8_data("/sys/devices/system/cpu/online", 64)
+            .ok()
+            .and_then(|list| count_cpus_in_list(&list))
+            .unwrap_or_else(|| unsafe { sysconf(libc::_SC_NPROCESSORS_ONLN) }.max(1) as usize)
+    }
+
+    pub(crate) fn possible_cpu_count() -> usize {
+        get_all_utf8_data("/sys/devices/system/cpu/possible", 64)
+            .ok()
+            .and_then(|list| count_cpus_in_list(&list))
+            .unwrap_or_else(|| unsafe { sysconf(libc::_SC_NPROCESSORS_CONF) }.max(1) as usize)
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus = CpusWrapper::new();
         self.refresh_cpu_specifics(refresh_kind);
     }
 }
 
+/// Parses the kernel counters from the content of `/proc/stat`.
+fn parse_kernel_stats(data: &str) -> Option<KernelStats> {
+    let mut interrupts = None;
+    let mut context_switches = None;
+    let mut processes_forked = None;
+    let mut procs_running = None;
+    let mut procs_blocked = None;
+    for line in data.lines() {
+        let mut parts = line.split_whitespace();
+        let field = match parts.next() {
+            Some("intr") => &mut interrupts,
+            Some("ctxt") => &mut context_switches,
+            Some("processes") => &mut processes_forked,
+            Some("procs_running") => &mut procs_running,
+            Some("procs_blocked") => &mut procs_blocked,
+            _ => continue,
+        };
+        // For `intr`, the first value is the total, followed by the per-interrupt counts.
+        *field = parts.next().and_then(|v| u64::from_str(v).ok());
+    }
+    Some(KernelStats {
+        interrupts: interrupts?,
+        context_switches: context_switches?,
+        processes_forked: processes_forked?,
+        procs_running: procs_running?,
+        procs_blocked: procs_blocked?,
+    })
+}
+
+/// Parses a `/proc/pressure/*` file, which looks like:
+///
+/// ```text
+/// some avg10=0.12 avg60=0.05 avg300=0.01 total=123456
+/// full avg10=0.00 avg60=0.00 avg300=0.00 total=2345
+/// ```
+fn parse_pressure(data: &str) -> Option<PressureStats> {
+    let mut stats = PressureStats::default();
+    let mut found = false;
+    for line in data.lines() {
+        let mut parts = line.split_whitespace();
+        let (avg10, avg60) = match parts.next() {
+            Some("some") => {
+                found = true;
+                (&mut stats.some_avg10, &mut stats.some_avg60)
+            }
+            Some("full") => (&mut stats.full_avg10, &mut stats.full_avg60),
+            _ => continue,
+        };
+        for part in parts {
+            match part.split_once('=') {
+                Some(("avg10", value)) => *avg10 = f32::from_str(value).ok()?,
+                Some(("avg60", value)) => *avg60 = f32::from_str(value).ok()?,
+                _ => {}
+            }
+        }
+    }
+    found.then_some(stats)
+}
+
+/// Returns `true` if the newest `vmlinuz-[version]` kernel in `boot_dir` isn't the `running` one.
+///
+/// Returns `None` if the running kernel isn't in `boot_dir`, since we can't know if it's older
+/// than the other ones in this case.
+fn newer_kernel_installed(boot_dir: &Path, running: &str) -> Option<bool> {
+    let mut running_found = false;
+    let mut newest: Option<String> = None;
+    for entry in std::fs::read_dir(boot_dir).ok()?.flatten() {
+        let file_name = entry.file_name();
+        let Some(version) = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix("vmlinuz-"))
+        else {
+            continue;
+        };
+        // Rescue kernels (like `vmlinuz-0-rescue-[machine-id]`) are not real versions.
+        if version.contains("rescue") {
+            continue;
+        }
+        running_found |= version == running;
+        if newest.as_deref().map_or(true, |newest| {
+            compare_versions(version, newest) == Ordering::Greater
+        }) {
+            newest = Some(version.to_owned());
+        }
+    }
+    if !running_found {
+        return None;
+    }
+    newest.map(|newest| newest != running)
+}
+
+/// Compares two version strings, comparing their numbers numerically (so `5.10` is greater than
+/// `5.9`) and the rest alphabetically.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    fn chunks(s: &str) -> impl Iterator<Item = &str> {
+        let mut rest = s;
+        std::iter::from_fn(move || {
+            let first = rest.chars().next()?;
+            let len = rest
+                .find(|c: char| c.is_ascii_digit() != first.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (chunk, remaining) = rest.split_at(len);
+            rest = remaining;
+            Some(chunk)
+        })
+    }
+
+    let mut a = chunks(a);
+    let mut b = chunks(b);
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => match (u64::from_str(a), u64::from_str(b)) {
+                (Ok(a), Ok(b)) => a.cmp(&b),
+                _ => a.cmp(b),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Parses the selected mode (the one between brackets) of a transparent huge pages setting file,
+/// like `always [madvise] never`.
+fn parse_thp_mode(data: &str) -> Option<ThpMode> {
+    let mode = data
+        .split_whitespace()
+        .find_map(|mode| mode.strip_prefix('[')?.strip_suffix(']'))?;
+    match mode {
+        "always" => Some(ThpMode::Always),
+        "madvise" => Some(ThpMode::MadviseOnly),
+        "never" => Some(ThpMode::Never),
+        "defer" => Some(ThpMode::Defer),
+        "defer+madvise" => Some(ThpMode::DeferMadvise),
+        _ => None,
+    }
+}
+
+/// Returns the number of CPUs in a CPU list as found in sysfs, like `0-3,6,8-9`.
+fn count_cpus_in_list(list: &str) -> Option<usize> {
+    parse_cpu_list(list).map(|cpus| cpus.len())
+}
+
+/// Returns the CPUs of a CPU list as found in sysfs, like `0-3,6,8-9`.
+fn parse_cpu_list(list: &str) -> Option<Vec<usize>> {
+    let mut cpus = Vec::new();
+    for range in list.trim().split(',') {
+        match range.split_once('-') {
+            Some((start, end)) => {
+                let start = usize::from_str(start).ok()?;
+                let end = usize::from_str(end).ok()?;
+                if end < start {
+                    return None;
+                }
+                cpus.extend(start..=end);
+            }
+            None => cpus.push(usize::from_str(range).ok()?),
+        }
+    }
+    Some(cpus)
+}
+
+/// Returns the effective CPUs of the cpuset of the cgroup of the current process.
+fn read_cgroup_cpuset() -> Option<Vec<usize>> {
+    let cgroups = read_cgroups(Path::new("/proc/self"))?;
+    let find = |f: fn(&[String]) -> bool| {
+        cgroups
+            .iter()
+            .find(|cgroup| f(&cgroup.controllers))
+            .map(|cgroup| cgroup.path.as_str())
+    };
+    // cgroups v2 first, then cgroups v1.
+    find(|controllers| controllers.is_empty())
+        .and_then(|path| {
+            read_cgroup_cpuset_file(Path::new("/sys/fs/cgroup"), path, "cpuset.cpus.effective")
+        })
+        .or_else(|| {
+            let path = find(|controllers| controllers.iter().any(|c| c == "cpuset"))?;
+            read_cgroup_cpuset_file(
+                Path::new("/sys/fs/cgroup/cpuset"),
+                path,
+                "cpuset.effective_cpus",
+            )
+        })
+}
+
+fn read_cgroup_cpuset_file(root: &Path, path: &str, file: &str) -> Option<Vec<usize>> {
+    // If the cpuset controller isn't enabled in this cgroup, it inherits its parent's cpuset.
+    root.join(path.trim_start_matches('/'))
+        .ancestors()
+        .take_while(|dir| dir.starts_with(root))
+        .find_map(|dir| get_all_utf8_data(dir.join(file), 4096).ok())
+        .and_then(|list| parse_cpu_list(&list))
+}
+
+/// Returns the CPU quota and period of the cgroup directory `dir` if it is limited.
+pub(crate) fn read_cgroup_cpu_quota(dir: &Path) -> Option<(u64, u64)> {
+    let (quota, period) = if let Ok(content) = get_all_utf8_data(dir.join("cpu.max"), 64) {
+        // cgroups v2: the content is "$MAX $PERIOD", `$MAX` being "max" if there is no limit.
+        let mut parts = content.split_whitespace();
+        let quota = u64::from_str(parts.next()?).ok()?;
+        let period = u64::from_str(parts.next()?).ok()?;
+        (quota, period)
+    } else {
+        // cgroups v1: the quota is -1 if there is no limit.
+        let read = |file| {
+            get_all_utf8_data(dir.join(file), 32)
+                .ok()
+                .and_then(|d| u64::from_str(d.trim()).ok())
+        };
+        (read("cpu.cfs_quota_us")?, read("cpu.cfs_period_us")?)
+    };
+    if period == 0 {
+        return None;
+    }
+    Some((quota, period))
+}
+
 fn read_u64(filename: &str) -> Option<u64> {
     get_all_utf8_data(filename, 16_635)
         .ok()
@@ -593,7 +1197,7 @@ fn read_table_key(filename: &str, target_key: &str, colsep: char) -> Option<u64>
 impl crate::CGroupLimits {
     fn new(sys: &SystemInner) -> Option<Self> {
         assert!(
-            sys.mem_total != 0,
+            sys.mem.mem_total != 0,
             "You need to call System::refresh_memory before trying to get cgroup limits!",
         );
         if let (Some(mem_cur), Some(mem_max), Some(mem_rss)) = (
@@ -603,17 +1207,17 @@ impl crate::CGroupLimits {
             read_table_key("/sys/fs/cgroup/memory.stat", "anon", ' '),
         ) {
             let mut limits = Self {
-                total_memory: sys.mem_total,
-                free_memory: sys.mem_free,
-                free_swap: sys.swap_free,
+                total_memory: sys.mem.mem_total,
+                free_memory: sys.mem.mem_free,
+                free_swap: sys.mem.swap_free,
                 rss: mem_rss,
             };
 
-            limits.total_memory = min(mem_max, sys.mem_total);
+            limits.total_memory = min(mem_max, sys.mem.mem_total);
             limits.free_memory = limits.total_memory.saturating_sub(mem_cur);
 
             if let Some(swap_cur) = read_u64("/sys/fs/cgroup/memory.swap.current") {
-                limits.free_swap = sys.swap_total.saturating_sub(swap_cur);
+                limits.free_swap = sys.mem.swap_total.saturating_sub(swap_cur);
             }
 
             Some(limits)
@@ -624,13 +1228,13 @@ impl crate::CGroupLimits {
             read_table_key("/sys/fs/cgroup/memory/memory.stat", "total_rss", ' '),
         ) {
             let mut limits = Self {
-                total_memory: sys.mem_total,
-                free_memory: sys.mem_free,
-                free_swap: sys.swap_free,
+                total_memory: sys.mem.mem_total,
+                free_memory: sys.mem.mem_free,
+                free_swap: sys.mem.swap_free,
                 rss: mem_rss,
             };
 
-            limits.total_memory = min(mem_max, sys.mem_total);
+            limits.total_memory = min(mem_max, sys.mem.mem_total);
             limits.free_memory = limits.total_memory.saturating_sub(mem_cur);
 
             Some(limits)
@@ -752,16 +1356,75 @@ fn get_system_info_android(info: InfoType) -> Option<String> {
     }
 }
 
+/// Computes how much data (uncompressed) a zram device can hold: the data already stored plus
+/// what can still be stored, which is limited by the size of the device and by the memory the
+/// compressed data can still use (its `mem_limit` if set, the available memory otherwise).
+///
+/// `mm_stat` is the content of `/sys/block/zramN/mm_stat`, which starts with
+/// `orig_data_size compr_data_size mem_used_total mem_limit`.
+fn zram_effective_capacity(disksize: u64, mm_stat: &str, available_memory: u64) -> Option<u64> {
+    let mut fields = mm_stat.split_whitespace().map(u64::from_str);
+    let orig_data_size = fields.next()?.ok()?;
+    let compr_data_size = fields.next()?.ok()?;
+    let mem_used_total = fields.next()?.ok()?;
+    let mem_limit = fields.next()?.ok()?;
+
+    let memory_headroom = if mem_limit > 0 {
+        mem_limit
+            .saturating_sub(mem_used_total)
+            .min(available_memory)
+    } else {
+        available_memory
+    };
+    // Until some data is stored, we can't know the compression ratio.
+    let ratio = if compr_data_size > 0 && orig_data_size > compr_data_size {
+        orig_data_size as f64 / compr_data_size as f64
+    } else {
+        1.
+    };
+    let remaining = disksize
+        .saturating_sub(orig_data_size)
+        .min((memory_headroom as f64 * ratio) as u64);
+    Some(orig_data_size.saturating_add(remaining))
+}
+
+/// Returns the user of a logind session (read from `/run/systemd/sessions/<id>`) if it's an
+/// active graphical session.
+fn active_graphical_session_user(content: &str) -> Option<String> {
+    let (mut active, mut graphical, mut user) = (false, false, None);
+    for line in content.lines() {
+        match line.split_once('=') {
+            Some(("ACTIVE", value)) => active = value == "1",
+            Some(("TYPE", value)) => graphical = matches!(value, "x11" | "wayland" | "mir"),
+            Some(("USER", value)) if !value.is_empty() => user = Some(value.to_owned()),
+            _ => {}
+        }
+    }
+    if active && graphical {
+        user
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use super::active_graphical_session_user;
+    use super::count_cpus_in_list;
     #[cfg(target_os = "android")]
     use super::get_system_info_android;
     #[cfg(not(target_os = "android"))]
     use super::get_system_info_linux;
+    use super::parse_cpu_list;
+    use super::parse_kernel_stats;
+    use super::parse_pressure;
+    use super::parse_thp_mode;
     use super::read_table;
     use super::read_table_key;
     use super::system_info_as_list;
+    use super::zram_effective_capacity;
     use super::InfoType;
+    use super::{compare_versions, newer_kernel_installed};
     use std::collections::HashMap;
     use std::io::Write;
     use tempfile::NamedTempFile;
@@ -968,4 +1631,195 @@ DISTRIB_DESCRIPTION="Ubuntu 20.10"
             vec!["rhel".to_string(), "fedora".to_string()],
         );
     }
+
+    #[test]
+    fn test_count_cpus_in_list() {
+        assert_eq!(count_cpus_in_list("0\n"), Some(1));
+        assert_eq!(count_cpus_in_list("0-7\n"), Some(8));
+        assert_eq!(count_cpus_in_list("0-3,6,8-9\n"), Some(7));
+        assert_eq!(count_cpus_in_list(""), None);
+        assert_eq!(count_cpus_in_list("3-1"), None);
+    }
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0\n"), Some(vec![0]));
+        assert_eq!(
+            parse_cpu_list("0-3,6,8-9\n"),
+            Some(vec![0, 1, 2, 3, 6, 8, 9])
+        );
+        assert_eq!(parse_cpu_list(""), None);
+        assert_eq!(parse_cpu_list("3-1"), None);
+    }
+
+    #[test]
+    fn test_parse_kernel_stats() {
+        let data = "\
+cpu  10132153 290696 3084719 46828483 16683 0 25195 0 0 0
+cpu0 1393280 32966 572056 13343292 6130 0 17875 0 0 0
+intr 1462898 42 0 0 7 0 0
+ctxt 115315
+btime 769041601
+processes 86031
+procs_running 2
+procs_blocked 1
+softirq 229245889 94 60001584 13619 5175704 2471304 0 1 0 0 0
+";
+        let stats = parse_kernel_stats(data).unwrap();
+        assert_eq!(stats.interrupts, 1462898);
+        assert_eq!(stats.context_switches, 115315);
+        assert_eq!(stats.processes_forked, 86031);
+        assert_eq!(stats.procs_running, 2);
+        assert_eq!(stats.procs_blocked, 1);
+        assert_eq!(parse_kernel_stats("cpu  1 2 3\n"), None);
+        // All counters are needed.
+        let data = data.replace("procs_running 2\n", "");
+        assert_eq!(parse_kernel_stats(&data), None);
+    }
+
+    #[test]
+    fn test_parse_pressure() {
+        let stats = parse_pressure(
+            "\
+some avg10=1.50 avg60=0.75 avg300=0.10 total=123456
+full avg10=0.50 avg60=0.25 avg300=0.00 total=2345
+",
+        )
+        .expect("failed to parse pressure");
+        assert_eq!(stats.some_avg10, 1.5);
+        assert_eq!(stats.some_avg60, 0.75);
+        assert_eq!(stats.full_avg10, 0.5);
+        assert_eq!(stats.full_avg60, 0.25);
+
+        // The `full` line for CPU only exists since Linux 5.13.
+        let stats = parse_pressure("some avg10=2.00 avg60=1.00 avg300=0.50 total=42\n")
+            .expect("failed to parse pressure");
+        assert_eq!(stats.some_avg10, 2.);
+        assert_eq!(stats.full_avg10, 0.);
+
+        assert_eq!(parse_pressure(""), None);
+        assert_eq!(parse_pressure("some avg10=oops\n"), None);
+    }
+
+    #[test]
+    fn test_parse_thp_mode() {
+        use crate::ThpMode;
+
+        assert_eq!(
+            parse_thp_mode("always [madvise] never\n"),
+            Some(ThpMode::MadviseOnly)
+        );
+        assert_eq!(
+            parse_thp_mode("[always] madvise never\n"),
+            Some(ThpMode::Always)
+        );
+        assert_eq!(
+            parse_thp_mode("always madvise [never]\n"),
+            Some(ThpMode::Never)
+        );
+        assert_eq!(
+            parse_thp_mode("always defer [defer+madvise] madvise never\n"),
+            Some(ThpMode::DeferMadvise)
+        );
+        assert_eq!(parse_thp_mode("always madvise never\n"), None);
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        use std::cmp::Ordering;
+
+        assert_eq!(compare_versions("5.10.0", "5.9.0"), Ordering::Greater);
+        assert_eq!(compare_versions("5.9.0", "5.10.0"), Ordering::Less);
+        assert_eq!(
+            compare_versions(
+                "5.14.0-427.13.1.el9_4.x86_64",
+                "5.14.0-362.8.1.el9_3.x86_64"
+            ),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_versions("6.8.0-45-generic", "6.8.0-45-generic"),
+            Ordering::Equal
+        );
+        assert_eq!(compare_versions("6.8.0", "6.8.0-1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_newer_kernel_installed() {
+        let boot = tempfile::tempdir().expect("failed to create temporary directory");
+        let boot = boot.path();
+        for name in [
+            "vmlinuz-6.8.0-9-generic",
+            "vmlinuz-6.8.0-10-generic",
+            "vmlinuz-0-rescue-0123456789abcdef",
+            "vmlinuz",
+        ] {
+            std::fs::write(boot.join(name), b"").expect("failed to write file");
+        }
+        assert_eq!(
+            newer_kernel_installed(boot, "6.8.0-10-generic"),
+            Some(false)
+        );
+        assert_eq!(newer_kernel_installed(boot, "6.8.0-9-generic"), Some(true));
+        // The running kernel isn't in the boot folder.
+        assert_eq!(newer_kernel_installed(boot, "6.9.0-1-generic"), None);
+    }
+
+    #[test]
+    fn test_active_graphical_session_user() {
+        let session = "\
+# This is private data. Do not parse.
+UID=1000
+USER=alice
+ACTIVE=1
+IS_DISPLAY=1
+STATE=active
+REMOTE=0
+TYPE=wayland
+CLASS=user
+SEAT=seat0
+";
+        assert_eq!(
+            active_graphical_session_user(session).as_deref(),
+            Some("alice")
+        );
+        assert_eq!(
+            active_graphical_session_user(&session.replace("ACTIVE=1", "ACTIVE=0")),
+            None
+        );
+        assert_eq!(
+            active_graphical_session_user(&session.replace("TYPE=wayland", "TYPE=tty")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_zram_effective_capacity() {
+        const GIB: u64 = 1024 * 1024 * 1024;
+
+        // 1 GiB compressed to 256 MiB (ratio of 4), no memory limit and 1 GiB of available
+        // memory: the 8 GiB device is full with 1 + 4 GiB.
+        let mm_stat = format!("{} {} {} 0 0 0 0 0", GIB, GIB / 4, GIB / 4);
+        assert_eq!(
+            zram_effective_capacity(8 * GIB, &mm_stat, GIB),
+            Some(5 * GIB)
+        );
+        // With a lot of available memory, the size of the device is the limit.
+        assert_eq!(
+            zram_effective_capacity(8 * GIB, &mm_stat, 16 * GIB),
+            Some(8 * GIB)
+        );
+        // With a memory limit of 512 MiB, only 256 MiB can still be used.
+        let mm_stat = format!("{} {} {} {} 0 0 0 0", GIB, GIB / 4, GIB / 4, GIB / 2);
+        assert_eq!(
+            zram_effective_capacity(8 * GIB, &mm_stat, 16 * GIB),
+            Some(2 * GIB)
+        );
+        // Nothing stored yet: the compression ratio is unknown.
+        assert_eq!(
+            zram_effective_capacity(8 * GIB, "0 0 0 0 0 0 0 0", GIB),
+            Some(GIB)
+        );
+        assert_eq!(zram_effective_capacity(8 * GIB, "", GIB), None);
+    }
 }
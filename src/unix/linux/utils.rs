@@ -90,7 +90,7 @@ pub(crate) fn to_u64(v: &[u8]) -> u64 {
 }
 
 /// Converts a path to a NUL-terminated `Vec<u8>` suitable for use with C functions.
-#[cfg(feature = "disk")]
+#[cfg(any(feature = "disk", feature = "system"))]
 pub(crate) fn to_cpath(path: &std::path::Path) -> Vec<u8> {
     use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
 
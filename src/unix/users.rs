@@ -63,7 +63,7 @@ pub(crate) unsafe fn get_group_name(
         )) != 0
         {
             // If there was not enough memory, we give it more.
-            if last_errno == libc::ERANGE as _ {
+            if last_errno == libc::ERANGE {
                 // Needs to be updated for `Vec::reserve` to actually add additional capacity.
                 // In here it's "fine" since we never read from `buffer`.
                 buffer.set_len(buffer.capacity());
@@ -113,6 +113,10 @@ pub(crate) unsafe fn get_user_groups(
     }
 }
 
+pub(crate) fn get_current_user_id() -> Option<Uid> {
+    Some(Uid(unsafe { libc::geteuid() }))
+}
+
 // Not used by mac.
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
 pub(crate) fn get_users(users: &mut Vec<User>) {
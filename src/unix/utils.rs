@@ -35,6 +35,58 @@ pub(crate) fn cstr_to_rust_with_size(
     }
 }
 
+/// Returns the canonical name of `host_name`, resolved with `getaddrinfo`.
+#[cfg(feature = "system")]
+pub(crate) fn fqdn(host_name: &str) -> Option<String> {
+    let host_name = std::ffi::CString::new(host_name).ok()?;
+    let mut hints: libc::addrinfo = unsafe { std::mem::zeroed() };
+    hints.ai_family = libc::AF_UNSPEC;
+    hints.ai_socktype = libc::SOCK_DGRAM;
+    hints.ai_flags = libc::AI_CANONNAME;
+    let mut result = std::ptr::null_mut();
+    if unsafe { libc::getaddrinfo(host_name.as_ptr(), std::ptr::null(), &hints, &mut result) } != 0
+        || result.is_null()
+    {
+        return None;
+    }
+    let fqdn = unsafe { cstr_to_rust_with_size((*result).ai_canonname, None) };
+    unsafe { libc::freeaddrinfo(result) };
+    fqdn.filter(|fqdn| !fqdn.is_empty())
+}
+
+/// Returns the name of the user with the given `uid`.
+#[cfg(all(
+    feature = "system",
+    any(target_os = "linux", target_os = "android", target_os = "macos")
+))]
+pub(crate) fn user_name(uid: libc::uid_t) -> Option<String> {
+    let mut pwd = std::mem::MaybeUninit::<libc::passwd>::uninit();
+    let mut buffer = vec![0 as libc::c_char; 2048];
+    let mut result = std::ptr::null_mut();
+    loop {
+        let ret = unsafe {
+            libc::getpwuid_r(
+                uid,
+                pwd.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut result,
+            )
+        };
+        // If the buffer is too small, we give it more room.
+        if ret == libc::ERANGE {
+            buffer.resize(buffer.len() * 2, 0);
+            continue;
+        }
+        if ret != 0 || result.is_null() {
+            return None;
+        }
+        break;
+    }
+    let pwd = unsafe { pwd.assume_init() };
+    cstr_to_rust_with_size(pwd.pw_name, None)
+}
+
 #[cfg(all(
     feature = "system",
     not(any(target_os = "ios", feature = "apple-sandbox"))
@@ -55,3 +107,145 @@ pub(crate) fn wait_process(pid: crate::Pid) -> Option<std::process::ExitStatus>
         Some(std::process::ExitStatus::from_raw(status))
     }
 }
+
+#[cfg(feature = "system")]
+pub(crate) fn cached_pages(path: &std::path::Path) -> Option<crate::CacheInfo> {
+    use std::os::unix::io::AsRawFd;
+
+    let file = std::fs::File::open(path).ok()?;
+    let metadata = file.metadata().ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+    let page_size = u64::try_from(unsafe { libc::sysconf(libc::_SC_PAGESIZE) })
+        .ok()
+        .filter(|page_size| *page_size > 0)?;
+    let total_pages = metadata.len().div_ceil(page_size);
+    // Empty files cannot be mapped.
+    if total_pages == 0 {
+        return Some(crate::CacheInfo::default());
+    }
+    let len = usize::try_from(metadata.len()).ok()?;
+    let mut pages = vec![0u8; usize::try_from(total_pages).ok()?];
+    unsafe {
+        let addr = libc::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc::PROT_READ,
+            libc::MAP_SHARED,
+            file.as_raw_fd(),
+            0,
+        );
+        if addr == libc::MAP_FAILED {
+            return None;
+        }
+        let ret = libc::mincore(addr, len, pages.as_mut_ptr() as *mut _);
+        libc::munmap(addr, len);
+        if ret != 0 {
+            return None;
+        }
+    }
+    Some(crate::CacheInfo {
+        total_pages,
+        // Only the least significant bit tells if the page is resident.
+        cached_pages: pages.iter().filter(|page| **page & 1 != 0).count() as u64,
+    })
+}
+
+/// Returns the bitness of the ELF executable at `path`.
+#[cfg(all(
+    feature = "system",
+    any(target_os = "linux", target_os = "android", target_os = "freebsd")
+))]
+pub(crate) fn elf_bitness(path: &std::path::Path) -> Option<crate::Bitness> {
+    use std::io::Read;
+
+    let mut header = [0; 5];
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .ok()?;
+    parse_elf_bitness(&header)
+}
+
+/// Returns the bitness from the `EI_CLASS` field of an ELF header.
+#[cfg(all(
+    feature = "system",
+    any(target_os = "linux", target_os = "android", target_os = "freebsd")
+))]
+fn parse_elf_bitness(header: &[u8]) -> Option<crate::Bitness> {
+    if !header.starts_with(b"\x7fELF") {
+        return None;
+    }
+    match header.get(4)? {
+        1 => Some(crate::Bitness::Bit32),
+        2 => Some(crate::Bitness::Bit64),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `uid` is a system account: the `nobody` user or a UID lower than the first
+/// UID of regular users (`UID_MIN` from `/etc/login.defs`, `1000` by default).
+#[cfg(all(
+    feature = "system",
+    any(target_os = "linux", target_os = "android", target_os = "freebsd")
+))]
+pub(crate) fn is_system_user(uid: libc::uid_t) -> bool {
+    static UID_MIN: std::sync::OnceLock<u32> = std::sync::OnceLock::new();
+
+    let uid_min = *UID_MIN.get_or_init(|| {
+        std::fs::read_to_string("/etc/login.defs")
+            .ok()
+            .and_then(|content| parse_uid_min(&content))
+            .unwrap_or(1000)
+    });
+    // `65534` is the `nobody` user.
+    uid < uid_min || uid == 65534
+}
+
+/// Returns the first UID of regular users (`UID_MIN`) from the content of `/etc/login.defs`.
+#[cfg(all(
+    feature = "system",
+    any(target_os = "linux", target_os = "android", target_os = "freebsd")
+))]
+fn parse_uid_min(content: &str) -> Option<u32> {
+    content.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        if parts.next() != Some("UID_MIN") {
+            return None;
+        }
+        parts.next()?.parse().ok()
+    })
+}
+
+#[cfg(all(
+    test,
+    feature = "system",
+    any(target_os = "linux", target_os = "android", target_os = "freebsd")
+))]
+mod test {
+    use super::{parse_elf_bitness, parse_uid_min};
+    use crate::Bitness;
+
+    #[test]
+    fn check_parse_elf_bitness() {
+        assert_eq!(parse_elf_bitness(b"\x7fELF\x01"), Some(Bitness::Bit32));
+        assert_eq!(parse_elf_bitness(b"\x7fELF\x02"), Some(Bitness::Bit64));
+        assert_eq!(parse_elf_bitness(b"\x7fELF\x03"), None);
+        assert_eq!(parse_elf_bitness(b"\x7fELF"), None);
+        assert_eq!(parse_elf_bitness(b"#!/bin/sh"), None);
+    }
+
+    #[test]
+    fn check_parse_uid_min() {
+        let login_defs = "\
+# Min/max values for automatic uid selection in useradd
+#
+UID_MIN\t\t\t 1000
+UID_MAX\t\t\t60000
+SYS_UID_MIN\t\t  100
+";
+        assert_eq!(parse_uid_min(login_defs), Some(1000));
+        assert_eq!(parse_uid_min("SYS_UID_MIN 100\n"), None);
+        assert_eq!(parse_uid_min("UID_MIN\n"), None);
+    }
+}
@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{CompressionInfo, Disk, DiskKind, DiskRefreshKind, DiskUsage, MountEntry};
 
 use std::{ffi::OsStr, path::Path};
 
@@ -31,6 +31,14 @@ impl DiskInner {
         0
     }
 
+    pub(crate) fn available_space_delta(&self) -> i64 {
+        0
+    }
+
+    pub(crate) fn free_space(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         false
     }
@@ -39,6 +47,10 @@ impl DiskInner {
         false
     }
 
+    pub(crate) fn compression(&self) -> Option<CompressionInfo> {
+        None
+    }
+
     pub(crate) fn refresh_specifics(&mut self, _refreshes: DiskRefreshKind) -> bool {
         true
     }
@@ -73,6 +85,14 @@ impl DisksInner {
         // Does nothing.
     }
 
+    pub(crate) fn total_io(&self) -> DiskUsage {
+        DiskUsage::default()
+    }
+
+    pub(crate) fn list_mounts() -> Vec<MountEntry> {
+        Vec::new()
+    }
+
     pub(crate) fn list(&self) -> &[Disk] {
         &self.disks
     }
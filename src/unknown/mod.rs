@@ -35,7 +35,7 @@ cfg_if! {
         pub mod users;
 
         pub(crate) use self::groups::get_groups;
-        pub(crate) use self::users::{get_users, UserInner};
+        pub(crate) use self::users::{get_current_user_id, get_users, UserInner};
     }
 }
 
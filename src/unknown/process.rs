@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{Bitness, DiskUsage, Gid, OpenFile, Pid, ProcessStatus, Signal, Uid};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -55,6 +55,14 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn memory_delta(&self) -> i64 {
+        0
+    }
+
+    pub(crate) fn bitness(&self) -> Option<Bitness> {
+        None
+    }
+
     pub(crate) fn virtual_memory(&self) -> u64 {
         0
     }
@@ -79,6 +87,10 @@ impl ProcessInner {
         0.0
     }
 
+    pub(crate) fn cpu_count(&self) -> usize {
+        0
+    }
+
     pub(crate) fn accumulated_cpu_time(&self) -> u64 {
         0
     }
@@ -111,6 +123,14 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn open_files(&self) -> Option<&[OpenFile]> {
+        None
+    }
+
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        None
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         false
     }
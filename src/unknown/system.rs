@@ -1,7 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
+    Cpu, CpuRefreshKind, LoadAvg, MemoryInfo, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
     ProcessesToUpdate,
 };
 
@@ -20,12 +20,14 @@ pub const MINIMUM_CPU_UPDATE_INTERVAL: Duration = Duration::from_millis(0);
 
 pub(crate) struct SystemInner {
     process_list: HashMap<Pid, Process>,
+    physical_core_count: Option<Option<usize>>,
 }
 
 impl SystemInner {
     pub(crate) fn new() -> Self {
         Self {
             process_list: Default::default(),
+            physical_core_count: None,
         }
     }
 
@@ -37,6 +39,12 @@ impl SystemInner {
 
     pub(crate) fn refresh_cpu_specifics(&mut self, _refresh_kind: CpuRefreshKind) {}
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {}
+
+    pub(crate) fn refresh_cpu(&mut self, _index: usize, _refresh_kind: CpuRefreshKind) -> bool {
+        false
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, _refresh_kind: CpuRefreshKind) {}
 
     pub(crate) fn refresh_processes_specifics(
@@ -99,6 +107,10 @@ impl SystemInner {
         0
     }
 
+    pub(crate) fn memory_info() -> MemoryInfo {
+        MemoryInfo::default()
+    }
+
     pub(crate) fn uptime() -> u64 {
         0
     }
@@ -142,6 +154,10 @@ impl SystemInner {
     pub(crate) fn host_name() -> Option<String> {
         None
     }
+
+    pub(crate) fn fqdn() -> Option<String> {
+        None
+    }
     pub(crate) fn cpu_arch() -> Option<String> {
         None
     }
@@ -149,4 +165,10 @@ impl SystemInner {
     pub(crate) fn physical_core_count() -> Option<usize> {
         None
     }
+
+    pub(crate) fn physical_core_count_cached(&mut self) -> Option<usize> {
+        *self
+            .physical_core_count
+            .get_or_insert_with(Self::physical_core_count)
+    }
 }
@@ -57,6 +57,10 @@ impl ComponentInner {
         Some(self.max)
     }
 
+    pub(crate) fn reset_max(&mut self) {
+        self.max = self.temperature;
+    }
+
     pub(crate) fn critical(&self) -> Option<f32> {
         self.critical
     }
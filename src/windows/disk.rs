@@ -1,12 +1,13 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::disk::sum_disks_usage;
 use crate::sys::utils::HandleWrapper;
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{CompressionInfo, Disk, DiskKind, DiskRefreshKind, DiskUsage, MountEntry};
 
 use std::ffi::{OsStr, OsString};
 use std::mem::size_of;
 use std::os::windows::ffi::OsStringExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use windows::core::{Error, HRESULT, PCWSTR};
 use windows::Win32::Foundation::MAX_PATH;
@@ -125,6 +126,9 @@ pub(crate) struct DiskInner {
     s_mount_point: OsString,
     total_space: u64,
     available_space: u64,
+    /// Value of `available_space` before the last refresh, `None` until it was retrieved.
+    old_available_space: Option<u64>,
+    free_space: u64,
     is_removable: bool,
     is_read_only: bool,
     device_path: Vec<u16>,
@@ -160,6 +164,24 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn available_space_delta(&self) -> i64 {
+        self.old_available_space
+            .map_or(0, |old| self.available_space as i64 - old as i64)
+    }
+
+    fn set_available_space(&mut self, available_space: u64) {
+        // The first time, there is no previous value so the delta is `0`.
+        self.old_available_space = Some(
+            self.old_available_space
+                .map_or(available_space, |_| self.available_space),
+        );
+        self.available_space = available_space;
+    }
+
+    pub(crate) fn free_space(&self) -> u64 {
+        self.free_space
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -168,6 +190,10 @@ impl DiskInner {
         self.is_read_only
     }
 
+    pub(crate) fn compression(&self) -> Option<CompressionInfo> {
+        None
+    }
+
     pub(crate) fn refresh_specifics(&mut self, refreshes: DiskRefreshKind) -> bool {
         if refreshes.kind() || refreshes.io_usage() {
             unsafe {
@@ -197,7 +223,8 @@ impl DiskInner {
                 unsafe { get_drive_size(&self.mount_point) }
             {
                 self.total_space = total_space;
-                self.available_space = available_space;
+                self.set_available_space(available_space);
+                self.free_space = available_space;
             }
         }
         true
@@ -242,6 +269,14 @@ impl DisksInner {
         }
     }
 
+    pub(crate) fn total_io(&self) -> DiskUsage {
+        sum_disks_usage(
+            self.disks
+                .iter()
+                .map(|disk| (&disk.inner.device_path, disk.usage())),
+        )
+    }
+
     pub(crate) fn list(&self) -> &[Disk] {
         &self.disks
     }
@@ -249,6 +284,10 @@ impl DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn list_mounts() -> Vec<MountEntry> {
+        unsafe { get_mounts() }
+    }
 }
 
 unsafe fn get_drive_size(mount_point: &[u16]) -> Option<(u64, u64)> {
@@ -269,6 +308,51 @@ unsafe fn get_drive_size(mount_point: &[u16]) -> Option<(u64, u64)> {
     }
 }
 
+unsafe fn get_mounts() -> Vec<MountEntry> {
+    let mut mounts = Vec::new();
+    for volume_name in get_volume_guid_paths() {
+        let mount_paths = get_volume_path_names_for_volume_name(&volume_name[..]);
+        if mount_paths.is_empty() {
+            continue;
+        }
+
+        let mut file_system = [0u16; 32];
+        let mut flags = 0;
+        if GetVolumeInformationW(
+            PCWSTR::from_raw(volume_name.as_ptr()),
+            None,
+            None,
+            None,
+            Some(&mut flags),
+            Some(&mut file_system),
+        )
+        .is_err()
+        {
+            sysinfo_debug!(
+                "Error: GetVolumeInformationW = {:?}",
+                Error::from_win32().code()
+            );
+        }
+        let options = if (flags & FILE_READ_ONLY_VOLUME) != 0 {
+            "ro"
+        } else {
+            "rw"
+        };
+
+        let device = os_string_from_zero_terminated(&volume_name);
+        let file_system = os_string_from_zero_terminated(&file_system);
+        for mount_path in mount_paths {
+            mounts.push(MountEntry {
+                device: device.clone(),
+                mount_point: PathBuf::from(os_string_from_zero_terminated(&mount_path)),
+                fs_type: file_system.clone(),
+                options: options.to_owned(),
+            });
+        }
+    }
+    mounts
+}
+
 pub(crate) unsafe fn get_list(
     disks: &mut Vec<Disk>,
     remove_not_listed_disks: bool,
@@ -336,6 +420,8 @@ pub(crate) unsafe fn get_list(
                 mount_point: mount_path,
                 total_space: 0,
                 available_space: 0,
+                old_available_space: None,
+                free_space: 0,
                 is_removable,
                 is_read_only,
                 device_path: device_path.clone(),
@@ -393,7 +479,7 @@ unsafe fn get_disk_kind(handle: &HandleWrapper) -> DiskKind {
         .is_ok()
     };
 
-    if !device_io_control || dw_size != size_of::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() as _ {
+    if !device_io_control || dw_size != size_of::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() as u32 {
         DiskKind::Unknown(-1)
     } else {
         let is_hdd = result.IncursSeekPenalty.as_bool();
@@ -37,7 +37,7 @@ cfg_if! {
         mod users;
 
         pub(crate) use self::groups::get_groups;
-        pub(crate) use self::users::get_users;
+        pub(crate) use self::users::{get_current_user_id, get_users};
         pub(crate) use self::users::UserInner;
     }
 
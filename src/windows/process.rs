@@ -3,7 +3,9 @@
 use crate::sys::system::is_proc_running;
 use crate::sys::utils::HandleWrapper;
 use crate::windows::Sid;
-use crate::{DiskUsage, Gid, Pid, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    Bitness, DiskUsage, Gid, OpenFile, Pid, ProcessRefreshKind, ProcessStatus, Signal, Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -42,10 +44,13 @@ use windows::Win32::System::ProcessStatus::{
     GetModuleFileNameExW, GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS_EX,
 };
 use windows::Win32::System::RemoteDesktop::ProcessIdToSessionId;
-use windows::Win32::System::SystemInformation::OSVERSIONINFOEXW;
+use windows::Win32::System::SystemInformation::{
+    IMAGE_FILE_MACHINE_AMD64, IMAGE_FILE_MACHINE_ARM64, IMAGE_FILE_MACHINE_IA64,
+    IMAGE_FILE_MACHINE_UNKNOWN, OSVERSIONINFOEXW,
+};
 use windows::Win32::System::Threading::{
-    GetExitCodeProcess, GetProcessIoCounters, GetProcessTimes, GetSystemTimes, OpenProcess,
-    OpenProcessToken, CREATE_NO_WINDOW, IO_COUNTERS, PEB, PROCESS_BASIC_INFORMATION,
+    GetExitCodeProcess, GetProcessIoCounters, GetProcessTimes, GetSystemTimes, IsWow64Process2,
+    OpenProcess, OpenProcessToken, CREATE_NO_WINDOW, IO_COUNTERS, PEB, PROCESS_BASIC_INFORMATION,
     PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
 };
 use windows::Win32::UI::Shell::CommandLineToArgvW;
@@ -176,6 +181,8 @@ unsafe impl Sync for HandleWrapper {}
 pub(crate) struct ProcessInner {
     name: OsString,
     cmd: Vec<OsString>,
+    /// The command line as it was given to the process, before being split into arguments.
+    raw_cmd: Option<OsString>,
     exe: Option<PathBuf>,
     pid: Pid,
     user_id: Option<Uid>,
@@ -184,6 +191,7 @@ pub(crate) struct ProcessInner {
     root: Option<PathBuf>,
     pub(crate) memory: u64,
     pub(crate) virtual_memory: u64,
+    pub(crate) old_memory: u64,
     pub(crate) parent: Option<Pid>,
     status: ProcessStatus,
     handle: Option<Arc<HandleWrapper>>,
@@ -191,6 +199,8 @@ pub(crate) struct ProcessInner {
     start_time: u64,
     pub(crate) run_time: u64,
     cpu_usage: f32,
+    /// Number of CPUs when `cpu_usage` was last computed.
+    cpu_count: usize,
     pub(crate) updated: bool,
     old_read_bytes: u64,
     old_written_bytes: u64,
@@ -261,6 +271,7 @@ impl ProcessInner {
             parent,
             user_id: None,
             cmd: Vec::new(),
+            raw_cmd: None,
             environ: Vec::new(),
             exe: None,
             cwd: None,
@@ -268,7 +279,9 @@ impl ProcessInner {
             status: ProcessStatus::Run,
             memory: 0,
             virtual_memory: 0,
+            old_memory: 0,
             cpu_usage: 0.,
+            cpu_count: 0,
             cpu_calc_values: CPUsageCalculationValues::new(),
             start_time,
             run_time,
@@ -290,6 +303,7 @@ impl ProcessInner {
     ) {
         if refresh_kind.cpu() {
             compute_cpu_usage(self, nb_cpus);
+            self.cpu_count = nb_cpus as usize;
         }
         if refresh_kind.disk_usage() {
             update_disk_usage(self);
@@ -306,6 +320,7 @@ impl ProcessInner {
                 } {
                     sysinfo_debug!("GetProcessMemoryInfo failed: {_error:?}");
                 } else {
+                    self.old_memory = self.memory;
                     self.memory = mem_info.WorkingSetSize as _;
                     self.virtual_memory = mem_info.PrivateUsage as _;
                 }
@@ -370,6 +385,20 @@ impl ProcessInner {
         &self.cmd
     }
 
+    pub(crate) fn cmd_display(&self) -> String {
+        if let Some(raw_cmd) = &self.raw_cmd {
+            return raw_cmd.to_string_lossy().into_owned();
+        }
+        let mut out = String::new();
+        for (pos, arg) in self.cmd.iter().enumerate() {
+            if pos > 0 {
+                out.push(' ');
+            }
+            push_quoted_arg(&mut out, &arg.to_string_lossy());
+        }
+        out
+    }
+
     pub(crate) fn exe(&self) -> Option<&Path> {
         self.exe.as_deref()
     }
@@ -394,6 +423,30 @@ impl ProcessInner {
         self.memory
     }
 
+    pub(crate) fn bitness(&self) -> Option<Bitness> {
+        let handle = self.get_handle()?;
+        let mut process_machine = IMAGE_FILE_MACHINE_UNKNOWN;
+        let mut native_machine = IMAGE_FILE_MACHINE_UNKNOWN;
+        unsafe {
+            IsWow64Process2(handle, &mut process_machine, Some(&mut native_machine)).ok()?;
+        }
+        // `process_machine` is only set if the process runs under WOW64, meaning it's a 32-bit
+        // process on a 64-bit system.
+        if process_machine != IMAGE_FILE_MACHINE_UNKNOWN {
+            return Some(Bitness::Bit32);
+        }
+        match native_machine {
+            IMAGE_FILE_MACHINE_AMD64 | IMAGE_FILE_MACHINE_ARM64 | IMAGE_FILE_MACHINE_IA64 => {
+                Some(Bitness::Bit64)
+            }
+            _ => Some(Bitness::Bit32),
+        }
+    }
+
+    pub(crate) fn memory_delta(&self) -> i64 {
+        self.memory as i64 - self.old_memory as i64
+    }
+
     pub(crate) fn virtual_memory(&self) -> u64 {
         self.virtual_memory
     }
@@ -418,6 +471,10 @@ impl ProcessInner {
         self.cpu_usage
     }
 
+    pub(crate) fn cpu_count(&self) -> usize {
+        self.cpu_count
+    }
+
     pub(crate) fn accumulated_cpu_time(&self) -> u64 {
         self.accumulated_cpu_time
     }
@@ -488,6 +545,14 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn open_files(&self) -> Option<&[OpenFile]> {
+        None
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         std::mem::replace(&mut self.updated, false)
     }
@@ -595,12 +660,41 @@ unsafe fn ph_query_process_variable_size(
     Some(buffer)
 }
 
-unsafe fn get_cmdline_from_buffer(buffer: PCWSTR) -> Vec<OsString> {
+/// Quotes `arg` so that `CommandLineToArgvW` parses it back as is.
+fn push_quoted_arg(out: &mut String, arg: &str) {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '"']) {
+        out.push_str(arg);
+        return;
+    }
+    out.push('"');
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        if c == '\\' {
+            backslashes += 1;
+            continue;
+        }
+        // Backslashes are only special when followed by a double quote.
+        let count = if c == '"' {
+            backslashes * 2 + 1
+        } else {
+            backslashes
+        };
+        out.extend(std::iter::repeat('\\').take(count));
+        out.push(c);
+        backslashes = 0;
+    }
+    out.extend(std::iter::repeat('\\').take(backslashes * 2));
+    out.push('"');
+}
+
+/// Returns the raw command line and its arguments.
+unsafe fn get_cmdline_from_buffer(buffer: PCWSTR) -> (Option<OsString>, Vec<OsString>) {
+    let raw = OsString::from_wide(buffer.as_wide());
     // Get argc and argv from the command line
     let mut argc = MaybeUninit::<i32>::uninit();
     let argv_p = CommandLineToArgvW(buffer, argc.as_mut_ptr());
     if argv_p.is_null() {
-        return Vec::new();
+        return (Some(raw), Vec::new());
     }
     let argc = argc.assume_init();
     let argv = std::slice::from_raw_parts(argv_p, argc as usize);
@@ -612,7 +706,7 @@ unsafe fn get_cmdline_from_buffer(buffer: PCWSTR) -> Vec<OsString> {
 
     let _err = LocalFree(HLOCAL(argv_p as _));
 
-    res
+    (Some(raw), res)
 }
 
 unsafe fn get_region_size(handle: HANDLE, ptr: *const c_void) -> Result<usize, &'static str> {
@@ -805,7 +899,13 @@ unsafe fn get_process_params(
         }
 
         let proc_params = proc_params.assume_init();
-        get_cmd_line(&proc_params, handle, refresh_kind, &mut process.cmd);
+        get_cmd_line(
+            &proc_params,
+            handle,
+            refresh_kind,
+            &mut process.cmd,
+            &mut process.raw_cmd,
+        );
         get_proc_env(&proc_params, handle, refresh_kind, &mut process.environ);
         get_cwd_and_root(
             &proc_params,
@@ -850,7 +950,13 @@ unsafe fn get_process_params(
         return;
     }
     let proc_params = proc_params.assume_init();
-    get_cmd_line(&proc_params, handle, refresh_kind, &mut process.cmd);
+    get_cmd_line(
+        &proc_params,
+        handle,
+        refresh_kind,
+        &mut process.cmd,
+        &mut process.raw_cmd,
+    );
     get_proc_env(&proc_params, handle, refresh_kind, &mut process.environ);
     get_cwd_and_root(
         &proc_params,
@@ -896,18 +1002,21 @@ unsafe fn null_terminated_wchar_to_string(slice: &[u16]) -> OsString {
     }
 }
 
-fn get_cmd_line_old<T: RtlUserProcessParameters>(params: &T, handle: HANDLE) -> Vec<OsString> {
+fn get_cmd_line_old<T: RtlUserProcessParameters>(
+    params: &T,
+    handle: HANDLE,
+) -> (Option<OsString>, Vec<OsString>) {
     match params.get_cmdline(handle) {
         Ok(buffer) => unsafe { get_cmdline_from_buffer(PCWSTR::from_raw(buffer.as_ptr())) },
         Err(_e) => {
             sysinfo_debug!("get_cmd_line_old failed to get data: {}", _e);
-            Vec::new()
+            (None, Vec::new())
         }
     }
 }
 
 #[allow(clippy::cast_ptr_alignment)]
-fn get_cmd_line_new(handle: HANDLE) -> Vec<OsString> {
+fn get_cmd_line_new(handle: HANDLE) -> (Option<OsString>, Vec<OsString>) {
     unsafe {
         if let Some(buffer) = ph_query_process_variable_size(handle, ProcessCommandLineInformation)
         {
@@ -915,7 +1024,7 @@ fn get_cmd_line_new(handle: HANDLE) -> Vec<OsString> {
 
             get_cmdline_from_buffer(PCWSTR::from_raw(buffer.as_ptr()))
         } else {
-            vec![]
+            (None, vec![])
         }
     }
 }
@@ -925,15 +1034,16 @@ fn get_cmd_line<T: RtlUserProcessParameters>(
     handle: HANDLE,
     refresh_kind: ProcessRefreshKind,
     cmd_line: &mut Vec<OsString>,
+    raw_cmd_line: &mut Option<OsString>,
 ) {
     if !refresh_kind.cmd().needs_update(|| cmd_line.is_empty()) {
         return;
     }
-    if *windows_8_1_or_newer() {
-        *cmd_line = get_cmd_line_new(handle);
+    (*raw_cmd_line, *cmd_line) = if *windows_8_1_or_newer() {
+        get_cmd_line_new(handle)
     } else {
-        *cmd_line = get_cmd_line_old(params, handle);
-    }
+        get_cmd_line_old(params, handle)
+    };
 }
 
 fn get_proc_env<T: RtlUserProcessParameters>(
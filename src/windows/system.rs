@@ -1,7 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, ProcessRefreshKind, ProcessesToUpdate,
+    Cpu, CpuRefreshKind, LoadAvg, MemoryInfo, MemoryRefreshKind, Pid, ProcessRefreshKind,
+    ProcessesToUpdate, TpmInfo, TpmVersion,
 };
 
 use crate::sys::cpu::*;
@@ -22,12 +23,19 @@ use windows::Win32::System::ProcessStatus::{K32GetPerformanceInfo, PERFORMANCE_I
 use windows::Win32::System::Registry::{
     RegCloseKey, RegOpenKeyExW, RegQueryValueExW, HKEY, HKEY_LOCAL_MACHINE, KEY_READ, REG_NONE,
 };
-use windows::Win32::System::SystemInformation::{self, GetSystemInfo};
+use windows::Win32::System::RemoteDesktop::{
+    WTSFreeMemory, WTSGetActiveConsoleSessionId, WTSQuerySessionInformationW, WTSUserName,
+    WTS_CURRENT_SERVER_HANDLE,
+};
+use windows::Win32::System::SystemInformation::{self, GetNativeSystemInfo, GetSystemInfo};
 use windows::Win32::System::SystemInformation::{
-    ComputerNamePhysicalDnsHostname, GetComputerNameExW, GetTickCount64, GlobalMemoryStatusEx,
-    MEMORYSTATUSEX, SYSTEM_INFO,
+    ComputerNamePhysicalDnsFullyQualified, ComputerNamePhysicalDnsHostname, GetComputerNameExW,
+    GetTickCount64, GlobalMemoryStatusEx, COMPUTER_NAME_FORMAT, MEMORYSTATUSEX, SYSTEM_INFO,
 };
 use windows::Win32::System::Threading::GetExitCodeProcess;
+use windows::Win32::System::TpmBaseServices::{
+    Tbsi_GetDeviceInfo, TPM_DEVICE_INFO, TPM_VERSION_12, TPM_VERSION_20,
+};
 
 declare_signals! {
     (),
@@ -52,6 +60,30 @@ impl SystemInner {
     }
 }
 
+/// Returns the total and available physical memory.
+unsafe fn get_ram_info() -> (u64, u64) {
+    let mut mem_info: MEMORYSTATUSEX = zeroed();
+    mem_info.dwLength = size_of::<MEMORYSTATUSEX>() as _;
+    let _err = GlobalMemoryStatusEx(&mut mem_info);
+    (mem_info.ullTotalPhys as _, mem_info.ullAvailPhys as _)
+}
+
+/// Returns the total and used swap.
+unsafe fn get_swap_info() -> Option<(u64, u64)> {
+    let mut perf_info: PERFORMANCE_INFORMATION = zeroed();
+    if !K32GetPerformanceInfo(&mut perf_info, size_of::<PERFORMANCE_INFORMATION>() as _).as_bool() {
+        return None;
+    }
+    let page_size = perf_info.PageSize as u64;
+    let physical_total = perf_info.PhysicalTotal as u64;
+    let commit_limit = perf_info.CommitLimit as u64;
+    let commit_total = perf_info.CommitTotal as u64;
+    Some((
+        page_size.saturating_mul(commit_limit.saturating_sub(physical_total)),
+        page_size.saturating_mul(commit_total.saturating_sub(physical_total)),
+    ))
+}
+
 /// Calculates system boot time in seconds with improved precision.
 /// Uses nanoseconds throughout to avoid rounding errors in uptime calculation,
 /// converting to seconds only at the end for stable results. Result is capped
@@ -81,6 +113,7 @@ pub(crate) struct SystemInner {
     swap_used: u64,
     cpus: CpusWrapper,
     query: Option<Query>,
+    physical_core_count: Option<Option<usize>>,
 }
 
 impl SystemInner {
@@ -93,6 +126,7 @@ impl SystemInner {
             swap_used: 0,
             cpus: CpusWrapper::new(),
             query: None,
+            physical_core_count: None,
         }
     }
 
@@ -116,6 +150,20 @@ impl SystemInner {
     }
 
     pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
+        self.refresh_cpus(false, refresh_kind);
+    }
+
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.refresh_cpus(true, CpuRefreshKind::nothing().with_cpu_usage());
+    }
+
+    pub(crate) fn refresh_cpu(&mut self, index: usize, refresh_kind: CpuRefreshKind) -> bool {
+        // All CPU counters are part of the same query, so they're all refreshed.
+        self.refresh_cpu_specifics(refresh_kind);
+        index < self.cpus().len()
+    }
+
+    fn refresh_cpus(&mut self, only_update_global_cpu: bool, refresh_kind: CpuRefreshKind) {
         if self.query.is_none() {
             self.query = Query::new(false);
             self.initialize_cpu_counters(refresh_kind);
@@ -136,6 +184,9 @@ impl SystemInner {
             if let Some(total_idle_time) = total_idle_time {
                 self.cpus.global.set_cpu_usage(100.0 - total_idle_time);
             }
+            if only_update_global_cpu {
+                return;
+            }
             for cpu in self.cpus.iter_mut(refresh_kind) {
                 let mut idle_time = None;
                 if let Some(ref key_used) = *get_key_used(cpu) {
@@ -163,25 +214,12 @@ impl SystemInner {
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
         unsafe {
             if refresh_kind.ram() {
-                let mut mem_info: MEMORYSTATUSEX = zeroed();
-                mem_info.dwLength = size_of::<MEMORYSTATUSEX>() as _;
-                let _err = GlobalMemoryStatusEx(&mut mem_info);
-                self.mem_total = mem_info.ullTotalPhys as _;
-                self.mem_available = mem_info.ullAvailPhys as _;
+                (self.mem_total, self.mem_available) = get_ram_info();
             }
             if refresh_kind.swap() {
-                let mut perf_info: PERFORMANCE_INFORMATION = zeroed();
-                if K32GetPerformanceInfo(&mut perf_info, size_of::<PERFORMANCE_INFORMATION>() as _)
-                    .as_bool()
-                {
-                    let page_size = perf_info.PageSize as u64;
-                    let physical_total = perf_info.PhysicalTotal as u64;
-                    let commit_limit = perf_info.CommitLimit as u64;
-                    let commit_total = perf_info.CommitTotal as u64;
-                    self.swap_total =
-                        page_size.saturating_mul(commit_limit.saturating_sub(physical_total));
-                    self.swap_used =
-                        page_size.saturating_mul(commit_total.saturating_sub(physical_total));
+                if let Some((swap_total, swap_used)) = get_swap_info() {
+                    self.swap_total = swap_total;
+                    self.swap_used = swap_used;
                 }
             }
         }
@@ -285,6 +323,8 @@ impl SystemInner {
                     // Make a new 'ProcessInner' using the Windows PROCESSENTRY32W struct.
                     let mut p = ProcessInner::from_process_entry(&process_entry, now);
                     p.update(refresh_kind, nb_cpus, now, false);
+                    // No previous value for a new process.
+                    p.old_memory = p.memory;
                     process_list.insert(proc_id, Process { inner: p });
                 }
 
@@ -349,6 +389,22 @@ impl SystemInner {
         self.swap_used
     }
 
+    pub(crate) fn memory_info() -> MemoryInfo {
+        unsafe {
+            let (total, available) = get_ram_info();
+            let (total_swap, used_swap) = get_swap_info().unwrap_or_default();
+            MemoryInfo {
+                total,
+                // MEMORYSTATUSEX doesn't report free memory
+                free: available,
+                available,
+                used: total.saturating_sub(available),
+                total_swap,
+                free_swap: total_swap.saturating_sub(used_swap),
+            }
+        }
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe { GetTickCount64() / 1_000 }
     }
@@ -382,7 +438,11 @@ impl SystemInner {
     }
 
     pub(crate) fn host_name() -> Option<String> {
-        get_dns_hostname()
+        get_computer_name(ComputerNamePhysicalDnsHostname)
+    }
+
+    pub(crate) fn fqdn() -> Option<String> {
+        get_computer_name(ComputerNamePhysicalDnsFullyQualified)
     }
 
     pub(crate) fn kernel_version() -> Option<String> {
@@ -428,29 +488,108 @@ impl SystemInner {
             // https://docs.microsoft.com/fr-fr/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info
             let mut info = SYSTEM_INFO::default();
             GetSystemInfo(&mut info);
-            match info.Anonymous.Anonymous.wProcessorArchitecture {
-                SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA => Some("alpha".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA64 => Some("alpha64".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_AMD64 => Some("x86_64".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_ARM => Some("arm".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_ARM32_ON_WIN64 => Some("arm".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_ARM64 => Some("arm64".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_IA32_ON_ARM64
-                | SystemInformation::PROCESSOR_ARCHITECTURE_IA32_ON_WIN64 => {
-                    Some("ia32".to_string())
-                }
-                SystemInformation::PROCESSOR_ARCHITECTURE_IA64 => Some("ia64".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_INTEL => Some("x86".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_MIPS => Some("mips".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_PPC => Some("powerpc".to_string()),
-                _ => None,
+            arch_name(&info)
+        }
+    }
+
+    pub(crate) fn native_cpu_arch() -> Option<String> {
+        unsafe {
+            // Contrary to `GetSystemInfo`, it returns the host architecture even when the
+            // process runs under WOW64.
+            let mut info = SYSTEM_INFO::default();
+            GetNativeSystemInfo(&mut info);
+            arch_name(&info)
+        }
+    }
+
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        // This key doesn't exist on systems booted in legacy BIOS mode.
+        get_reg_value_u32(
+            HKEY_LOCAL_MACHINE,
+            r"SYSTEM\CurrentControlSet\Control\SecureBoot\State",
+            "UEFISecureBootEnabled",
+        )
+        .map(|value| u32::from_le_bytes(value) != 0)
+    }
+
+    pub(crate) fn tpm() -> Option<TpmInfo> {
+        // `TBS_E_TPM_NOT_FOUND` from `<tbs.h>`.
+        const TBS_E_TPM_NOT_FOUND: u32 = 0x8028_400F;
+
+        let mut info: TPM_DEVICE_INFO = unsafe { zeroed() };
+        let ret = unsafe {
+            Tbsi_GetDeviceInfo(
+                size_of::<TPM_DEVICE_INFO>() as _,
+                &mut info as *mut TPM_DEVICE_INFO as *mut _,
+            )
+        };
+        match ret {
+            0 => Some(TpmInfo {
+                version: match info.tpmVersion {
+                    TPM_VERSION_12 => TpmVersion::V1,
+                    TPM_VERSION_20 => TpmVersion::V2,
+                    _ => TpmVersion::Unknown,
+                },
+                present: true,
+            }),
+            TBS_E_TPM_NOT_FOUND => Some(TpmInfo {
+                version: TpmVersion::Unknown,
+                present: false,
+            }),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn active_desktop_user() -> Option<String> {
+        unsafe {
+            let session_id = WTSGetActiveConsoleSessionId();
+            // `0xFFFFFFFF` means that there is no session attached to the console.
+            if session_id == u32::MAX {
+                return None;
             }
+            let mut buffer = PWSTR::null();
+            let mut len = 0;
+            WTSQuerySessionInformationW(
+                WTS_CURRENT_SERVER_HANDLE,
+                session_id,
+                WTSUserName,
+                &mut buffer,
+                &mut len,
+            )
+            .ok()?;
+            let user = buffer.to_string().ok();
+            WTSFreeMemory(buffer.as_ptr() as *mut _);
+            user.filter(|user| !user.is_empty())
         }
     }
 
     pub(crate) fn physical_core_count() -> Option<usize> {
         get_physical_core_count()
     }
+
+    pub(crate) fn physical_core_count_cached(&mut self) -> Option<usize> {
+        *self
+            .physical_core_count
+            .get_or_insert_with(Self::physical_core_count)
+    }
+}
+
+unsafe fn arch_name(info: &SYSTEM_INFO) -> Option<String> {
+    match info.Anonymous.Anonymous.wProcessorArchitecture {
+        SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA => Some("alpha".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA64 => Some("alpha64".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_AMD64 => Some("x86_64".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_ARM => Some("arm".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_ARM32_ON_WIN64 => Some("arm".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_ARM64 => Some("arm64".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_IA32_ON_ARM64
+        | SystemInformation::PROCESSOR_ARCHITECTURE_IA32_ON_WIN64 => Some("ia32".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_IA64 => Some("ia64".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_INTEL => Some("x86".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_MIPS => Some("mips".to_string()),
+        SystemInformation::PROCESSOR_ARCHITECTURE_PPC => Some("powerpc".to_string()),
+        _ => None,
+    }
 }
 
 pub(crate) fn is_proc_running(handle: HANDLE) -> bool {
@@ -459,24 +598,20 @@ pub(crate) fn is_proc_running(handle: HANDLE) -> bool {
         && exit_code == STILL_ACTIVE.0 as u32
 }
 
-fn get_dns_hostname() -> Option<String> {
+fn get_computer_name(format: COMPUTER_NAME_FORMAT) -> Option<String> {
     let mut buffer_size = 0;
     // Running this first to get the buffer size since the DNS name can be longer than MAX_COMPUTERNAME_LENGTH
     // setting the `lpBuffer` to null will return the buffer size
     // https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getcomputernameexw
     unsafe {
-        let _err = GetComputerNameExW(
-            ComputerNamePhysicalDnsHostname,
-            PWSTR::null(),
-            &mut buffer_size,
-        );
+        let _err = GetComputerNameExW(format, PWSTR::null(), &mut buffer_size);
 
         // Setting the buffer with the new length
         let mut buffer = vec![0_u16; buffer_size as usize];
 
         // https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/ne-sysinfoapi-computer_name_format
         if GetComputerNameExW(
-            ComputerNamePhysicalDnsHostname,
+            format,
             PWSTR::from_raw(buffer.as_mut_ptr()),
             &mut buffer_size,
         )
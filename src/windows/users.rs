@@ -5,7 +5,7 @@ use crate::{windows::sid::Sid, Gid, Group, GroupInner, Uid, User};
 
 use std::ptr::null_mut;
 use windows::core::PCWSTR;
-use windows::Win32::Foundation::{ERROR_MORE_DATA, LUID};
+use windows::Win32::Foundation::{CloseHandle, ERROR_MORE_DATA, HANDLE, LUID};
 use windows::Win32::NetworkManagement::NetManagement::{
     NERR_Success, NetApiBufferFree, NetUserEnum, NetUserGetInfo, NetUserGetLocalGroups,
     FILTER_NORMAL_ACCOUNT, LG_INCLUDE_INDIRECT, LOCALGROUP_USERS_INFO_0, MAX_PREFERRED_LENGTH,
@@ -15,6 +15,8 @@ use windows::Win32::Security::Authentication::Identity::{
     LsaEnumerateLogonSessions, LsaFreeReturnBuffer, LsaGetLogonSessionData,
     SECURITY_LOGON_SESSION_DATA, SECURITY_LOGON_TYPE,
 };
+use windows::Win32::Security::{GetTokenInformation, TokenUser, TOKEN_QUERY, TOKEN_USER};
+use windows::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
 
 pub(crate) struct UserInner {
     pub(crate) uid: Uid,
@@ -159,6 +161,36 @@ unsafe fn get_groups_for_user(username: PCWSTR) -> Vec<Group> {
     groups
 }
 
+pub(crate) fn get_current_user_id() -> Option<Uid> {
+    unsafe {
+        let mut token = HANDLE::default();
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token).is_err() {
+            sysinfo_debug!("OpenProcessToken failed");
+            return None;
+        }
+
+        let mut size = 0;
+        // This first call is expected to fail since we only want the size of the buffer.
+        let _ = GetTokenInformation(token, TokenUser, None, 0, &mut size);
+        // We use `u64` so the buffer is correctly aligned for `TOKEN_USER`.
+        let mut buffer = vec![0u64; (size as usize).div_ceil(std::mem::size_of::<u64>())];
+        let ret = GetTokenInformation(
+            token,
+            TokenUser,
+            Some(buffer.as_mut_ptr().cast()),
+            size,
+            &mut size,
+        );
+        let _err = CloseHandle(token);
+        if let Err(_err) = ret {
+            sysinfo_debug!("GetTokenInformation failed: {_err:?}");
+            return None;
+        }
+        let token_user = &*(buffer.as_ptr() as *const TOKEN_USER);
+        Sid::from_psid(token_user.User.Sid).map(Uid)
+    }
+}
+
 pub(crate) fn get_users(users: &mut Vec<User>) {
     users.clear();
 
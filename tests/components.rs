@@ -16,3 +16,68 @@ fn test_components() {
     c.refresh(false);
     assert!(!c.is_empty());
 }
+
+#[cfg(feature = "component")]
+#[test]
+fn test_components_reset_max() {
+    let mut c = sysinfo::Components::new_with_refreshed_list();
+    for component in c.iter_mut() {
+        component.reset_max();
+        if let (Some(temperature), Some(max)) = (component.temperature(), component.max()) {
+            assert_eq!(temperature, max);
+        }
+    }
+    c.refresh(false);
+    for component in &c {
+        if let (Some(temperature), Some(max)) = (component.temperature(), component.max()) {
+            assert!(max >= temperature);
+        }
+    }
+}
+
+#[cfg(feature = "component")]
+#[test]
+fn test_components_is_critical() {
+    let c = sysinfo::Components::new_with_refreshed_list();
+    // The values of the critical thresholds are checked with fake sensors in the unit tests.
+    for component in c.iter().filter(|component| component.critical().is_none()) {
+        assert!(!component.is_critical());
+    }
+}
+
+#[cfg(feature = "component")]
+#[test]
+fn test_components_temperature_summary() {
+    let c = sysinfo::Components::new_with_refreshed_list();
+    let temperatures = c
+        .iter()
+        .filter_map(|component| component.temperature())
+        .filter(|t| !t.is_nan())
+        .collect::<Vec<_>>();
+    if temperatures.is_empty() {
+        assert!(c.hottest().is_none());
+        assert!(c.average_temperature().is_none());
+        return;
+    }
+    let hottest = c
+        .hottest()
+        .and_then(|component| component.temperature())
+        .expect("failed to get hottest component");
+    assert!(temperatures.iter().all(|t| *t <= hottest));
+    let average = c.average_temperature().expect("failed to get average");
+    assert!(average <= hottest);
+}
+
+#[cfg(feature = "component")]
+#[test]
+fn test_components_thermal_zone_type() {
+    let c = sysinfo::Components::new_with_refreshed_list();
+    let zone_types = c
+        .iter()
+        .filter_map(|component| component.thermal_zone_type())
+        .collect::<Vec<_>>();
+    if !cfg!(target_os = "linux") {
+        assert!(zone_types.is_empty());
+    }
+    assert!(zone_types.iter().all(|zone_type| !zone_type.is_empty()));
+}
@@ -50,3 +50,136 @@ fn test_too_rapid_cpu_refresh() {
 
     assert!(s.cpus().iter().any(|c| !c.cpu_usage().is_nan()));
 }
+
+#[test]
+fn test_refresh_global_cpu_usage() {
+    let mut s = sysinfo::System::new();
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+
+    s.refresh_global_cpu_usage();
+    // The CPUs list is still filled at the first call.
+    assert!(!s.cpus().is_empty());
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_global_cpu_usage();
+
+    let usage = s.global_cpu_usage();
+    assert!(!usage.is_nan());
+    assert!((0. ..=100.).contains(&usage));
+}
+
+#[test]
+fn test_cpu_steal_time_percent() {
+    let mut s = sysinfo::System::new();
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+
+    s.refresh_cpu_usage();
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_cpu_usage();
+
+    for cpu in s.cpus() {
+        let steal = cpu.steal_time_percent();
+        if cfg!(target_os = "linux") {
+            let steal = steal.expect("steal time should be available on Linux");
+            assert!((0. ..=100.).contains(&steal));
+        } else {
+            assert_eq!(steal, None);
+        }
+    }
+}
+
+#[test]
+fn test_cpu_frequency_governor() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+
+    let mut s = sysinfo::System::new();
+    s.refresh_cpu_list(sysinfo::CpuRefreshKind::nothing());
+    assert!(s
+        .cpus()
+        .iter()
+        .all(|cpu| cpu.frequency_governor().is_none()));
+
+    s.refresh_cpu_frequency();
+    for (pos, cpu) in s.cpus().iter().enumerate() {
+        let expected = if cfg!(target_os = "linux") {
+            std::fs::read_to_string(format!(
+                "/sys/devices/system/cpu/cpu{pos}/cpufreq/scaling_governor"
+            ))
+            .ok()
+            .map(|governor| governor.trim().to_owned())
+            .filter(|governor| !governor.is_empty())
+        } else {
+            None
+        };
+        assert_eq!(cpu.frequency_governor(), expected.as_deref());
+    }
+}
+
+#[test]
+fn test_refresh_cpu() {
+    use sysinfo::{CpuRefreshKind, System};
+
+    let mut s = System::new();
+    let refresh_kind = CpuRefreshKind::nothing().with_cpu_usage();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(!s.refresh_cpu(0, refresh_kind));
+        return;
+    }
+
+    // The first call fills the list of CPUs.
+    assert!(s.refresh_cpu(0, refresh_kind));
+    let nb_cpus = s.cpus().len();
+    assert!(nb_cpus > 0);
+    assert!(!s.refresh_cpu(nb_cpus, refresh_kind));
+
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    assert!(s.refresh_cpu(nb_cpus - 1, refresh_kind));
+    let usage = s.cpus()[nb_cpus - 1].cpu_usage();
+    assert!((0. ..=100.).contains(&usage));
+}
+
+#[test]
+fn test_physical_core_count_cached() {
+    let mut s = sysinfo::System::new();
+    let count = sysinfo::System::physical_core_count();
+    assert_eq!(s.physical_core_count_cached(), count);
+    // The second call returns the stored value.
+    assert_eq!(s.physical_core_count_cached(), count);
+}
+
+#[test]
+fn test_smt_enabled() {
+    let smt_enabled = sysinfo::System::smt_enabled();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(smt_enabled.is_none());
+    } else if smt_enabled == Some(true) {
+        // With SMT, there are more logical CPUs than physical cores.
+        if let Some(physical_core_count) = sysinfo::System::physical_core_count() {
+            assert!(sysinfo::System::possible_cpu_count() > physical_core_count);
+        }
+    }
+}
+
+#[test]
+fn test_runnable_and_blocked_process_count() {
+    let mut s = sysinfo::System::new();
+    assert!(s.runnable_process_count().is_none());
+    assert!(s.blocked_process_count().is_none());
+
+    s.refresh_cpu_usage();
+    if sysinfo::IS_SUPPORTED_SYSTEM && cfg!(any(target_os = "linux", target_os = "android")) {
+        // The current thread is running.
+        assert!(s.runnable_process_count().unwrap() >= 1);
+        assert!(s.blocked_process_count().is_some());
+    } else {
+        assert!(s.runnable_process_count().is_none());
+        assert!(s.blocked_process_count().is_none());
+    }
+}
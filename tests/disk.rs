@@ -74,30 +74,22 @@ fn test_disk_refresh_kind() {
                 // These would ideally assert that *all* are refreshed, but we settle for a weaker
                 // assertion because failures can't be distinguished from "not refreshed" values.
                 assert!(
-                    disks
-                        .iter()
-                        .any(|disk| disk.available_space() != Default::default()),
+                    disks.iter().any(|disk| disk.available_space() != 0),
                     "{name}: disk.available_space should be refreshed"
                 );
                 assert!(
-                    disks
-                        .iter()
-                        .any(|disk| disk.total_space() != Default::default()),
+                    disks.iter().any(|disk| disk.total_space() != 0),
                     "{name}: disk.total_space should be refreshed"
                 );
                 // We can't assert anything about booleans, since false is indistinguishable from
                 // not-refreshed
             } else {
                 assert!(
-                    disks
-                        .iter()
-                        .all(|disk| disk.available_space() == Default::default()),
+                    disks.iter().all(|disk| disk.available_space() == 0),
                     "{name}: disk.available_space should not be refreshed"
                 );
                 assert!(
-                    disks
-                        .iter()
-                        .all(|disk| disk.total_space() == Default::default()),
+                    disks.iter().all(|disk| disk.total_space() == 0),
                     "{name}: disk.total_space should not be refreshed"
                 );
             }
@@ -184,3 +176,208 @@ fn test_disks_usage() {
     // just verify the number is non-zero.
     assert!(written_bytes > 0);
 }
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_refresh_list_non_blocking() {
+    use std::time::Duration;
+
+    if should_skip() {
+        return;
+    }
+
+    let mut disks = sysinfo::Disks::new();
+    disks.refresh_list_non_blocking(Duration::from_secs(10));
+    assert!(!disks.list().is_empty());
+    // Local disks should always answer in time.
+    assert!(disks
+        .iter()
+        .any(|disk| !disk.space_unavailable() && disk.total_space() != 0));
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_total_io() {
+    if should_skip() {
+        return;
+    }
+
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    let total = disks.total_io();
+    // Each device is counted at most once, so the total can't be bigger than the sum of all disks.
+    let sum: u64 = disks.iter().map(|disk| disk.usage().total_read_bytes).sum();
+    assert!(total.total_read_bytes <= sum);
+    assert!(disks
+        .iter()
+        .all(|disk| disk.usage().total_read_bytes <= total.total_read_bytes));
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_mount_kind() {
+    use sysinfo::MountKind;
+
+    if should_skip() {
+        return;
+    }
+
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        assert_eq!(
+            disk.is_network(),
+            disk.mount_kind() == MountKind::Network,
+            "{:?}",
+            disk.mount_point(),
+        );
+        if !cfg!(target_os = "linux") {
+            assert_eq!(disk.mount_kind(), MountKind::Unknown);
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_free_space() {
+    if should_skip() {
+        return;
+    }
+
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        assert!(disk.free_space() <= disk.total_space());
+        // The space available to the current user can't be bigger than the free space. It's not
+        // true on macOS where the available space includes the purgeable space.
+        if cfg!(target_os = "linux") {
+            assert!(disk.available_space() <= disk.free_space());
+        }
+        let usage = disk.usage_percent();
+        assert!((0. ..=100.).contains(&usage), "{usage}");
+    }
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_list_mounts() {
+    let mounts = sysinfo::Disks::list_mounts();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(mounts.is_empty());
+        return;
+    }
+    assert!(!mounts.is_empty());
+    assert!(mounts
+        .iter()
+        .all(|mount| mount.options.starts_with("rw") || mount.options.starts_with("ro")));
+
+    // All the disks should be listed in the mount points.
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        assert!(
+            mounts
+                .iter()
+                .any(|mount| mount.mount_point == disk.mount_point()),
+            "{:?}",
+            disk.mount_point(),
+        );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_mount_ids() {
+    if should_skip() {
+        return;
+    }
+
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        if cfg!(any(target_os = "linux", target_os = "android")) {
+            let mount_id = disk.mount_id().expect("failed to get mount ID");
+            assert_ne!(Some(mount_id), disk.parent_mount_id());
+            assert!(disk.propagation().is_some());
+        } else {
+            assert!(disk.mount_id().is_none());
+            assert!(disk.parent_mount_id().is_none());
+            assert!(disk.propagation().is_none());
+        }
+    }
+}
+
+#[test]
+fn test_disks_mounted_since() {
+    if should_skip() {
+        return;
+    }
+
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        let mounted_since = disk.mounted_since();
+        if !cfg!(any(target_os = "linux", target_os = "android")) {
+            assert!(mounted_since.is_none());
+        } else if let Some(mounted_since) = mounted_since {
+            assert!(mounted_since <= std::time::SystemTime::now());
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "disk")]
+fn test_overlay_layers() {
+    let mounts = sysinfo::Disks::list_mounts();
+    for mount in &mounts {
+        let overlay = sysinfo::Disks::overlay_layers(&mount.mount_point);
+        if mount.fs_type == "overlay" && cfg!(target_os = "linux") {
+            // The mount point might be hidden by another mount.
+            if let Some(overlay) = overlay {
+                assert!(!overlay.lower_dirs.is_empty());
+            }
+        } else if mounts
+            .iter()
+            .filter(|m| m.mount_point == mount.mount_point)
+            .count()
+            == 1
+        {
+            assert!(overlay.is_none());
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_available_space_delta() {
+    if should_skip() {
+        return;
+    }
+    let mut disks = sysinfo::Disks::new_with_refreshed_list();
+    // The storage was only refreshed once.
+    assert!(disks.iter().all(|disk| disk.available_space_delta() == 0));
+
+    disks.refresh(false);
+    for disk in disks.list() {
+        assert!(
+            disk.available_space_delta().unsigned_abs() <= disk.total_space(),
+            "{:?}",
+            disk.mount_point(),
+        );
+    }
+}
+
+#[test]
+fn test_disks_compression() {
+    if should_skip() {
+        return;
+    }
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        let compression = disk.compression();
+        let file_system = disk.file_system().to_string_lossy();
+        if !matches!(&*file_system, "btrfs" | "apfs") {
+            assert_eq!(compression, None, "{:?}", disk.mount_point());
+        } else if let Some(compression) = compression {
+            assert!(
+                compression.is_enabled || compression.algorithm.is_none(),
+                "{:?}",
+                disk.mount_point(),
+            );
+        }
+    }
+}
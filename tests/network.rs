@@ -14,3 +14,46 @@ fn test_networks() {
         assert!(n.iter().count() > 0);
     }
 }
+
+#[cfg(feature = "network")]
+#[test]
+fn test_networks_refresh_list_diff() {
+    use sysinfo::Networks;
+
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let mut n = Networks::new();
+        let diff = n.refresh_list_diff();
+        assert!(!diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.added.len(), n.iter().count());
+        assert!(n.list().keys().all(|name| diff.added.contains(name)));
+    }
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_networks_total() {
+    use sysinfo::Networks;
+
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let n = Networks::new_with_refreshed_list();
+        let total = n.total();
+        let with_loopback = n.total_with_loopback();
+        assert_eq!(
+            with_loopback.total_received,
+            n.values().map(|data| data.total_received()).sum::<u64>()
+        );
+        assert_eq!(
+            with_loopback.total_transmitted,
+            n.values().map(|data| data.total_transmitted()).sum::<u64>()
+        );
+        assert!(total.total_received <= with_loopback.total_received);
+        assert!(total.total_transmitted <= with_loopback.total_transmitted);
+        if let Some(lo) = n.get("lo") {
+            assert_eq!(
+                total.total_received + lo.total_received(),
+                with_loopback.total_received
+            );
+        }
+    }
+}
@@ -166,6 +166,33 @@ fn test_environ() {
     }
 }
 
+// Checks that the environment of a process owned by the current user can be retrieved, even
+// after a first refresh which didn't ask for it.
+#[test]
+fn test_environ_current_process() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    assert!(s.process(pid).unwrap().environ().is_empty());
+
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_environ(UpdateKind::Always),
+    );
+    let path = std::env::var("PATH").expect("PATH should be set");
+    let expected = format!("PATH={path}");
+    assert!(s
+        .process(pid)
+        .unwrap()
+        .environ()
+        .iter()
+        .any(|e| *e == *expected));
+}
+
 #[test]
 fn test_process_refresh() {
     let mut s = System::new();
@@ -329,6 +356,27 @@ fn test_process_session_id() {
     assert!(s.processes().values().any(|p| p.session_id().is_some()));
 }
 
+// Checks that `process_group_id` is working.
+#[test]
+fn test_process_group_id() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+    if cfg!(windows) {
+        assert!(p.process_group_id().is_none());
+    } else {
+        #[cfg(unix)]
+        assert_eq!(
+            p.process_group_id(),
+            Some(Pid::from(unsafe { libc::getpgrp() } as usize))
+        );
+    }
+}
+
 // Checks that `refresh_processes` is removing dead processes.
 #[test]
 fn test_refresh_processes() {
@@ -750,6 +798,7 @@ fn test_process_specific_refresh() {
         assert_eq!(p.exe(), None);
         assert_eq!(p.cwd(), None);
         assert_eq!(p.root(), None);
+        assert_eq!(p.open_files(), None);
         assert_eq!(p.memory(), 0);
         assert_eq!(p.virtual_memory(), 0);
         // These two won't be checked, too much lazyness in testing them...
@@ -839,6 +888,66 @@ fn test_process_specific_refresh() {
     update_specific_and_check!(cwd, with_cwd, , None);
 }
 
+#[test]
+fn test_process_open_files() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let path = std::env::current_exe().expect("failed to get current exe");
+    let _file = std::fs::File::open(&path).expect("failed to open file");
+
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_open_files(),
+    );
+    let p = s.process(pid).expect("current process not found");
+    if cfg!(any(target_os = "linux", target_os = "macos")) {
+        let files = p.open_files().expect("failed to get open files");
+        assert!(files.iter().any(|file| file.kind == sysinfo::FdKind::File
+            && file.path.as_deref() == Some(path.as_path())));
+    } else {
+        assert!(p.open_files().is_none());
+    }
+}
+
+#[test]
+fn test_process_socket_summary() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("failed to bind");
+    let addr = listener.local_addr().expect("failed to get local address");
+    let _client = std::net::TcpStream::connect(addr).expect("failed to connect");
+    let _server = listener.accept().expect("failed to accept");
+
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    let p = s.process(pid).expect("current process not found");
+    assert!(p.socket_summary().is_none());
+
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_connections(),
+    );
+    let p = s.process(pid).expect("current process not found");
+    if cfg!(target_os = "linux") {
+        let summary = p.socket_summary().expect("failed to get socket summary");
+        assert!(summary.listening >= 1);
+        assert!(summary.established >= 2);
+    } else {
+        assert!(p.socket_summary().is_none());
+    }
+}
+
 #[test]
 fn test_refresh_pids() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -1037,3 +1146,1051 @@ fn accumulated_cpu_time() {
         acc_time
     );
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_security_context() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[current_pid]), false);
+    let context = s
+        .process(current_pid)
+        .expect("no process found")
+        .security_context();
+
+    match std::fs::read_to_string("/proc/self/attr/current") {
+        Ok(expected) if !expected.trim_end_matches(['\0', '\n']).is_empty() => {
+            assert_eq!(
+                context.as_deref(),
+                Some(expected.trim_end_matches(['\0', '\n']))
+            );
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn test_process_display_name() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::Always),
+    );
+    let p = s.process(pid).expect("current process not found");
+    let display_name = p.display_name();
+    assert!(!display_name.is_empty());
+    if let Some(exe) = p.exe().and_then(|exe| exe.file_stem()) {
+        assert_eq!(
+            exe,
+            std::path::Path::new(&display_name).file_stem().unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_process_is_kernel_thread() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let p = s.process(pid).expect("current process not found");
+    assert!(!p.is_kernel_thread());
+
+    #[cfg(target_os = "linux")]
+    if let Some(kthreadd) = s.process(Pid::from(2)) {
+        if kthreadd.name() == "kthreadd" {
+            assert!(kthreadd.is_kernel_thread());
+        }
+    }
+}
+
+#[test]
+fn test_process_realtime_params() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+    // Tests are run with the default (non-realtime) scheduling policy.
+    assert_eq!(p.realtime_params(), None);
+}
+
+#[test]
+fn test_process_autogroup() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+    let enabled = std::fs::read_to_string("/proc/sys/kernel/sched_autogroup_enabled")
+        .is_ok_and(|enabled| enabled.trim() == "1");
+    if cfg!(target_os = "linux") && enabled {
+        let autogroup = p.autogroup().expect("failed to get autogroup");
+        assert!((-20..=19).contains(&autogroup.nice));
+    } else {
+        assert_eq!(p.autogroup(), None);
+    }
+}
+
+#[test]
+fn test_process_kind() {
+    use sysinfo::ProcessKind;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        false,
+        ProcessRefreshKind::nothing().with_user(UpdateKind::Always),
+    );
+    let p = s.process(pid).expect("current process not found");
+    assert!(matches!(p.kind(), ProcessKind::System | ProcessKind::User));
+
+    #[cfg(target_os = "linux")]
+    if let Some(kthreadd) = s.process(Pid::from(2)) {
+        if kthreadd.name() == "kthreadd" {
+            assert_eq!(kthreadd.kind(), ProcessKind::Kernel);
+        }
+    }
+
+    // Without the user information, we can't know.
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    if cfg!(target_os = "linux") {
+        let p = s.process(pid).expect("current process not found");
+        assert_eq!(p.kind(), ProcessKind::Unknown);
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+// `rlim_t` isn't `u64` on all targets.
+#[allow(clippy::unnecessary_cast)]
+fn test_process_open_files_limit() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    let mut limits = unsafe { std::mem::zeroed::<libc::rlimit>() };
+    assert_eq!(
+        unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &mut limits) },
+        0
+    );
+    let to_u64 = |limit| {
+        if limit == libc::RLIM_INFINITY {
+            u64::MAX
+        } else {
+            limit as u64
+        }
+    };
+    assert_eq!(
+        p.open_files_limit(),
+        Some((to_u64(limits.rlim_cur), to_u64(limits.rlim_max)))
+    );
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_umask() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    // Setting the umask to read it would race with the other tests, so we read it directly.
+    let status = std::fs::read_to_string("/proc/self/status").expect("failed to read status");
+    let umask = status
+        .lines()
+        .find_map(|line| line.strip_prefix("Umask:"))
+        .map(|umask| u32::from_str_radix(umask.trim(), 8).expect("invalid umask"));
+    assert_eq!(p.umask(), umask);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_tracer_pid() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    let status = std::fs::read_to_string("/proc/self/status").unwrap();
+    let expected = status
+        .lines()
+        .find_map(|line| line.strip_prefix("TracerPid:"))
+        .and_then(|tracer| tracer.trim().parse::<usize>().ok())
+        .filter(|tracer| *tracer != 0)
+        .map(Pid::from);
+    assert_eq!(p.tracer_pid(), expected);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_is_chrooted_and_mount_namespace_id() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    assert_eq!(p.is_chrooted(), Some(false));
+    let link = std::fs::read_link("/proc/self/ns/mnt").unwrap();
+    assert_eq!(
+        p.mount_namespace_id().map(|id| format!("mnt:[{id}]")),
+        link.to_str().map(str::to_owned),
+    );
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_sched_stats() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    if std::path::Path::new("/proc/self/schedstat").exists() {
+        let stats = p
+            .sched_stats()
+            .expect("failed to get scheduling statistics");
+        assert!(stats.timeslices > 0);
+    } else {
+        assert!(p.sched_stats().is_none());
+    }
+}
+
+#[test]
+fn test_process_scheduling_summary() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    if cfg!(target_os = "linux") {
+        let summary = p
+            .scheduling_summary()
+            .expect("failed to get scheduling summary");
+        // Tests are run with the default (non-realtime) scheduling policy.
+        assert!(matches!(
+            summary.policy,
+            Some(sysinfo::SchedPolicy::Other | sysinfo::SchedPolicy::Batch)
+        ));
+        assert!(summary.nice.is_some_and(|nice| (-20..=19).contains(&nice)));
+        assert!(summary.last_cpu.is_some());
+        assert!(summary.voluntary_context_switches.is_some());
+        assert!(summary.involuntary_context_switches.is_some());
+    } else {
+        assert!(p.scheduling_summary().is_none());
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_rss_breakdown() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    let status = std::fs::read_to_string("/proc/self/status").expect("failed to read status");
+    if status.contains("RssAnon:") {
+        let rss = p.rss_breakdown().expect("failed to get RSS breakdown");
+        // The test binary itself is mapped from a file and has a heap.
+        assert!(rss.anonymous > 0);
+        assert!(rss.file_backed > 0);
+    } else {
+        assert!(p.rss_breakdown().is_none());
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_char_io() {
+    use std::io::Write;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    let refresh_kind = ProcessRefreshKind::nothing().with_disk_usage();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let before = s
+        .process(pid)
+        .expect("current process not found")
+        .char_io()
+        .expect("failed to get char I/O");
+
+    // Writing to `/dev/null` doesn't touch any disk but still counts as written chars.
+    let mut null = std::fs::File::create("/dev/null").unwrap();
+    null.write_all(&[0; 4096]).unwrap();
+
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let after = s
+        .process(pid)
+        .expect("current process not found")
+        .char_io()
+        .expect("failed to get char I/O");
+    assert!(after.total_written_bytes >= before.total_written_bytes + 4096);
+    assert_eq!(
+        after.written_bytes,
+        after.total_written_bytes - before.total_written_bytes
+    );
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_io_syscalls() {
+    use std::io::Write;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    assert!(s.process(pid).unwrap().io_syscalls().is_none());
+
+    let refresh_kind = ProcessRefreshKind::nothing().with_disk_usage();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let before = s
+        .process(pid)
+        .expect("current process not found")
+        .io_syscalls()
+        .expect("failed to get I/O syscalls");
+
+    let mut null = std::fs::File::create("/dev/null").unwrap();
+    for _ in 0..10 {
+        null.write_all(&[0]).unwrap();
+    }
+
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let after = s
+        .process(pid)
+        .expect("current process not found")
+        .io_syscalls()
+        .expect("failed to get I/O syscalls");
+    assert!(after.write_calls >= before.write_calls + 10);
+    // Reading `/proc/[pid]/io` is a read syscall.
+    assert!(after.read_calls > before.read_calls);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_disk_usage_only_refresh() {
+    use std::io::Write;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    let refresh_kind = ProcessRefreshKind::nothing().with_disk_usage();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let Some(mut previous) = s.process(pid).and_then(|p| p.char_io()) else {
+        // `/proc/self/io` isn't always readable.
+        return;
+    };
+    let mut previous_disk_usage = s.process(pid).unwrap().disk_usage();
+    let mut null = std::fs::File::create("/dev/null").unwrap();
+
+    for i in 0..5 {
+        null.write_all(&[0; 4096]).unwrap();
+        if i % 2 == 1 {
+            // Refreshing other fields must not reset the disk usage baseline.
+            s.refresh_processes_specifics(
+                ProcessesToUpdate::Some(&[pid]),
+                false,
+                ProcessRefreshKind::nothing().with_memory(),
+            );
+        }
+        s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+        let p = s.process(pid).expect("current process not found");
+        let current = p.char_io().expect("failed to get char I/O");
+        assert!(current.total_written_bytes >= previous.total_written_bytes + 4096);
+        assert_eq!(
+            current.written_bytes,
+            current.total_written_bytes - previous.total_written_bytes
+        );
+        assert_eq!(
+            current.read_bytes,
+            current.total_read_bytes - previous.total_read_bytes
+        );
+        let disk_usage = p.disk_usage();
+        assert_eq!(
+            disk_usage.written_bytes,
+            disk_usage.total_written_bytes - previous_disk_usage.total_written_bytes
+        );
+        assert_eq!(
+            disk_usage.read_bytes,
+            disk_usage.total_read_bytes - previous_disk_usage.total_read_bytes
+        );
+        previous = current;
+        previous_disk_usage = disk_usage;
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_effective_cpu_count() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    let count = p
+        .effective_cpu_count()
+        .expect("failed to get effective CPU count");
+    assert!(count > 0.);
+    assert!(count <= System::available_parallelism() as f32);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_name_source() {
+    use sysinfo::NameSource;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    let refresh = |s: &mut System, source| {
+        s.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing().with_name_source(source),
+        );
+        s.process(pid)
+            .expect("current process not found")
+            .name()
+            .to_os_string()
+    };
+
+    let exe = std::env::current_exe().expect("failed to get current exe");
+    assert_eq!(refresh(&mut s, NameSource::Exe), exe.file_name().unwrap());
+    let arg0 = std::env::args_os().next().expect("no first argument");
+    assert_eq!(refresh(&mut s, NameSource::Cmdline), arg0);
+    let comm = std::fs::read_to_string("/proc/self/comm").expect("failed to read comm");
+    assert_eq!(refresh(&mut s, NameSource::Comm), comm.trim_end());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_cgroups() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    let data = std::fs::read_to_string("/proc/self/cgroup").expect("failed to read cgroup");
+    let cgroups = p.cgroups().expect("failed to get cgroups");
+    assert_eq!(cgroups.len(), data.lines().count());
+    for cgroup in &cgroups {
+        assert!(cgroup.path.starts_with('/'));
+        let line = format!("{}:{}", cgroup.controllers.join(","), cgroup.path);
+        assert!(data.lines().any(|l| l.ends_with(&line)), "{line}");
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_cgroup_usage() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+
+    let cgroup = std::fs::read_to_string("/proc/self/cgroup").expect("failed to read cgroup");
+    let has_memory_current = cgroup
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(|path| {
+            std::path::Path::new("/sys/fs/cgroup")
+                .join(path.trim_start_matches('/'))
+                .join("memory.current")
+                .exists()
+        })
+        .unwrap_or(false);
+    match p.cgroup_usage() {
+        Some(usage) => {
+            assert!(usage.memory_current > 0);
+            if let Some(memory_peak) = usage.memory_peak {
+                assert!(memory_peak >= usage.memory_current);
+            }
+        }
+        None => assert!(!has_memory_current),
+    }
+}
+
+#[test]
+fn test_process_info() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let process = System::process_info(pid, ProcessRefreshKind::nothing().with_memory())
+        .expect("current process not found");
+    assert_eq!(process.pid(), pid);
+    assert!(process.memory() > 0);
+    assert!(System::process_info(
+        Pid::from(u32::MAX as usize / 2),
+        ProcessRefreshKind::nothing()
+    )
+    .is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_exe_deleted() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+    assert_eq!(p.exe_deleted(), Some(false));
+
+    // The temporary directory may be mounted with `noexec`, so we use the one of cargo instead.
+    let exe = std::path::Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join(format!("sysinfo-exe-deleted-{}", pid.as_u32()));
+    std::fs::copy("/bin/sleep", &exe).expect("failed to copy sleep");
+    let mut child = std::process::Command::new(&exe)
+        .arg("3")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .expect("failed to spawn copied sleep");
+    std::fs::remove_file(&exe).expect("failed to remove copied sleep");
+    let child_pid = Pid::from_u32(child.id());
+    s.refresh_processes(ProcessesToUpdate::Some(&[child_pid]), false);
+    let deleted = s.process(child_pid).and_then(|p| p.exe_deleted());
+    let _ = child.kill();
+    let _ = child.wait();
+    assert_eq!(deleted, Some(true));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_exe_identity() {
+    use std::os::unix::fs::MetadataExt;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    let p = s.process(pid).expect("current process not found");
+    assert_eq!(p.exe_identity(), None);
+
+    let exe = std::fs::metadata(std::env::current_exe().expect("failed to get current exe"))
+        .expect("failed to get exe metadata");
+    // The second time, `exe` is already set but the identity is still refreshed.
+    for _ in 0..2 {
+        s.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+        );
+        let p = s.process(pid).expect("current process not found");
+        assert_eq!(p.exe_identity(), Some((exe.dev(), exe.ino())));
+    }
+}
+
+#[test]
+fn test_process_exe_permissions() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+    let permissions = p.exe_permissions();
+    if !cfg!(target_os = "linux") {
+        assert_eq!(permissions, None);
+        return;
+    }
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        let exe = std::fs::metadata(std::env::current_exe().expect("failed to get current exe"))
+            .expect("failed to get exe metadata");
+        let permissions = permissions.expect("failed to get exe permissions");
+        // The test binary is built by cargo so it has no special permission.
+        assert!(!permissions.setuid);
+        assert!(!permissions.setgid);
+        assert!(!permissions.has_file_caps);
+        assert_eq!(*permissions.owner_uid, exe.uid());
+    }
+}
+
+#[test]
+fn test_refresh_pids_detailed() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let dead = Pid::from(u32::MAX as usize / 2);
+    let mut s = System::new();
+    let res = s.refresh_pids_detailed(&[pid, dead], ProcessRefreshKind::nothing());
+    assert_eq!(res, vec![(pid, true), (dead, false)]);
+    assert!(s.process(pid).is_some());
+    // Refreshing again must report the process as updated again.
+    let res = s.refresh_pids_detailed(&[pid], ProcessRefreshKind::nothing());
+    assert_eq!(res, vec![(pid, true)]);
+}
+
+#[test]
+fn test_poll_new_processes() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let current = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    // On the first call, all processes are new.
+    let new_pids = s.poll_new_processes(ProcessRefreshKind::nothing());
+    assert!(new_pids.contains(&current));
+    assert!(new_pids.windows(2).all(|w| w[0] < w[1]));
+
+    let mut p = start_proc!("3", "PollNewProcessesSignal");
+    let pid = Pid::from_u32(p.id() as _);
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let new_pids = s.poll_new_processes(ProcessRefreshKind::nothing());
+    p.kill().expect("Unable to kill process.");
+    assert!(new_pids.contains(&pid));
+    assert!(!new_pids.contains(&current));
+}
+
+#[test]
+fn test_process_bitness() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+    let expected = if cfg!(target_pointer_width = "64") {
+        sysinfo::Bitness::Bit64
+    } else {
+        sysinfo::Bitness::Bit32
+    };
+    assert_eq!(p.bitness(), Some(expected));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_processes_to_jsonl() {
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+    let mut out = Vec::new();
+    s.processes_to_jsonl(&mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), s.processes().len());
+    for line in lines {
+        let value: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert!(value.is_object());
+    }
+}
+
+#[test]
+fn test_process_cpu_usage_normalized() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    let refresh_kind = ProcessRefreshKind::nothing().with_cpu();
+    s.refresh_processes_specifics(ProcessesToUpdate::All, false, refresh_kind);
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_processes_specifics(ProcessesToUpdate::All, false, refresh_kind);
+
+    // On Linux, the CPUs are refreshed along with the processes CPU usage.
+    let cpu_count = s.cpus().len() as f32;
+    for process in s.processes().values() {
+        let normalized = process.cpu_usage_normalized();
+        assert!((0. ..=100.).contains(&normalized));
+        assert!(normalized <= process.cpu_usage());
+        if cfg!(target_os = "linux") {
+            assert!((normalized * cpu_count - process.cpu_usage()).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn test_process_cpu_usage_since() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    let refresh_kind = ProcessRefreshKind::nothing().with_cpu();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let baseline = s
+        .process(pid)
+        .expect("current process not found")
+        .cpu_baseline();
+
+    // We keep the CPU busy so the process accumulates CPU time.
+    let start = std::time::Instant::now();
+    let mut x = 0u64;
+    while start.elapsed() < std::time::Duration::from_millis(300) {
+        x = std::hint::black_box(x.wrapping_add(1));
+    }
+
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    let usage = s
+        .process(pid)
+        .expect("current process not found")
+        .cpu_usage_since(&baseline);
+    assert!(usage > 0., "{usage}");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_hottest_task() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let stop = Arc::new(AtomicBool::new(false));
+    let thread = {
+        let stop = Arc::clone(&stop);
+        std::thread::spawn(move || {
+            let mut x = 0u64;
+            while !stop.load(Ordering::Relaxed) {
+                x = std::hint::black_box(x.wrapping_add(1));
+            }
+        })
+    };
+    // CPU usage is only computed for tasks which already used some CPU at the first refresh.
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    let refresh_kind = ProcessRefreshKind::nothing().with_cpu().with_tasks();
+    s.refresh_processes_specifics(ProcessesToUpdate::All, false, refresh_kind);
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_processes_specifics(ProcessesToUpdate::All, false, refresh_kind);
+    stop.store(true, Ordering::Relaxed);
+    thread.join().unwrap();
+
+    let p = s.process(pid).expect("current process not found");
+    let hottest = p.hottest_task().expect("failed to get hottest task");
+    assert!(p.tasks().unwrap().contains(&hottest));
+    let hottest_usage = s.process(hottest).unwrap().cpu_usage();
+    for task in p.tasks().unwrap() {
+        if let Some(task) = s.process(*task) {
+            assert!(task.cpu_usage() <= hottest_usage);
+        }
+    }
+}
+
+#[test]
+fn test_usage_by_user() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        false,
+        ProcessRefreshKind::nothing()
+            .with_cpu()
+            .with_memory()
+            .with_user(UpdateKind::Always),
+    );
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let uid = s.process(pid).expect("current process not found").user_id();
+
+    let memory = s.memory_by_user();
+    assert!(memory.values().sum::<u64>() <= s.processes().values().map(|p| p.memory()).sum());
+    assert!(memory[&uid.cloned()] > 0);
+
+    let cpu_usage = s.cpu_usage_by_user();
+    assert!(cpu_usage.contains_key(&uid.cloned()));
+    assert!(cpu_usage.values().all(|usage| *usage >= 0.));
+}
+
+// Threads are listed as processes on Linux and have the memory of their process, so they must
+// not be counted.
+#[test]
+#[cfg(target_os = "linux")]
+fn test_usage_by_user_skips_threads() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let (tx, rx) = std::sync::mpsc::channel::<()>();
+    let thread = std::thread::spawn(move || rx.recv());
+
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        false,
+        ProcessRefreshKind::nothing()
+            .with_memory()
+            .with_tasks()
+            .with_user(UpdateKind::Always),
+    );
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let process = s.process(pid).expect("current process not found");
+    let uid = process.user_id().cloned();
+    let tasks = process.tasks().expect("failed to get tasks");
+    // The main thread isn't listed in the tasks, but the thread spawned above is.
+    assert!(!tasks.is_empty());
+    // Skip the main thread in case it gets listed in the tasks.
+    let threads_memory = tasks
+        .iter()
+        .filter(|tid| **tid != pid)
+        .filter_map(|tid| s.process(*tid))
+        .map(|thread| thread.memory())
+        .sum::<u64>();
+    assert!(threads_memory >= process.memory());
+    let all_memory = s
+        .processes()
+        .values()
+        .filter(|p| p.user_id().cloned() == uid)
+        .map(|p| p.memory())
+        .sum::<u64>();
+
+    // Memory could have changed between the refreshes of the different entries, but the
+    // threads memory is way higher than these variations.
+    assert!(s.memory_by_user()[&uid] <= all_memory - threads_memory);
+
+    tx.send(()).unwrap();
+    thread.join().unwrap().unwrap();
+}
+
+#[test]
+fn test_refresh_processes_specifics_checked() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let dead = Pid::from(u32::MAX as usize / 2);
+    let dead2 = Pid::from(u32::MAX as usize / 2 + 1);
+    let mut s = System::new();
+    let refresh_kind = ProcessRefreshKind::nothing();
+    let res =
+        s.refresh_processes_specifics_checked(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+    assert_eq!(res.ok(), Some(1));
+
+    match s.refresh_processes_specifics_checked(
+        ProcessesToUpdate::Some(&[dead, pid, dead2]),
+        true,
+        refresh_kind,
+    ) {
+        Err(sysinfo::RefreshError::Processes { nb_updated, errors }) => {
+            assert_eq!(nb_updated, 1);
+            // All failures are reported.
+            assert_eq!(
+                errors.iter().map(|(pid, _)| *pid).collect::<Vec<_>>(),
+                vec![dead, dead2]
+            );
+            assert!(errors
+                .iter()
+                .all(|(_, error)| error.kind() == std::io::ErrorKind::NotFound));
+        }
+        res => panic!("unexpected result: {res:?}"),
+    }
+    // The other processes are still refreshed.
+    assert!(s.process(pid).is_some());
+}
+
+#[test]
+fn test_process_child_count() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut child = start_proc!("3", "ChildCountSignal");
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+    let count = s
+        .process(pid)
+        .expect("current process not found")
+        .child_count(&s);
+    let _ = child.kill();
+    let _ = child.wait();
+    assert!(count >= 1);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_task_names() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let (tx, rx) = std::sync::mpsc::channel::<()>();
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel::<()>();
+    let thread = std::thread::Builder::new()
+        .name("sysinfo-named".to_owned())
+        .spawn(move || {
+            // The thread name is only set once the thread started.
+            let _ = ready_tx.send(());
+            let _ = rx.recv();
+        })
+        .expect("failed to spawn thread");
+    ready_rx.recv().unwrap();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_tasks(),
+    );
+    // Task names are opt-in.
+    let p = s.process(pid).expect("current process not found");
+    assert!(p.task_names().is_none());
+
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing().with_tasks().with_task_names(),
+    );
+    let p = s.process(pid).expect("current process not found");
+    let task_names = p.task_names().expect("failed to get task names").clone();
+    let _ = tx.send(());
+    thread.join().unwrap();
+
+    // Threads of other tests might exit before their name is read.
+    let tasks = p.tasks().expect("failed to get tasks");
+    assert!(task_names.keys().all(|task| tasks.contains(task)));
+    assert!(task_names.values().any(|name| name == "sysinfo-named"));
+}
+
+#[test]
+fn test_process_memory_delta() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_memory(),
+    );
+    let p = s.process(pid).expect("current process not found");
+    assert_eq!(p.memory_delta(), 0);
+    let memory = p.memory();
+
+    let data = vec![1u8; 64 * 1024 * 1024];
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_memory(),
+    );
+    let p = s.process(pid).expect("current process not found");
+    assert_eq!(p.memory_delta(), p.memory() as i64 - memory as i64);
+    drop(data);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_last_cpu() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+    let last_cpu = p.last_cpu().expect("failed to get last CPU");
+    assert!(last_cpu < System::possible_cpu_count());
+}
+
+#[test]
+#[cfg(unix)]
+fn test_process_cmd_display() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut child = std::process::Command::new("sh")
+        .arg("-c")
+        // The trailing command prevents shells like bash from replacing themselves with `sleep`.
+        .arg("sleep 3; :")
+        .arg("it's")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .expect("failed to spawn sh");
+    let pid = Pid::from_u32(child.id());
+    let mut s = System::new();
+    let mut cmd_display = String::new();
+    // The command line might not be set right away.
+    for _ in 0..50 {
+        s.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing().with_cmd(UpdateKind::Always),
+        );
+        if let Some(p) = s.process(pid).filter(|p| p.cmd().len() == 4) {
+            cmd_display = p.cmd_display();
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+    assert_eq!(cmd_display, r"sh -c 'sleep 3; :' 'it'\''s'");
+}
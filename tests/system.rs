@@ -107,6 +107,17 @@ fn check_hostname_has_no_nuls() {
     }
 }
 
+#[test]
+fn check_fqdn() {
+    let fqdn = System::fqdn();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(fqdn.is_none());
+    } else if let Some(fqdn) = fqdn {
+        assert!(!fqdn.is_empty());
+        assert!(!fqdn.contains('\u{0}'));
+    }
+}
+
 #[test]
 fn check_uptime() {
     let uptime = System::uptime();
@@ -254,3 +265,345 @@ fn test_refresh_memory() {
     assert_ne!(s.total_memory(), 0);
     assert_ne!(s.free_memory(), 0);
 }
+
+#[test]
+fn test_open_file_descriptors() {
+    let fds = System::open_file_descriptors();
+    if cfg!(target_os = "linux") && sysinfo::IS_SUPPORTED_SYSTEM {
+        let (allocated, max) = fds.expect("failed to retrieve open file descriptors");
+        assert!(allocated > 0);
+        assert!(allocated <= max);
+    } else {
+        assert!(fds.is_none());
+    }
+}
+
+#[test]
+fn test_available_entropy() {
+    let entropy = System::available_entropy();
+    if cfg!(target_os = "linux") && sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(entropy.is_some());
+    } else {
+        assert!(entropy.is_none());
+    }
+}
+
+#[test]
+fn test_swap_activity() {
+    let mut s = System::new();
+    assert!(s.swap_activity().is_none());
+    s.refresh_memory();
+    let activity = s.swap_activity();
+    if cfg!(target_os = "linux") && sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(activity.is_some());
+    } else {
+        assert!(activity.is_none());
+    }
+}
+
+#[test]
+fn test_effective_swap_capacity() {
+    let mut s = System::new();
+    s.refresh_memory();
+    let swaps = std::fs::read_to_string("/proc/swaps").unwrap_or_default();
+    // Size (in the swap) and capacity (uncompressed) of the zram devices.
+    let (zram_size, zram_disksize) = swaps
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let device = fields.next()?.strip_prefix("/dev/")?;
+            if !device.starts_with("zram") {
+                return None;
+            }
+            let size = fields.nth(1)?.parse::<u64>().ok()? * 1024;
+            let disksize = std::fs::read_to_string(format!("/sys/block/{device}/disksize"))
+                .ok()?
+                .trim()
+                .parse::<u64>()
+                .ok()?;
+            Some((size, disksize))
+        })
+        .fold((0, 0), |(size, disksize), (s, d)| (size + s, disksize + d));
+    let capacity = s.effective_swap_capacity();
+    if zram_size == 0 {
+        assert_eq!(capacity, s.total_swap());
+    } else {
+        // A zram device can't hold more than its size (nor less than nothing).
+        let other_swap = s.total_swap().saturating_sub(zram_size);
+        assert!(capacity >= other_swap, "{capacity} < {other_swap}");
+        assert!(
+            capacity <= other_swap + zram_disksize,
+            "{capacity} > {other_swap} + {zram_disksize}"
+        );
+    }
+}
+
+#[test]
+fn test_is_swapping() {
+    let mut s = System::new();
+    s.refresh_memory();
+    assert!(s.is_swapping().is_none());
+    s.refresh_memory();
+    let is_swapping = s.is_swapping();
+    if cfg!(target_os = "linux") && sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(is_swapping.is_some());
+    } else {
+        assert!(is_swapping.is_none());
+    }
+}
+
+#[test]
+fn test_available_parallelism() {
+    let count = System::available_parallelism();
+    assert!(count >= 1);
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let mut s = System::new();
+        s.refresh_cpu_list(sysinfo::CpuRefreshKind::nothing());
+        assert!(count <= s.cpus().len());
+    }
+}
+
+#[test]
+fn test_memory_info() {
+    let info = sysinfo::memory_info();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(info, sysinfo::MemoryInfo::default());
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_memory();
+    assert_eq!(info.total, s.total_memory());
+    assert_eq!(info.total_swap, s.total_swap());
+    assert!(info.available <= info.total);
+    assert!(info.free_swap <= info.total_swap);
+}
+
+#[test]
+fn test_kernel_cmdline() {
+    let cmdline = System::kernel_cmdline();
+    if cfg!(target_os = "linux") && sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(cmdline.is_some());
+    } else {
+        assert!(cmdline.is_none());
+    }
+}
+
+#[test]
+fn test_kernel_stats() {
+    let stats = System::kernel_stats();
+    if cfg!(target_os = "linux") && sysinfo::IS_SUPPORTED_SYSTEM {
+        let stats = stats.expect("failed to get kernel stats");
+        assert!(stats.context_switches > 0);
+        assert!(stats.processes_forked > 0);
+    } else {
+        assert!(stats.is_none());
+    }
+}
+
+#[test]
+fn test_secure_boot_enabled() {
+    let enabled = System::secure_boot_enabled();
+    let supported =
+        cfg!(any(target_os = "linux", target_os = "windows")) && sysinfo::IS_SUPPORTED_SYSTEM;
+    // Without EFI, secure boot status is never available.
+    if !supported
+        || (cfg!(target_os = "linux") && !std::path::Path::new("/sys/firmware/efi").exists())
+    {
+        assert!(enabled.is_none());
+    }
+}
+
+#[test]
+fn test_online_and_possible_cpu_count() {
+    let online = System::online_cpu_count();
+    let possible = System::possible_cpu_count();
+    assert!(online >= 1);
+    assert!(online <= possible);
+}
+
+#[test]
+fn test_cpuset_cpus() {
+    let cpus = System::cpuset_cpus();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || !cfg!(any(target_os = "linux", target_os = "android")) {
+        assert!(cpus.is_none());
+    } else if let Some(cpus) = cpus {
+        assert!(!cpus.is_empty());
+        assert!(cpus.windows(2).all(|w| w[0] < w[1]));
+        assert!(cpus.len() <= System::possible_cpu_count());
+    }
+}
+
+#[test]
+fn test_init_process_name() {
+    let name = System::init_process_name();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || !cfg!(any(target_os = "linux", target_os = "android")) {
+        assert!(name.is_none());
+    } else if std::fs::read("/proc/1/comm").is_ok() {
+        // `/proc/1` can't be read with `hidepid=2` or in some sandboxes.
+        assert!(!name.expect("failed to get init process name").is_empty());
+    }
+}
+
+#[test]
+fn test_cached_pages() {
+    use std::io::Write;
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(&[1; 10_000]).unwrap();
+    file.flush().unwrap();
+
+    let info = sysinfo::cached_pages(file.path());
+    if cfg!(unix) && sysinfo::IS_SUPPORTED_SYSTEM {
+        let info = info.expect("failed to get cached pages");
+        assert!(info.total_pages > 0);
+        assert!(info.cached_pages <= info.total_pages);
+    } else {
+        assert!(info.is_none());
+    }
+    assert!(sysinfo::cached_pages(std::path::Path::new("/sysinfo/does/not/exist")).is_none());
+}
+
+#[test]
+fn test_pid_usage() {
+    let mut s = System::new();
+    assert!(s.pid_usage().is_none());
+
+    s.refresh_processes(sysinfo::ProcessesToUpdate::All, false);
+    let usage = s.pid_usage();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || !cfg!(any(target_os = "linux", target_os = "android")) {
+        assert!(usage.is_none());
+    } else {
+        let usage = usage.expect("failed to get PID usage");
+        let pid = sysinfo::get_current_pid().unwrap().as_u32();
+        assert!(usage.highest_active >= pid);
+        assert!(usage.highest_active < usage.pid_max);
+    }
+}
+
+#[test]
+fn test_tpm() {
+    let tpm = System::tpm();
+    let supported = cfg!(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "windows"
+    )) && sysinfo::IS_SUPPORTED_SYSTEM;
+    if !supported {
+        assert!(tpm.is_none());
+    } else if cfg!(any(target_os = "linux", target_os = "android")) {
+        let tpm = tpm.expect("failed to get TPM information");
+        assert_eq!(
+            tpm.present,
+            std::path::Path::new("/sys/class/tpm/tpm0").exists()
+        );
+        if !tpm.present {
+            assert_eq!(tpm.version, sysinfo::TpmVersion::Unknown);
+        }
+    }
+}
+
+#[test]
+fn test_pressure() {
+    let pressure = System::pressure();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || !cfg!(any(target_os = "linux", target_os = "android")) {
+        assert!(pressure.is_none());
+    } else if let Some(pressure) = pressure {
+        for stats in [pressure.cpu, pressure.memory, pressure.io] {
+            assert!((0. ..=100.).contains(&stats.some_avg10));
+            assert!((0. ..=100.).contains(&stats.full_avg60));
+            assert!(stats.full_avg10 <= stats.some_avg10);
+        }
+    } else {
+        assert!(!std::path::Path::new("/proc/pressure/cpu").exists());
+    }
+}
+
+#[test]
+fn test_system_summary() {
+    let s = System::new_all();
+    let summary = s.summary();
+    assert_eq!(summary.process_count, s.processes().len());
+    assert_eq!(summary.total_memory, s.total_memory());
+    assert_eq!(summary.total_swap, s.total_swap());
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(summary.process_count > 0);
+        assert!(summary.used_memory <= summary.total_memory);
+    }
+}
+
+#[test]
+fn test_last_refresh_timings() {
+    use sysinfo::{MemoryRefreshKind, RefreshKind, RefreshTimings};
+
+    let mut s = System::new();
+    assert_eq!(s.last_refresh_timings(), RefreshTimings::default());
+
+    s.refresh_specifics(RefreshKind::nothing().with_memory(MemoryRefreshKind::everything()));
+    let timings = s.last_refresh_timings();
+    // Only the memory was refreshed.
+    assert_eq!(timings.cpu, std::time::Duration::ZERO);
+    assert_eq!(timings.processes, std::time::Duration::ZERO);
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(timings.memory > std::time::Duration::ZERO);
+    }
+}
+
+#[test]
+fn test_arch_details() {
+    let details = System::arch_details();
+    assert!(!details.arch.is_empty());
+    assert!(details.bits == 32 || details.bits == 64);
+    // The host word size can't be smaller than the one of the current process.
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(details.bits as u32 >= usize::BITS);
+    }
+    if cfg!(target_endian = "little") {
+        assert_eq!(details.endianness, sysinfo::Endianness::Little);
+    } else {
+        assert_eq!(details.endianness, sysinfo::Endianness::Big);
+    }
+}
+
+#[test]
+fn test_transparent_huge_pages() {
+    let thp = System::transparent_huge_pages();
+    if cfg!(target_os = "linux")
+        && sysinfo::IS_SUPPORTED_SYSTEM
+        && std::path::Path::new("/sys/kernel/mm/transparent_hugepage/enabled").exists()
+    {
+        let thp = thp.expect("failed to get transparent huge pages settings");
+        assert!(matches!(
+            thp.enabled,
+            sysinfo::ThpMode::Always | sysinfo::ThpMode::MadviseOnly | sysinfo::ThpMode::Never
+        ));
+    } else if !cfg!(any(target_os = "linux", target_os = "android")) {
+        assert!(thp.is_none());
+    }
+}
+
+#[test]
+fn test_reboot_required() {
+    let reboot_required = System::reboot_required();
+    if !cfg!(any(target_os = "linux", target_os = "android")) {
+        assert!(reboot_required.is_none());
+    } else if std::path::Path::new("/run/reboot-required").exists() {
+        assert_eq!(reboot_required, Some(true));
+    }
+}
+
+#[test]
+fn test_active_desktop_user() {
+    let user = System::active_desktop_user();
+    let supported = cfg!(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "windows"
+    )) && sysinfo::IS_SUPPORTED_SYSTEM;
+    if !supported {
+        assert!(user.is_none());
+    } else if let Some(user) = user {
+        assert!(!user.is_empty());
+    }
+}
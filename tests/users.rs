@@ -25,3 +25,24 @@ fn test_users() {
         }
     }
 }
+
+#[cfg(feature = "user")]
+#[test]
+fn test_current_user() {
+    use sysinfo::Users;
+
+    let mut users = Users::new();
+    assert!(users.current_user().is_none());
+    users.refresh();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(users.current_user().is_none());
+        return;
+    }
+    // The current user might be filtered out of the list (like system accounts), so we can
+    // only check it's consistent.
+    if let Some(user) = users.current_user() {
+        assert!(users.get_user_by_id(user.id()).is_some());
+        #[cfg(unix)]
+        assert_eq!(**user.id(), unsafe { libc::geteuid() });
+    }
+}